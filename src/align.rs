@@ -0,0 +1,98 @@
+use crate::{transcendental, Angle, Point, Pose2};
+
+/// Return the rigid transform best mapping source onto target in the least squares sense,
+/// so that pose.transform_point(source[i]) lies as close as possible to target[i]. Points
+/// are matched by index. None if the slices are empty or differ in length.
+pub fn align(source: &[Point], target: &[Point]) -> Option<Pose2> {
+    align_with_scale(source, target, false).map(|(pose, _)| pose)
+}
+
+/// Like align, but additionally estimate a uniform scale factor (Umeyama / Procrustes).
+/// The fitted mapping is target[i] ≈ pose.transform_point(scale * source[i]).
+pub fn align_scaled(source: &[Point], target: &[Point]) -> Option<(Pose2, f32)> {
+    align_with_scale(source, target, true)
+}
+
+fn align_with_scale(
+    source: &[Point],
+    target: &[Point],
+    estimate_scale: bool,
+) -> Option<(Pose2, f32)> {
+    if source.is_empty() || source.len() != target.len() {
+        return None;
+    }
+    let (source_center, target_center) = (mean(source), mean(target));
+    // In 2D the cross covariance reduces to the summed dot and cross products of the
+    // centered point pairs, whose angle is the optimal rotation
+    let (mut dot, mut cross, mut spread) = (0.0f64, 0.0f64, 0.0f64);
+    for (s, t) in source.iter().zip(target) {
+        let (sx, sy) = (
+            (s.x - source_center.x) as f64,
+            (s.y - source_center.y) as f64,
+        );
+        let (tx, ty) = (
+            (t.x - target_center.x) as f64,
+            (t.y - target_center.y) as f64,
+        );
+        dot += sx * tx + sy * ty;
+        cross += sx * ty - sy * tx;
+        spread += sx * sx + sy * sy;
+    }
+    let angle = transcendental::atan2(cross, dot);
+    let scale = if estimate_scale && spread > 0.0 {
+        ((dot * dot + cross * cross).sqrt() / spread) as f32
+    } else {
+        1.0
+    };
+    let rotation = Pose2::new(Point { x: 0.0, y: 0.0 }, Angle::from_radians(angle as f32));
+    let rotated = rotation.transform_point(Point {
+        x: scale * source_center.x,
+        y: scale * source_center.y,
+    });
+    let position = Point {
+        x: target_center.x - rotated.x,
+        y: target_center.y - rotated.y,
+    };
+    Some((Pose2::new(position, rotation.heading), scale))
+}
+
+fn mean(points: &[Point]) -> Point {
+    let n = points.len() as f32;
+    let (x, y) = points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+    Point { x: x / n, y: y / n }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_align_recovers_transform() {
+        let source = vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 1.0), p(-1.0, 3.0)];
+        let truth = Pose2::new(p(5.0, -2.0), Angle::from_degrees(30.0));
+        let target: Vec<Point> = source.iter().map(|s| truth.transform_point(*s)).collect();
+        let pose = align(&source, &target).unwrap();
+        assert!((pose.heading.degrees() - 30.0).abs() < 1e-3);
+        assert!((pose.position.x - 5.0).abs() < 1e-4);
+        assert!((pose.position.y + 2.0).abs() < 1e-4);
+        assert_eq!(None, align(&source, &target[1..]));
+    }
+
+    #[test]
+    fn test_align_scaled() {
+        let source = vec![p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)];
+        let truth = Pose2::new(p(1.0, 1.0), Angle::from_degrees(-90.0));
+        let target: Vec<Point> = source
+            .iter()
+            .map(|s| truth.transform_point(p(2.5 * s.x, 2.5 * s.y)))
+            .collect();
+        let (pose, scale) = align_scaled(&source, &target).unwrap();
+        assert!((scale - 2.5).abs() < 1e-4);
+        assert!((pose.heading.degrees() + 90.0).abs() < 1e-3);
+        assert!((pose.position.x - 1.0).abs() < 1e-4);
+        assert!((pose.position.y - 1.0).abs() < 1e-4);
+    }
+}
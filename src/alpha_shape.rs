@@ -0,0 +1,144 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::delaunay::circumcenter;
+use crate::{delaunay, transcendental, Mesh, Point, Polygon, PolygonWithHoles};
+
+/// Return the alpha shape of the points: the Delaunay triangles whose circumcircle has a
+/// radius of at most alpha. Large alpha keeps the whole convex hull, small alpha carves
+/// into dents and gaps wider than about twice alpha. The vertices of the mesh are the
+/// points in their order.
+pub fn alpha_shape(points: &[Point], alpha: f32) -> Mesh {
+    let mut mesh = delaunay(points);
+    let vertices = &mesh.vertices;
+    let indices = mesh
+        .indices
+        .chunks(3)
+        .filter(|t| {
+            let [a, b, c] = [
+                vertices[t[0] as usize],
+                vertices[t[1] as usize],
+                vertices[t[2] as usize],
+            ];
+            match circumcenter(a, b, c) {
+                Some(center) => transcendental::hypot(center.x - a.x, center.y - a.y) <= alpha,
+                None => false,
+            }
+        })
+        .flatten()
+        .copied()
+        .collect();
+    mesh.indices = indices;
+    mesh
+}
+
+/// Return the outlines of the alpha shape as polygons, the footprint of the point cloud
+/// that unlike the convex hull follows L shapes and courtyards. Exteriors run counter
+/// clockwise and holes clockwise. Parts touching in a single vertex may come out as one
+/// ring through that vertex.
+pub fn concave_hull(points: &[Point], alpha: f32) -> Vec<PolygonWithHoles> {
+    let mesh = alpha_shape(points, alpha);
+    let mut edges: BTreeSet<(u32, u32)> = BTreeSet::new();
+    for t in mesh.indices.chunks(3) {
+        for i in 0..3 {
+            edges.insert((t[i], t[(i + 1) % 3]));
+        }
+    }
+    // Edges of only one kept triangle form the boundary, oriented with that triangle
+    let mut next: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+    for (a, b) in &edges {
+        if !edges.contains(&(*b, *a)) {
+            next.entry(*a).or_default().push(*b);
+        }
+    }
+
+    let mut rings: Vec<Polygon> = Vec::new();
+    while let Some(&start) = next.keys().next() {
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            ring.push(mesh.vertices[current as usize]);
+            let targets = match next.get_mut(&current) {
+                Some(targets) => targets,
+                None => break,
+            };
+            let target = targets.pop();
+            if targets.is_empty() {
+                next.remove(&current);
+            }
+            match target {
+                Some(target) if target != start => current = target,
+                _ => break,
+            }
+        }
+        if ring.len() >= 3 {
+            rings.push(Polygon::new(ring));
+        }
+    }
+
+    let (exteriors, holes): (Vec<Polygon>, Vec<Polygon>) =
+        rings.into_iter().partition(|r| r.signed_area() > 0.0);
+    let mut shapes: Vec<PolygonWithHoles> = exteriors
+        .into_iter()
+        .map(|e| PolygonWithHoles::new(e, Vec::new()))
+        .collect();
+    for hole in holes {
+        let inside = shapes
+            .iter_mut()
+            .find(|s| s.exterior.contains(hole.points[0]));
+        if let Some(shape) = inside {
+            shape.holes.push(hole);
+        }
+    }
+    shapes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Area;
+
+    /// Points on a grid of spacing 1 filling an L of two 6 by 2 arms
+    fn l_cloud() -> Vec<Point> {
+        let mut points = Vec::new();
+        for i in 0..=6 {
+            for j in 0..=6 {
+                if i <= 2 || j <= 2 {
+                    points.push(p(i as f32, j as f32));
+                }
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_l_shaped_footprint() {
+        let points = l_cloud();
+        let hulls = concave_hull(&points, 1.0);
+        assert_eq!(1, hulls.len());
+        assert!(hulls[0].holes.is_empty());
+        // The half cell at the inner corner is as round as the grid cells and stays
+        assert!((hulls[0].area() - 20.5).abs() < 1e-4);
+        assert!(!hulls[0].exterior.contains(p(4.5, 4.5)));
+        let convex = concave_hull(&points, 100.0);
+        assert!((convex[0].area() - 28.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_ring_with_courtyard() {
+        let mut points = Vec::new();
+        for i in 0..=5 {
+            for j in 0..=5 {
+                if i <= 1 || j <= 1 || i >= 4 || j >= 4 {
+                    points.push(p(i as f32, j as f32));
+                }
+            }
+        }
+        let hulls = concave_hull(&points, 1.0);
+        assert_eq!(1, hulls.len());
+        assert_eq!(1, hulls[0].holes.len());
+        // 5 by 5 square minus the 3 by 3 courtyard with its corners cut
+        assert!((hulls[0].area() - 18.0).abs() < 1e-4);
+        assert!(alpha_shape(&points, 0.1).indices.is_empty());
+    }
+}
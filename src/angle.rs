@@ -0,0 +1,116 @@
+use std::iter::Sum;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::transcendental;
+
+/// Angle stored in degrees, like all angles of this crate. Use the constructors to convert
+/// from radians instead of converting by hand.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+pub struct Angle {
+    degrees: f32,
+}
+
+impl Angle {
+    pub fn from_degrees(degrees: f32) -> Angle {
+        Angle { degrees }
+    }
+
+    pub fn from_radians(radians: f32) -> Angle {
+        Angle {
+            degrees: radians.to_degrees(),
+        }
+    }
+
+    pub fn degrees(&self) -> f32 {
+        self.degrees
+    }
+
+    pub fn radians(&self) -> f32 {
+        self.degrees.to_radians()
+    }
+
+    /// Return the same direction expressed in the range (-180, 180]
+    pub fn normalized(&self) -> Angle {
+        let mut degrees = self.degrees % 360.0;
+        if degrees > 180.0 {
+            degrees -= 360.0;
+        } else if degrees <= -180.0 {
+            degrees += 360.0;
+        }
+        Angle { degrees }
+    }
+
+    pub fn sin(&self) -> f32 {
+        transcendental::sin(self.radians())
+    }
+
+    pub fn cos(&self) -> f32 {
+        transcendental::cos(self.radians())
+    }
+
+    pub fn tan(&self) -> f32 {
+        transcendental::tan(self.radians())
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        Angle::from_degrees(self.degrees + other.degrees)
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        Angle::from_degrees(self.degrees - other.degrees)
+    }
+}
+
+impl Mul<f32> for Angle {
+    type Output = Angle;
+
+    fn mul(self, factor: f32) -> Angle {
+        Angle::from_degrees(self.degrees * factor)
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle::from_degrees(-self.degrees)
+    }
+}
+
+impl Sum for Angle {
+    fn sum<I: Iterator<Item = Angle>>(iter: I) -> Angle {
+        iter.fold(Angle::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Angle> for Angle {
+    fn sum<I: Iterator<Item = &'a Angle>>(iter: I) -> Angle {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Angle::from_degrees(30.0), Angle::from_degrees(60.0));
+        assert_eq!(Angle::from_degrees(90.0), a + b);
+        assert_eq!(Angle::from_degrees(-30.0), a - b);
+        assert_eq!(Angle::from_degrees(-60.0), -(a * 2.0));
+        assert!(a < b);
+        assert_eq!(Angle::from_degrees(180.0), [a, b, b, a].iter().sum());
+        assert!((a.sin() - 0.5).abs() < 1e-6);
+        assert!((b.cos() - 0.5).abs() < 1e-6);
+        assert!((Angle::from_degrees(45.0).tan() - 1.0).abs() < 1e-6);
+    }
+}
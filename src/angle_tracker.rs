@@ -0,0 +1,78 @@
+use crate::Angle;
+
+/// Turns a stream of headings into one continuous angle, for integrating compass or gyro
+/// readings. Each reading is assumed to be less than half a turn away from the previous
+/// one, so a step from 179 to -179 degrees counts as 2 degrees and not -358.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct AngleTracker {
+    angle: Option<Angle>,
+}
+
+impl AngleTracker {
+    pub fn new() -> AngleTracker {
+        AngleTracker::default()
+    }
+
+    /// Start tracking from an angle that may already include whole turns
+    pub fn starting_at(angle: Angle) -> AngleTracker {
+        AngleTracker { angle: Some(angle) }
+    }
+
+    /// Add a heading reading and return the unwrapped angle. The first reading is taken
+    /// as it is.
+    pub fn update(&mut self, heading: Angle) -> Angle {
+        let angle = match self.angle {
+            Some(angle) => angle + (heading - angle).normalized(),
+            None => heading,
+        };
+        self.angle = Some(angle);
+        angle
+    }
+
+    /// Return the unwrapped angle, None before the first reading
+    pub fn angle(&self) -> Option<Angle> {
+        self.angle
+    }
+
+    /// Return the number of whole turns made counter clockwise from zero, negative for
+    /// clockwise turns
+    pub fn turns(&self) -> i32 {
+        self.angle
+            .map(|a| (a.degrees() / 360.0).trunc() as i32)
+            .unwrap_or(0)
+    }
+
+    pub fn reset(&mut self) {
+        self.angle = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unwrap_across_half_turn() {
+        let mut tracker = AngleTracker::new();
+        assert_eq!(None, tracker.angle());
+        let readings = [170.0, -175.0, -120.0, 0.0, 100.0, -170.0];
+        let unwrapped: Vec<f32> = readings
+            .iter()
+            .map(|d| tracker.update(Angle::from_degrees(*d)).degrees())
+            .collect();
+        assert_eq!(vec![170.0, 185.0, 240.0, 360.0, 460.0, 550.0], unwrapped);
+        assert_eq!(1, tracker.turns());
+    }
+
+    #[test]
+    fn test_clockwise_turns() {
+        let mut tracker = AngleTracker::starting_at(Angle::from_degrees(-360.0));
+        for d in &[-90.0, 180.0, 90.0, 0.0] {
+            tracker.update(Angle::from_degrees(*d));
+        }
+        assert_eq!(Some(Angle::from_degrees(-720.0)), tracker.angle());
+        assert_eq!(-2, tracker.turns());
+        tracker.reset();
+        assert_eq!(0, tracker.turns());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{transcendental, Point, Rect, Vector};
+
+/// Circular arc around center. Like all angles in this crate start_angle and sweep_angle
+/// are in degrees, start_angle is measured counter clockwise from the positive x axis and
+/// a positive sweep_angle runs counter clockwise, a negative one clockwise.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CircularArc {
+    pub center: Point,
+    pub radius: f32,
+    pub start_angle: f32,
+    pub sweep_angle: f32,
+}
+
+impl CircularArc {
+    pub fn new(center: Point, radius: f32, start_angle: f32, sweep_angle: f32) -> CircularArc {
+        CircularArc {
+            center,
+            radius,
+            start_angle,
+            sweep_angle,
+        }
+    }
+
+    /// Return the point on the arc at parameter t, where t = 0 is the start and t = 1 the
+    /// end of the arc
+    pub fn point_at(&self, t: f32) -> Point {
+        let angle = (self.start_angle + self.sweep_angle * t).to_radians();
+        Point {
+            x: self.center.x + self.radius * transcendental::cos(angle),
+            y: self.center.y + self.radius * transcendental::sin(angle),
+        }
+    }
+
+    /// Return the first derivative at t as a vector starting at the arc point
+    pub fn derivative(&self, t: f32) -> Vector {
+        let angle = (self.start_angle + self.sweep_angle * t).to_radians();
+        let speed = self.radius * self.sweep_angle.to_radians();
+        let point = self.point_at(t);
+        Vector::new(
+            point,
+            Point {
+                x: point.x - speed * transcendental::sin(angle),
+                y: point.y + speed * transcendental::cos(angle),
+            },
+        )
+    }
+
+    /// Return the signed curvature, constant along the arc: the inverse radius, positive for
+    /// counter clockwise and negative for clockwise arcs
+    pub fn curvature_at(&self, _t: f32) -> f32 {
+        if self.radius == 0.0 || self.sweep_angle == 0.0 {
+            return 0.0;
+        }
+        self.sweep_angle.signum() / self.radius
+    }
+
+    pub fn start_point(&self) -> Point {
+        self.point_at(0.0)
+    }
+
+    pub fn end_point(&self) -> Point {
+        self.point_at(1.0)
+    }
+
+    /// Return the tight bounding box, including the extreme points of the circle the arc
+    /// passes through
+    pub fn bounding_box(&self) -> Rect {
+        let mut rect = Rect::new(self.start_point(), self.end_point());
+        let (low, high) = if self.sweep_angle >= 0.0 {
+            (self.start_angle, self.start_angle + self.sweep_angle)
+        } else {
+            (self.start_angle + self.sweep_angle, self.start_angle)
+        };
+        let mut quadrant = (low / 90.0).ceil();
+        while quadrant * 90.0 <= high {
+            let angle = (quadrant * 90.0).to_radians();
+            rect = rect.expand(Point {
+                x: self.center.x + self.radius * transcendental::cos(angle),
+                y: self.center.y + self.radius * transcendental::sin(angle),
+            });
+            quadrant += 1.0;
+        }
+        rect
+    }
+}
@@ -0,0 +1,362 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::sweep::segment_crossings;
+use crate::{
+    snap_round, transcendental, Line, OrderedPoint, Point, Polygon, Rect, Segment, Tolerance,
+    VertexId,
+};
+
+/// Handle of a directed half-edge in an Arrangement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(pub usize);
+
+/// Handle of a bounded face in an Arrangement.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FaceId(pub usize);
+
+#[derive(Debug, Clone, PartialEq)]
+struct HalfEdge {
+    origin: usize,
+    next: usize,
+    face: Option<usize>,
+}
+
+/// Planar subdivision induced by a set of segments, stored as a doubly connected edge list.
+/// Segments are split at all crossings, so edges only meet at vertices. Each edge is stored
+/// as two half-edges of opposite direction, the twin of half-edge i is i ^ 1, and every
+/// half-edge has the face on its left. Bounded faces are traced counter clockwise, the
+/// boundaries of the unbounded face clockwise and they have no FaceId. Components nested
+/// inside a face are not linked to it as holes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Arrangement {
+    vertices: Vec<Point>,
+    half_edges: Vec<HalfEdge>,
+    faces: Vec<usize>,
+}
+
+impl Arrangement {
+    /// Return the arrangement of the segments. Points are merged only when exactly equal.
+    pub fn new(segments: &[Segment]) -> Arrangement {
+        let segments: Vec<Segment> = segments
+            .iter()
+            .filter(|s| s.point_a != s.point_b)
+            .copied()
+            .collect();
+        let mut splits: Vec<Vec<Point>> = segments
+            .iter()
+            .map(|s| vec![s.point_a, s.point_b])
+            .collect();
+        for (i, j, point) in segment_crossings(&segments, Tolerance::default(), |_, _| false) {
+            splits[i].push(point);
+            splits[j].push(point);
+        }
+
+        let mut arrangement = Arrangement::default();
+        let mut ids: HashMap<OrderedPoint, usize> = HashMap::new();
+        let mut edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+        for (segment, mut points) in segments.iter().zip(splits) {
+            let start = segment.point_a;
+            points.sort_by(|a, b| {
+                let da = (a.x - start.x).powi(2) + (a.y - start.y).powi(2);
+                let db = (b.x - start.x).powi(2) + (b.y - start.y).powi(2);
+                da.total_cmp(&db)
+            });
+            let chain: Vec<usize> = points
+                .iter()
+                .map(|p| {
+                    let next = arrangement.vertices.len();
+                    let id = *ids.entry(OrderedPoint(*p)).or_insert(next);
+                    if id == next {
+                        arrangement.vertices.push(*p);
+                    }
+                    id
+                })
+                .collect();
+            for pair in chain.windows(2) {
+                if pair[0] != pair[1] {
+                    edges.insert((pair[0].min(pair[1]), pair[0].max(pair[1])));
+                }
+            }
+        }
+        arrangement.link(&edges);
+        arrangement
+    }
+
+    /// Return the arrangement of the segments after snap rounding them to a grid of the
+    /// given spacing, see snap_round. Crossings that f32 places slightly apart, like three
+    /// lines through one point, end up on the same vertex.
+    pub fn snap_rounded(segments: &[Segment], spacing: f32) -> Arrangement {
+        Arrangement::new(&snap_round(segments, spacing))
+    }
+
+    /// Return the arrangement of the lines clipped to bounds, with the border of bounds
+    /// added so the faces cover the whole rectangle
+    pub fn from_lines(lines: &[Line], bounds: Rect) -> Arrangement {
+        let corners = [
+            bounds.min,
+            Point {
+                x: bounds.max.x,
+                y: bounds.min.y,
+            },
+            bounds.max,
+            Point {
+                x: bounds.min.x,
+                y: bounds.max.y,
+            },
+        ];
+        let mut segments: Vec<Segment> = (0..4)
+            .map(|i| Segment::new(corners[i], corners[(i + 1) % 4]))
+            .collect();
+        segments.extend(lines.iter().filter_map(|l| clip(l, &bounds)));
+        Arrangement::new(&segments)
+    }
+
+    /// Create the half-edges for the undirected edges and connect them around the vertices
+    fn link(&mut self, edges: &BTreeSet<(usize, usize)>) {
+        for &(u, v) in edges {
+            for &origin in &[u, v] {
+                self.half_edges.push(HalfEdge {
+                    origin,
+                    next: 0,
+                    face: None,
+                });
+            }
+        }
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); self.vertices.len()];
+        for (e, half_edge) in self.half_edges.iter().enumerate() {
+            outgoing[half_edge.origin].push(e);
+        }
+        for around in &mut outgoing {
+            around.sort_by(|a, b| self.angle(*a).total_cmp(&self.angle(*b)));
+        }
+        // The successor of a half-edge ending at v is the edge leaving v clockwise next to
+        // its twin, which keeps the face on the left
+        for e in 0..self.half_edges.len() {
+            let twin = e ^ 1;
+            let around = &outgoing[self.half_edges[twin].origin];
+            let position = around.iter().position(|t| *t == twin).unwrap();
+            self.half_edges[e].next = around[(position + around.len() - 1) % around.len()];
+        }
+        let mut visited = vec![false; self.half_edges.len()];
+        for e in 0..self.half_edges.len() {
+            if visited[e] {
+                continue;
+            }
+            let cycle: Vec<usize> = self.cycle(e).collect();
+            for &c in &cycle {
+                visited[c] = true;
+            }
+            if self.signed_area(&cycle) > 0.0 {
+                for &c in &cycle {
+                    self.half_edges[c].face = Some(self.faces.len());
+                }
+                self.faces.push(e);
+            }
+        }
+    }
+
+    /// Direction of the half-edge in radians
+    fn angle(&self, e: usize) -> f32 {
+        let (a, b) = (self.point(e), self.point(e ^ 1));
+        transcendental::atan2(b.y - a.y, b.x - a.x)
+    }
+
+    fn point(&self, e: usize) -> Point {
+        self.vertices[self.half_edges[e].origin]
+    }
+
+    fn cycle(&self, start: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut current = Some(start);
+        std::iter::from_fn(move || {
+            let e = current?;
+            let next = self.half_edges[e].next;
+            current = if next == start { None } else { Some(next) };
+            Some(e)
+        })
+    }
+
+    fn signed_area(&self, cycle: &[usize]) -> f32 {
+        cycle
+            .iter()
+            .map(|e| {
+                let (a, b) = (self.point(*e), self.point(*e ^ 1));
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<f32>()
+            / 2.0
+    }
+
+    /// Iterate over the vertices with their ids
+    pub fn vertices(&self) -> impl Iterator<Item = (VertexId, Point)> + '_ {
+        self.vertices
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (VertexId(i), *p))
+    }
+
+    /// Iterate over all half-edges, two per edge
+    pub fn half_edges(&self) -> impl Iterator<Item = EdgeId> {
+        (0..self.half_edges.len()).map(EdgeId)
+    }
+
+    /// Iterate over every edge once, as a segment from its first to its second vertex
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        (0..self.half_edges.len())
+            .step_by(2)
+            .map(move |e| self.segment(EdgeId(e)))
+    }
+
+    /// Iterate over the bounded faces
+    pub fn faces(&self) -> impl Iterator<Item = FaceId> {
+        (0..self.faces.len()).map(FaceId)
+    }
+
+    pub fn origin(&self, edge: EdgeId) -> VertexId {
+        VertexId(self.half_edges[edge.0].origin)
+    }
+
+    pub fn twin(&self, edge: EdgeId) -> EdgeId {
+        EdgeId(edge.0 ^ 1)
+    }
+
+    /// Return the following half-edge along the boundary of the same face
+    pub fn next(&self, edge: EdgeId) -> EdgeId {
+        EdgeId(self.half_edges[edge.0].next)
+    }
+
+    /// Return the face left of the half-edge, None for the unbounded face
+    pub fn face(&self, edge: EdgeId) -> Option<FaceId> {
+        self.half_edges[edge.0].face.map(FaceId)
+    }
+
+    pub fn segment(&self, edge: EdgeId) -> Segment {
+        Segment::new(self.point(edge.0), self.point(edge.0 ^ 1))
+    }
+
+    /// Iterate over the half-edges around the face in counter clockwise order
+    pub fn face_edges(&self, face: FaceId) -> impl Iterator<Item = EdgeId> + '_ {
+        self.cycle(self.faces[face.0]).map(EdgeId)
+    }
+
+    /// Return the boundary of the face as a counter clockwise polygon
+    pub fn face_polygon(&self, face: FaceId) -> Polygon {
+        Polygon::new(self.face_edges(face).map(|e| self.point(e.0)).collect())
+    }
+
+    /// Return the smallest bounded face containing the point, None if it lies in the
+    /// unbounded face
+    pub fn face_containing(&self, point: Point) -> Option<FaceId> {
+        self.faces()
+            .map(|f| (f, self.face_polygon(f)))
+            .filter(|(_, polygon)| polygon.contains(point))
+            .min_by(|a, b| a.1.signed_area().total_cmp(&b.1.signed_area()))
+            .map(|(f, _)| f)
+    }
+}
+
+/// Return the part of the line inside bounds, None if it misses the rectangle
+fn clip(line: &Line, bounds: &Rect) -> Option<Segment> {
+    let norm = line.a * line.a + line.b * line.b;
+    if norm == 0.0 {
+        return None;
+    }
+    let origin = Point {
+        x: -line.a * line.c / norm,
+        y: -line.b * line.c / norm,
+    };
+    let direction = (-line.b, line.a);
+    let (mut low, mut high) = (f32::NEG_INFINITY, f32::INFINITY);
+    let axes = [
+        (origin.x, direction.0, bounds.min.x, bounds.max.x),
+        (origin.y, direction.1, bounds.min.y, bounds.max.y),
+    ];
+    for &(start, delta, min, max) in &axes {
+        if delta == 0.0 {
+            if start < min || start > max {
+                return None;
+            }
+        } else {
+            let (t0, t1) = ((min - start) / delta, (max - start) / delta);
+            low = low.max(t0.min(t1));
+            high = high.min(t0.max(t1));
+        }
+    }
+    if low >= high {
+        return None;
+    }
+    let at = |t: f32| Point {
+        x: origin.x + direction.0 * t,
+        y: origin.y + direction.1 * t,
+    };
+    Some(Segment::new(at(low), at(high)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_crossed_square() {
+        let segments = [
+            Segment::new(p(0.0, 0.0), p(2.0, 0.0)),
+            Segment::new(p(2.0, 0.0), p(2.0, 2.0)),
+            Segment::new(p(2.0, 2.0), p(0.0, 2.0)),
+            Segment::new(p(0.0, 2.0), p(0.0, 0.0)),
+            Segment::new(p(0.0, 0.0), p(2.0, 2.0)),
+            Segment::new(p(2.0, 0.0), p(0.0, 2.0)),
+        ];
+        let arrangement = Arrangement::new(&segments);
+        assert_eq!(5, arrangement.vertices().count());
+        assert_eq!(8, arrangement.edges().count());
+        assert_eq!(4, arrangement.faces().count());
+        for face in arrangement.faces() {
+            assert!((arrangement.face_polygon(face).signed_area() - 1.0).abs() < 1e-6);
+            for edge in arrangement.face_edges(face) {
+                assert_eq!(Some(face), arrangement.face(edge));
+                assert_eq!(edge, arrangement.twin(arrangement.twin(edge)));
+            }
+        }
+        let bottom = arrangement.face_containing(p(1.0, 0.2)).unwrap();
+        assert!(arrangement.face_polygon(bottom).contains(p(1.0, 0.2)));
+        assert_eq!(None, arrangement.face_containing(p(3.0, 1.0)));
+    }
+
+    #[test]
+    fn test_lines_in_bounds() {
+        let bounds = Rect::new(p(0.0, 0.0), p(4.0, 4.0));
+        let lines = [
+            Line::new(1.0, 0.0, -1.0),
+            Line::new(0.0, 1.0, -3.0),
+            Line::new(1.0, 0.0, -10.0),
+        ];
+        let arrangement = Arrangement::from_lines(&lines, bounds);
+        assert_eq!(4, arrangement.faces().count());
+        let area: f32 = arrangement
+            .faces()
+            .map(|f| arrangement.face_polygon(f).signed_area())
+            .sum();
+        assert!((area - 16.0).abs() < 1e-4);
+        // Dangling edges have the same face on both sides
+        let tree = Arrangement::new(&[Segment::new(p(0.0, 0.0), p(1.0, 0.0))]);
+        assert_eq!(0, tree.faces().count());
+        assert_eq!(EdgeId(1), tree.next(EdgeId(0)));
+    }
+
+    #[test]
+    fn test_snap_rounded_concurrent_lines() {
+        // Three lines through (1, 1) whose pairwise crossings differ slightly in f32
+        let segments = [
+            Segment::new(p(0.0, 0.0), p(3.0, 3.0)),
+            Segment::new(p(0.0, 1.0), p(3.0, 1.0)),
+            Segment::new(p(0.3, 2.4), p(1.7, -0.4)),
+        ];
+        let arrangement = Arrangement::snap_rounded(&segments, 0.1);
+        let center = arrangement
+            .vertices()
+            .filter(|(_, v)| (v.x - 1.0).abs() < 0.2 && (v.y - 1.0).abs() < 0.2)
+            .count();
+        assert_eq!(1, center);
+        assert_eq!(6, arrangement.edges().count());
+    }
+}
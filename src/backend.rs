@@ -0,0 +1,164 @@
+//! Interchangeable implementations of the trigonometric functions. StdTrig uses the exact
+//! functions of the standard library; with the fast-math feature FastTrig trades about 0.1
+//! degrees of accuracy for polynomial approximations that are much cheaper on
+//! microcontrollers without a floating point unit. With the deterministic feature LibmTrig
+//! gives bit identical results on every platform. Like the standard library the backends
+//! work in radians.
+//!
+//! A backend only applies where it is passed: to its own methods and to Angle::sin_cos_with
+//! and Angle::atan2_with. The solvers, hulls, triangulations and polygon routines of the
+//! crate always use the standard library, or libm with the deterministic feature, so picking
+//! FastTrig does not make them cheaper.
+
+/// Trigonometric functions of one backend.
+pub trait TrigBackend {
+    fn sin(&self, radians: f32) -> f32;
+    fn cos(&self, radians: f32) -> f32;
+    /// Return the angle in radians of the direction (x, y), in [-pi, pi]
+    fn atan2(&self, y: f32, x: f32) -> f32;
+
+    fn sin_cos(&self, radians: f32) -> (f32, f32) {
+        (self.sin(radians), self.cos(radians))
+    }
+}
+
+/// Exact backend using the functions of the standard library.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct StdTrig;
+
+impl TrigBackend for StdTrig {
+    fn sin(&self, radians: f32) -> f32 {
+        radians.sin()
+    }
+
+    fn cos(&self, radians: f32) -> f32 {
+        radians.cos()
+    }
+
+    fn atan2(&self, y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    fn sin_cos(&self, radians: f32) -> (f32, f32) {
+        radians.sin_cos()
+    }
+}
+
+/// Approximating backend: sin and cos by a Taylor polynomial of degree 7 after range
+/// reduction, accurate to 2e-4, and atan2 by a rational fit accurate to 0.1 degrees.
+#[cfg(feature = "fast-math")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct FastTrig;
+
+#[cfg(feature = "fast-math")]
+impl TrigBackend for FastTrig {
+    fn sin(&self, radians: f32) -> f32 {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        // Reduce to [-pi, pi], then mirror into [-pi/2, pi/2] where the polynomial is good
+        let turns = (radians / (2.0 * PI)).round();
+        let mut x = radians - turns * 2.0 * PI;
+        if x > FRAC_PI_2 {
+            x = PI - x;
+        } else if x < -FRAC_PI_2 {
+            x = -PI - x;
+        }
+        let x2 = x * x;
+        x * (1.0 - x2 / 6.0 * (1.0 - x2 / 20.0 * (1.0 - x2 / 42.0)))
+    }
+
+    fn cos(&self, radians: f32) -> f32 {
+        self.sin(radians + std::f32::consts::FRAC_PI_2)
+    }
+
+    fn atan2(&self, y: f32, x: f32) -> f32 {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        if x == 0.0 && y == 0.0 {
+            return 0.0;
+        }
+        // atan on [-1, 1], swapping the axes for steeper directions
+        let atan = |z: f32| FRAC_PI_4 * z - z * (z.abs() - 1.0) * (0.2447 + 0.0663 * z.abs());
+        if x.abs() >= y.abs() {
+            let angle = atan(y / x);
+            if x >= 0.0 {
+                angle
+            } else if y >= 0.0 {
+                angle + PI
+            } else {
+                angle - PI
+            }
+        } else {
+            let angle = -atan(x / y);
+            if y > 0.0 {
+                angle + FRAC_PI_2
+            } else {
+                angle - FRAC_PI_2
+            }
+        }
+    }
+}
+
+/// Backend using the software implementations of libm, which give the same bits on every
+/// platform. The deterministic feature also routes every transcendental function the crate
+/// itself calls through libm.
+#[cfg(feature = "deterministic")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct LibmTrig;
+
+#[cfg(feature = "deterministic")]
+impl TrigBackend for LibmTrig {
+    fn sin(&self, radians: f32) -> f32 {
+        libm::sinf(radians)
+    }
+
+    fn cos(&self, radians: f32) -> f32 {
+        libm::cosf(radians)
+    }
+
+    fn atan2(&self, y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+
+    fn sin_cos(&self, radians: f32) -> (f32, f32) {
+        libm::sincosf(radians)
+    }
+}
+
+impl crate::Angle {
+    /// Return sine and cosine of the angle computed by the backend
+    pub fn sin_cos_with<B: TrigBackend>(&self, backend: &B) -> (f32, f32) {
+        backend.sin_cos(self.radians())
+    }
+
+    /// Return the direction of (x, y) computed by the backend
+    pub fn atan2_with<B: TrigBackend>(y: f32, x: f32, backend: &B) -> crate::Angle {
+        crate::Angle::from_radians(backend.atan2(y, x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Angle;
+
+    #[test]
+    fn test_std_backend() {
+        let (sin, cos) = Angle::from_degrees(30.0).sin_cos_with(&StdTrig);
+        assert!((sin - 0.5).abs() < 1e-6);
+        assert!((cos - 0.75f32.sqrt()).abs() < 1e-6);
+        let angle = Angle::atan2_with(-1.0, -1.0, &StdTrig);
+        assert!((angle.degrees() + 135.0).abs() < 1e-4);
+    }
+
+    #[cfg(feature = "fast-math")]
+    #[test]
+    fn test_fast_backend_matches_std() {
+        for i in -720..=720 {
+            let radians = (i as f32).to_radians();
+            assert!((FastTrig.sin(radians) - radians.sin()).abs() < 2e-4);
+            assert!((FastTrig.cos(radians) - radians.cos()).abs() < 2e-4);
+            let (x, y) = (radians.cos() * 3.0, radians.sin() * 3.0);
+            let difference = Angle::from_radians(FastTrig.atan2(y, x) - y.atan2(x));
+            assert!(difference.normalized().degrees().abs() < 0.1);
+        }
+    }
+}
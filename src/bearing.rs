@@ -0,0 +1,80 @@
+//! Compass bearings measure clockwise from north, while Angle measures counter clockwise
+//! from the +x axis (east). Both are in degrees.
+
+use crate::Angle;
+
+/// Number of named directions on the compass rose.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CompassPoints {
+    /// N, E, S, W
+    Four,
+    /// Adds NE, SE, SW, NW
+    Eight,
+    /// Adds NNE, ENE and the other points in between
+    Sixteen,
+}
+
+const NAMES: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+impl Angle {
+    /// Return the direction of the compass bearing, where 0 is north and 90 east
+    pub fn from_bearing(bearing: f32) -> Angle {
+        Angle::from_degrees(90.0 - bearing).normalized()
+    }
+
+    /// Return the compass bearing of the direction in [0, 360), where 0 is north and 90 east
+    pub fn bearing(&self) -> f32 {
+        let bearing = (90.0 - self.degrees()).rem_euclid(360.0);
+        // rem_euclid can round up to the modulus for tiny negative values
+        if bearing >= 360.0 {
+            0.0
+        } else {
+            bearing
+        }
+    }
+
+    /// Return the name of the nearest compass point, like "NE" for 45 degrees
+    pub fn compass_point(&self, points: CompassPoints) -> &'static str {
+        let count = match points {
+            CompassPoints::Four => 4,
+            CompassPoints::Eight => 8,
+            CompassPoints::Sixteen => 16,
+        };
+        let sector = (self.bearing() / (360.0 / count as f32)).round() as usize % count;
+        NAMES[sector * (16 / count)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearing_conversion() {
+        assert_eq!(0.0, Angle::from_degrees(90.0).bearing());
+        assert_eq!(90.0, Angle::from_degrees(0.0).bearing());
+        assert_eq!(270.0, Angle::from_degrees(-180.0).bearing());
+        assert_eq!(315.0, Angle::from_degrees(135.0).bearing());
+        assert_eq!(Angle::from_degrees(-90.0), Angle::from_bearing(180.0));
+        assert_eq!(Angle::from_degrees(45.0), Angle::from_bearing(45.0));
+    }
+
+    #[test]
+    fn test_compass_points() {
+        let north_east = Angle::from_bearing(40.0);
+        assert_eq!("N", north_east.compass_point(CompassPoints::Four));
+        assert_eq!("NE", north_east.compass_point(CompassPoints::Eight));
+        assert_eq!("NE", north_east.compass_point(CompassPoints::Sixteen));
+        assert_eq!(
+            "NNW",
+            Angle::from_bearing(340.0).compass_point(CompassPoints::Sixteen)
+        );
+        assert_eq!(
+            "N",
+            Angle::from_bearing(355.0).compass_point(CompassPoints::Eight)
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use crate::curve::signed_curvature;
+use crate::{Point, Rect, Vector};
+
+/// Maximum recursion depth when flattening, bounding the output to 2^16 segments.
+const MAX_FLATTEN_DEPTH: u32 = 16;
+
+/// Quadratic Bézier curve from start to end, pulled towards a single control point.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct QuadraticBezier {
+    pub start: Point,
+    pub control: Point,
+    pub end: Point,
+}
+
+/// Cubic Bézier curve from start to end with two control points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CubicBezier {
+    pub start: Point,
+    pub control_a: Point,
+    pub control_b: Point,
+    pub end: Point,
+}
+
+impl QuadraticBezier {
+    pub fn new(start: Point, control: Point, end: Point) -> QuadraticBezier {
+        QuadraticBezier {
+            start,
+            control,
+            end,
+        }
+    }
+
+    /// Return the point on the curve at parameter t, where t = 0 is start and t = 1 is end
+    pub fn point_at(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        Point {
+            x: mt * mt * self.start.x + 2.0 * mt * t * self.control.x + t * t * self.end.x,
+            y: mt * mt * self.start.y + 2.0 * mt * t * self.control.y + t * t * self.end.y,
+        }
+    }
+
+    /// Return the first derivative at t as a vector starting at the curve point
+    pub fn derivative(&self, t: f32) -> Vector {
+        let (dx, dy) = self.derivative_components(t);
+        anchored(self.point_at(t), dx, dy)
+    }
+
+    fn derivative_components(&self, t: f32) -> (f32, f32) {
+        let mt = 1.0 - t;
+        (
+            2.0 * mt * (self.control.x - self.start.x) + 2.0 * t * (self.end.x - self.control.x),
+            2.0 * mt * (self.control.y - self.start.y) + 2.0 * t * (self.end.y - self.control.y),
+        )
+    }
+
+    /// Return the unit length tangent at t as a vector starting at the curve point
+    pub fn tangent(&self, t: f32) -> Vector {
+        normalized(self.derivative(t))
+    }
+
+    /// Return the signed curvature at t, positive where the curve turns counter clockwise.
+    /// The radius of the osculating circle is the inverse of the curvature.
+    pub fn curvature_at(&self, t: f32) -> f32 {
+        let (dx, dy) = self.derivative_components(t);
+        let ddx = 2.0 * (self.start.x - 2.0 * self.control.x + self.end.x);
+        let ddy = 2.0 * (self.start.y - 2.0 * self.control.y + self.end.y);
+        signed_curvature(dx, dy, ddx, ddy)
+    }
+
+    /// Return the tight bounding box of the curve, not just of its control points
+    pub fn bounding_box(&self) -> Rect {
+        let mut rect = Rect::new(self.start, self.end);
+        let ts = [
+            linear_root(self.start.x, self.control.x, self.end.x),
+            linear_root(self.start.y, self.control.y, self.end.y),
+        ];
+        for t in ts.iter().flatten() {
+            rect = rect.expand(self.point_at(*t));
+        }
+        rect
+    }
+
+    /// Split the curve at t using de Casteljau's algorithm, returning the part before and
+    /// after t
+    pub fn split(&self, t: f32) -> (QuadraticBezier, QuadraticBezier) {
+        let p01 = lerp(self.start, self.control, t);
+        let p12 = lerp(self.control, self.end, t);
+        let mid = lerp(p01, p12, t);
+        (
+            QuadraticBezier::new(self.start, p01, mid),
+            QuadraticBezier::new(mid, p12, self.end),
+        )
+    }
+
+    /// Approximate the curve by a polyline whose points deviate at most tolerance from the
+    /// curve. The first point is start and the last point is end.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Point> {
+        let mut points = vec![self.start];
+        self.flatten_into(tolerance, MAX_FLATTEN_DEPTH, &mut points);
+        points
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, points: &mut Vec<Point>) {
+        // The curve never strays further from its chord than half the control point distance
+        if depth == 0 || distance_to_chord(self.control, self.start, self.end) * 0.5 <= tolerance {
+            points.push(self.end);
+        } else {
+            let (first, second) = self.split(0.5);
+            first.flatten_into(tolerance, depth - 1, points);
+            second.flatten_into(tolerance, depth - 1, points);
+        }
+    }
+}
+
+impl CubicBezier {
+    pub fn new(start: Point, control_a: Point, control_b: Point, end: Point) -> CubicBezier {
+        CubicBezier {
+            start,
+            control_a,
+            control_b,
+            end,
+        }
+    }
+
+    /// Return the cubic Hermite curve from start to end, leaving start along start_tangent
+    /// and arriving at end along end_tangent. Only the direction and magnitude of the
+    /// tangents matter, not where the vectors are placed. Longer tangents make the curve
+    /// follow their direction for longer.
+    pub fn hermite(
+        start: Point,
+        start_tangent: Vector,
+        end: Point,
+        end_tangent: Vector,
+    ) -> CubicBezier {
+        CubicBezier::new(
+            start,
+            Point {
+                x: start.x + start_tangent.dx() / 3.0,
+                y: start.y + start_tangent.dy() / 3.0,
+            },
+            Point {
+                x: end.x - end_tangent.dx() / 3.0,
+                y: end.y - end_tangent.dy() / 3.0,
+            },
+            end,
+        )
+    }
+
+    /// Return the point on the curve at parameter t, where t = 0 is start and t = 1 is end
+    pub fn point_at(&self, t: f32) -> Point {
+        let mt = 1.0 - t;
+        let (w0, w1, w2, w3) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+        Point {
+            x: w0 * self.start.x + w1 * self.control_a.x + w2 * self.control_b.x + w3 * self.end.x,
+            y: w0 * self.start.y + w1 * self.control_a.y + w2 * self.control_b.y + w3 * self.end.y,
+        }
+    }
+
+    /// Return the first derivative at t as a vector starting at the curve point
+    pub fn derivative(&self, t: f32) -> Vector {
+        let (dx, dy) = self.derivative_components(t);
+        anchored(self.point_at(t), dx, dy)
+    }
+
+    fn derivative_components(&self, t: f32) -> (f32, f32) {
+        let mt = 1.0 - t;
+        let (w0, w1, w2) = (3.0 * mt * mt, 6.0 * mt * t, 3.0 * t * t);
+        (
+            w0 * (self.control_a.x - self.start.x)
+                + w1 * (self.control_b.x - self.control_a.x)
+                + w2 * (self.end.x - self.control_b.x),
+            w0 * (self.control_a.y - self.start.y)
+                + w1 * (self.control_b.y - self.control_a.y)
+                + w2 * (self.end.y - self.control_b.y),
+        )
+    }
+
+    /// Return the unit length tangent at t as a vector starting at the curve point
+    pub fn tangent(&self, t: f32) -> Vector {
+        normalized(self.derivative(t))
+    }
+
+    /// Return the signed curvature at t, positive where the curve turns counter clockwise.
+    /// The radius of the osculating circle is the inverse of the curvature.
+    pub fn curvature_at(&self, t: f32) -> f32 {
+        let (dx, dy) = self.derivative_components(t);
+        let mt = 1.0 - t;
+        let ddx = 6.0 * mt * (self.start.x - 2.0 * self.control_a.x + self.control_b.x)
+            + 6.0 * t * (self.control_a.x - 2.0 * self.control_b.x + self.end.x);
+        let ddy = 6.0 * mt * (self.start.y - 2.0 * self.control_a.y + self.control_b.y)
+            + 6.0 * t * (self.control_a.y - 2.0 * self.control_b.y + self.end.y);
+        signed_curvature(dx, dy, ddx, ddy)
+    }
+
+    /// Return the tight bounding box of the curve, not just of its control points
+    pub fn bounding_box(&self) -> Rect {
+        let mut rect = Rect::new(self.start, self.end);
+        let xs = quadratic_roots(self.start.x, self.control_a.x, self.control_b.x, self.end.x);
+        let ys = quadratic_roots(self.start.y, self.control_a.y, self.control_b.y, self.end.y);
+        for t in xs.iter().chain(ys.iter()).flatten() {
+            rect = rect.expand(self.point_at(*t));
+        }
+        rect
+    }
+
+    /// Split the curve at t using de Casteljau's algorithm, returning the part before and
+    /// after t
+    pub fn split(&self, t: f32) -> (CubicBezier, CubicBezier) {
+        let p01 = lerp(self.start, self.control_a, t);
+        let p12 = lerp(self.control_a, self.control_b, t);
+        let p23 = lerp(self.control_b, self.end, t);
+        let p012 = lerp(p01, p12, t);
+        let p123 = lerp(p12, p23, t);
+        let mid = lerp(p012, p123, t);
+        (
+            CubicBezier::new(self.start, p01, p012, mid),
+            CubicBezier::new(mid, p123, p23, self.end),
+        )
+    }
+
+    /// Approximate the curve by a polyline whose points deviate at most tolerance from the
+    /// curve. The first point is start and the last point is end.
+    pub fn flatten(&self, tolerance: f32) -> Vec<Point> {
+        let mut points = vec![self.start];
+        self.flatten_into(tolerance, MAX_FLATTEN_DEPTH, &mut points);
+        points
+    }
+
+    fn flatten_into(&self, tolerance: f32, depth: u32, points: &mut Vec<Point>) {
+        // The curve never strays further from its chord than 3/4 of the control point distance
+        let flatness = distance_to_chord(self.control_a, self.start, self.end)
+            .max(distance_to_chord(self.control_b, self.start, self.end))
+            * 0.75;
+        if depth == 0 || flatness <= tolerance {
+            points.push(self.end);
+        } else {
+            let (first, second) = self.split(0.5);
+            first.flatten_into(tolerance, depth - 1, points);
+            second.flatten_into(tolerance, depth - 1, points);
+        }
+    }
+}
+
+impl From<QuadraticBezier> for CubicBezier {
+    /// Degree elevation, the cubic describes exactly the same curve
+    fn from(q: QuadraticBezier) -> CubicBezier {
+        CubicBezier::new(
+            q.start,
+            lerp(q.start, q.control, 2.0 / 3.0),
+            lerp(q.end, q.control, 2.0 / 3.0),
+            q.end,
+        )
+    }
+}
+
+fn lerp(a: Point, b: Point, t: f32) -> Point {
+    Point {
+        x: a.x + (b.x - a.x) * t,
+        y: a.y + (b.y - a.y) * t,
+    }
+}
+
+fn anchored(point: Point, dx: f32, dy: f32) -> Vector {
+    Vector::new(
+        point,
+        Point {
+            x: point.x + dx,
+            y: point.y + dy,
+        },
+    )
+}
+
+fn normalized(mut v: Vector) -> Vector {
+    let length = v.length();
+    if length == 0.0 {
+        return v;
+    }
+    let a = v.point_a();
+    let b = v.point_b();
+    anchored(a, (b.x - a.x) / length, (b.y - a.y) / length)
+}
+
+/// Distance of point p from the line through a and b, or from a if a and b coincide
+fn distance_to_chord(p: Point, a: Point, b: Point) -> f32 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == 0.0 {
+        return Vector::new(a, p).length();
+    }
+    ((p.x - a.x) * dy - (p.y - a.y) * dx).abs() / length
+}
+
+/// Parameter in (0, 1) where the derivative of a quadratic coordinate vanishes
+fn linear_root(p0: f32, p1: f32, p2: f32) -> Option<f32> {
+    let denominator = p0 - 2.0 * p1 + p2;
+    if denominator == 0.0 {
+        return None;
+    }
+    let t = (p0 - p1) / denominator;
+    if t > 0.0 && t < 1.0 {
+        Some(t)
+    } else {
+        None
+    }
+}
+
+/// Parameters in (0, 1) where the derivative of a cubic coordinate vanishes
+fn quadratic_roots(p0: f32, p1: f32, p2: f32, p3: f32) -> [Option<f32>; 2] {
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+    let c = p1 - p0;
+    let inside = |t: f32| if t > 0.0 && t < 1.0 { Some(t) } else { None };
+    if a.abs() < 1e-12 {
+        if b == 0.0 {
+            return [None, None];
+        }
+        return [inside(-c / b), None];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return [None, None];
+    }
+    let root = discriminant.sqrt();
+    [
+        inside((-b + root) / (2.0 * a)),
+        inside((-b - root) / (2.0 * a)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_quadratic_point_and_bounding_box() {
+        let q = QuadraticBezier::new(p(0.0, 0.0), p(1.0, 2.0), p(2.0, 0.0));
+        assert_eq!(p(1.0, 1.0), q.point_at(0.5));
+        let bbox = q.bounding_box();
+        assert_eq!(p(0.0, 0.0), bbox.min);
+        assert_eq!(p(2.0, 1.0), bbox.max);
+        let mut tangent = q.tangent(0.5);
+        assert_eq!(1.0, tangent.length());
+        assert_eq!(p(2.0, 1.0), tangent.point_b());
+    }
+
+    #[test]
+    fn test_cubic_split_matches_original() {
+        let c = CubicBezier::new(p(0.0, 0.0), p(0.0, 3.0), p(4.0, 3.0), p(4.0, 0.0));
+        let (first, second) = c.split(0.25);
+        assert_eq!(c.start, first.start);
+        assert_eq!(c.end, second.end);
+        let expected = c.point_at(0.625);
+        let result = second.point_at(0.5);
+        assert!((expected.x - result.x).abs() < 1e-5);
+        assert!((expected.y - result.y).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_hermite_matches_tangents() {
+        let start_tangent = Vector::new(p(5.0, 5.0), p(11.0, 5.0));
+        let end_tangent = Vector::new(p(0.0, 0.0), p(0.0, -3.0));
+        let c = CubicBezier::hermite(p(0.0, 0.0), start_tangent, p(4.0, 2.0), end_tangent);
+        assert_eq!(p(0.0, 0.0), c.point_at(0.0));
+        assert_eq!(p(4.0, 2.0), c.point_at(1.0));
+        let start = c.derivative(0.0);
+        assert_eq!((6.0, 0.0), (start.dx(), start.dy()));
+        let end = c.derivative(1.0);
+        assert_eq!((0.0, -3.0), (end.dx(), end.dy()));
+    }
+
+    #[test]
+    fn test_cubic_flatten_within_tolerance() {
+        let c = CubicBezier::new(p(0.0, 0.0), p(0.0, 3.0), p(4.0, 3.0), p(4.0, 0.0));
+        let points = c.flatten(0.01);
+        assert_eq!(c.start, points[0]);
+        assert_eq!(c.end, *points.last().unwrap());
+        for i in 0..=20 {
+            let on_curve = c.point_at(i as f32 / 20.0);
+            let nearest = points
+                .windows(2)
+                .map(|w| {
+                    let mut v = Vector::new(w[0], on_curve);
+                    v.length().min(distance_to_chord(on_curve, w[0], w[1]))
+                })
+                .fold(f32::MAX, f32::min);
+            assert!(nearest <= 0.01);
+        }
+        let bbox = c.bounding_box();
+        assert!((bbox.max.y - 2.25).abs() < 1e-5);
+    }
+}
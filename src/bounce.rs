@@ -0,0 +1,134 @@
+use crate::{raycast, Point, Polyline, Ray, RayHit, Shape, Vector};
+
+/// Distance by which a reflected ray starts off the wall, so it does not hit it again.
+const SURFACE_OFFSET: f32 = 1e-4;
+
+/// Iterator over the successive wall hits of a ray reflecting off walls, see bounces.
+pub struct Bounces<'a, S: Shape> {
+    walls: &'a [S],
+    ray: Option<Ray>,
+}
+
+/// Return an iterator over the hits of a ray mirrored at every wall it meets. The
+/// iterator ends when the ray escapes, it never ends inside closed walls, so limit it with
+/// take or use trace_bounces.
+pub fn bounces<S: Shape>(ray: Ray, walls: &[S]) -> Bounces<'_, S> {
+    Bounces {
+        walls,
+        ray: Some(ray),
+    }
+}
+
+impl<'a, S: Shape> Bounces<'a, S> {
+    /// Return the ray that will be traced next, None once the ray escaped
+    pub fn ray(&self) -> Option<Ray> {
+        self.ray
+    }
+}
+
+impl<'a, S: Shape> Iterator for Bounces<'a, S> {
+    type Item = RayHit;
+
+    fn next(&mut self) -> Option<RayHit> {
+        let ray = self.ray?;
+        let hit = raycast(&ray, self.walls);
+        self.ray = hit.map(|h| reflect(&ray, &h));
+        hit
+    }
+}
+
+/// Trace a ray bouncing off the walls until it was reflected max_bounces times or travelled
+/// max_distance, whichever comes first, and return the travelled path. The path starts at
+/// the ray origin and contains every hit point. If the ray escapes or the distance runs out
+/// first, the path ends where max_distance is used up, unless max_distance is infinite.
+pub fn trace_bounces<S: Shape>(
+    ray: Ray,
+    walls: &[S],
+    max_bounces: usize,
+    max_distance: f32,
+) -> Polyline {
+    let mut points = vec![ray.origin];
+    let mut remaining = max_distance;
+    let mut iterator = bounces(ray, walls);
+    let mut current = ray;
+    for _ in 0..max_bounces {
+        match iterator.next() {
+            Some(hit) if hit.distance < remaining => {
+                points.push(hit.point);
+                remaining -= hit.distance;
+                if let Some(next) = iterator.ray() {
+                    current = next;
+                }
+            }
+            _ => {
+                if remaining.is_finite() {
+                    points.push(current.point_at(remaining));
+                }
+                break;
+            }
+        }
+    }
+    Polyline::new(points)
+}
+
+/// Mirror the ray at the hit normal, starting the new ray just off the wall
+fn reflect(ray: &Ray, hit: &RayHit) -> Ray {
+    let direction = ray.direction();
+    let (dx, dy) = (direction.dx(), direction.dy());
+    let (nx, ny) = (hit.normal.dx(), hit.normal.dy());
+    let dot = dx * nx + dy * ny;
+    let origin = Point {
+        x: hit.point.x + nx * SURFACE_OFFSET,
+        y: hit.point.y + ny * SURFACE_OFFSET,
+    };
+    Ray::new(
+        origin,
+        Vector::new(
+            origin,
+            Point {
+                x: origin.x + dx - 2.0 * dot * nx,
+                y: origin.y + dy - 2.0 * dot * ny,
+            },
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Polygon;
+
+    fn close(a: Point, b: Point) -> bool {
+        (a.x - b.x).abs() < 1e-3 && (a.y - b.y).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_bounces_in_square() {
+        let table = [Polygon::new(vec![
+            p(0.0, 0.0),
+            p(4.0, 0.0),
+            p(4.0, 4.0),
+            p(0.0, 4.0),
+        ])];
+        let ray = Ray::from_angle(p(2.0, 0.5), 45.0);
+        let hits: Vec<RayHit> = bounces(ray, &table).take(3).collect();
+        assert!(close(p(4.0, 2.5), hits[0].point));
+        assert!(close(p(2.5, 4.0), hits[1].point));
+        assert!(close(p(0.0, 1.5), hits[2].point));
+        let path = trace_bounces(ray, &table, 2, f32::INFINITY);
+        assert_eq!(3, path.points.len());
+        let path = trace_bounces(ray, &table, 10, 2f32.sqrt());
+        assert_eq!(2, path.points.len());
+        assert!(close(p(3.0, 1.5), path.points[1]));
+    }
+
+    #[test]
+    fn test_escaping_ray() {
+        let walls = [crate::Segment::new(p(2.0, -1.0), p(2.0, 1.0))];
+        let path = trace_bounces(Ray::from_angle(p(0.0, 0.0), 0.0), &walls, 5, 10.0);
+        assert_eq!(3, path.points.len());
+        assert!(close(p(2.0, 0.0), path.points[1]));
+        assert!(close(p(-6.0, 0.0), path.points[2]));
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{transcendental, Angle, Point, Polyline};
+
+/// Curve of a cable or chain hanging between two supports, y = offset + a cosh((x - x0) / a).
+/// The parameter a is the ratio of horizontal tension to weight per length; small values sag
+/// deeply, large values approach the straight chord.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Catenary {
+    pub start: Point,
+    pub end: Point,
+    a: f32,
+    x0: f32,
+    offset: f32,
+}
+
+impl Catenary {
+    /// Return the catenary between the supports that hangs sag below the middle of the chord.
+    /// None if the supports are vertically aligned or the sag is not positive.
+    pub fn with_sag(start: Point, end: Point, sag: f32) -> Option<Catenary> {
+        if sag <= 0.0 {
+            return None;
+        }
+        let span = (end.x - start.x).abs() as f64;
+        let middle = (start.x + end.x) as f64 / 2.0;
+        let a = solve(span, |a| {
+            let (x0, offset) = parameters(start, end, a);
+            let y = offset + a * transcendental::cosh((middle - x0) / a);
+            (start.y + end.y) as f64 / 2.0 - y - sag as f64
+        })?;
+        Some(Catenary::through(start, end, a))
+    }
+
+    /// Return the catenary of a cable with the given length between the supports. None if
+    /// the supports are vertically aligned or the cable is not longer than their distance.
+    pub fn with_length(start: Point, end: Point, length: f32) -> Option<Catenary> {
+        let (h, v) = ((end.x - start.x).abs() as f64, (end.y - start.y) as f64);
+        let length = length as f64;
+        if length * length <= h * h + v * v {
+            return None;
+        }
+        let target = (length * length - v * v).sqrt();
+        let a = solve(h, |a| {
+            2.0 * a * transcendental::sinh(h / (2.0 * a)) - target
+        })?;
+        Some(Catenary::through(start, end, a))
+    }
+
+    /// Catenary with parameter a through both points, which must differ in x
+    fn through(start: Point, end: Point, a: f32) -> Catenary {
+        let (x0, offset) = parameters(start, end, a as f64);
+        Catenary {
+            start,
+            end,
+            a,
+            x0: x0 as f32,
+            offset: offset as f32,
+        }
+    }
+
+    /// Return the height of the curve above x
+    pub fn y_at(&self, x: f32) -> f32 {
+        self.offset + self.a * transcendental::cosh((x - self.x0) / self.a)
+    }
+
+    /// Return the inclination of the curve at x, positive where it rises to the right
+    pub fn slope_at(&self, x: f32) -> Angle {
+        Angle::from_radians(transcendental::atan(transcendental::sinh(
+            (x - self.x0) / self.a,
+        )))
+    }
+
+    /// Return the lowest point of the curve between the supports
+    pub fn lowest_point(&self) -> Point {
+        let x = self
+            .x0
+            .clamp(self.start.x.min(self.end.x), self.start.x.max(self.end.x));
+        Point { x, y: self.y_at(x) }
+    }
+
+    /// Return the length of the cable between the supports
+    pub fn length(&self) -> f32 {
+        let arc = |x: f32| self.a * transcendental::sinh((x - self.x0) / self.a);
+        (arc(self.end.x) - arc(self.start.x)).abs()
+    }
+
+    /// Return the curve as a polyline of segments pieces of equal horizontal width
+    pub fn to_polyline(&self, segments: usize) -> Polyline {
+        let segments = segments.max(1);
+        let points = (0..=segments)
+            .map(|i| {
+                let x = self.start.x + (self.end.x - self.start.x) * i as f32 / segments as f32;
+                Point { x, y: self.y_at(x) }
+            })
+            .collect();
+        Polyline::new(points)
+    }
+}
+
+/// Return x0 and offset of the catenary with parameter a through start and end
+fn parameters(start: Point, end: Point, a: f64) -> (f64, f64) {
+    let (x1, y1) = (start.x as f64, start.y as f64);
+    let (x2, y2) = (end.x as f64, end.y as f64);
+    let half_span = (x2 - x1) / (2.0 * a);
+    let x0 = (x1 + x2) / 2.0
+        - a * transcendental::asinh((y2 - y1) / (2.0 * a * transcendental::sinh(half_span)));
+    (x0, y1 - a * transcendental::cosh((x1 - x0) / a))
+}
+
+/// Find the parameter a where the decreasing function f crosses zero, by bisection over a
+/// logarithmic range scaled to the span. None for a zero span or if f has no root.
+fn solve(span: f64, f: impl Fn(f64) -> f64) -> Option<f32> {
+    if span == 0.0 {
+        return None;
+    }
+    let (mut low, mut high) = (
+        transcendental::ln(span * 1e-3),
+        transcendental::ln(span * 1e7),
+    );
+    if !(f(transcendental::exp(low)) > 0.0 && f(transcendental::exp(high)) < 0.0) {
+        return None;
+    }
+    for _ in 0..100 {
+        let middle = (low + high) / 2.0;
+        if f(transcendental::exp(middle)) > 0.0 {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(transcendental::exp((low + high) / 2.0) as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_level_supports() {
+        let cable = Catenary::with_sag(p(0.0, 10.0), p(20.0, 10.0), 2.0).unwrap();
+        let lowest = cable.lowest_point();
+        assert!((lowest.x - 10.0).abs() < 1e-3);
+        assert!((lowest.y - 8.0).abs() < 1e-3);
+        assert!((cable.y_at(0.0) - 10.0).abs() < 1e-3);
+        assert!(cable.slope_at(20.0).degrees() > 0.0);
+        assert!(cable.length() > 20.0);
+        assert_eq!(None, Catenary::with_sag(p(0.0, 0.0), p(0.0, 5.0), 1.0));
+    }
+
+    #[test]
+    fn test_given_length() {
+        let (start, end) = (p(0.0, 0.0), p(10.0, 4.0));
+        let cable = Catenary::with_length(start, end, 15.0).unwrap();
+        assert!((cable.length() - 15.0).abs() < 1e-3);
+        assert!((cable.y_at(10.0) - 4.0).abs() < 1e-3);
+        assert!((cable.to_polyline(200).length() - 15.0).abs() < 0.01);
+        assert_eq!(None, Catenary::with_length(start, end, 5.0));
+    }
+}
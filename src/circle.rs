@@ -0,0 +1,13 @@
+use crate::Point;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: f32) -> Circle {
+        Circle { center, radius }
+    }
+}
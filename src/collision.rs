@@ -0,0 +1,352 @@
+use crate::{Circle, Point, Polygon, Ray, RayHit, Segment, Shape, Vector};
+
+/// Circle swept along a segment: all points within radius of the segment.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Capsule {
+    pub segment: Segment,
+    pub radius: f32,
+}
+
+/// Overlap between a moving body (circle or capsule) and an obstacle. The normal has unit
+/// length, starts at the contact point on the obstacle and points towards the body, so
+/// moving the body by normal * depth resolves the overlap.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Contact {
+    pub point: Point,
+    pub normal: Vector,
+    pub depth: f32,
+}
+
+/// First touch of a circle moving along a motion vector. t is the fraction of the motion
+/// in [0, 1] after which the circle touches the obstacle, point lies on the obstacle and the
+/// unit normal starts at point and points towards the circle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TimeOfImpact {
+    pub t: f32,
+    pub point: Point,
+    pub normal: Vector,
+}
+
+impl Capsule {
+    pub fn new(segment: Segment, radius: f32) -> Capsule {
+        Capsule { segment, radius }
+    }
+}
+
+/// Return the contact between a circle and a segment, None if they do not overlap
+pub fn circle_segment(circle: &Circle, segment: &Segment) -> Option<Contact> {
+    let closest = segment.closest_point(circle.center);
+    let distance = Vector::new(closest, circle.center).length();
+    if distance >= circle.radius {
+        return None;
+    }
+    let (nx, ny) = if distance > 0.0 {
+        (
+            (circle.center.x - closest.x) / distance,
+            (circle.center.y - closest.y) / distance,
+        )
+    } else {
+        segment_normal(segment)
+    };
+    Some(contact(closest, nx, ny, circle.radius - distance))
+}
+
+/// Return the contact between a capsule and a segment, None if they do not overlap. If the
+/// capsule axis crosses the segment, the segment is treated as an infinite wall and the
+/// capsule is pushed to the side of the wall its midpoint is on.
+pub fn capsule_segment(capsule: &Capsule, segment: &Segment) -> Option<Contact> {
+    let (on_capsule, on_segment) = capsule.segment.closest_points(segment);
+    let distance = Vector::new(on_segment, on_capsule).length();
+    if distance >= capsule.radius {
+        return None;
+    }
+    if distance > 0.0 {
+        return Some(contact(
+            on_segment,
+            (on_capsule.x - on_segment.x) / distance,
+            (on_capsule.y - on_segment.y) / distance,
+            capsule.radius - distance,
+        ));
+    }
+    let (nx, ny) = segment_normal(segment);
+    let side = |p: Point| (p.x - segment.point_a.x) * nx + (p.y - segment.point_a.y) * ny;
+    let (side_a, side_b) = (side(capsule.segment.point_a), side(capsule.segment.point_b));
+    let sign = if side_a + side_b < 0.0 { -1.0 } else { 1.0 };
+    let behind = -(sign * side_a).min(sign * side_b).min(0.0);
+    Some(contact(
+        on_segment,
+        sign * nx,
+        sign * ny,
+        capsule.radius + behind,
+    ))
+}
+
+/// Return the contact between a circle and a polygon, None if they do not overlap. A circle
+/// whose center lies inside the polygon is pushed out through the nearest edge.
+pub fn circle_polygon(circle: &Circle, polygon: &Polygon) -> Option<Contact> {
+    let (closest, distance) = polygon
+        .edges()
+        .map(|edge| edge.closest_point(circle.center))
+        .map(|p| (p, Vector::new(p, circle.center).length()))
+        .fold(None, |best: Option<(Point, f32)>, candidate| match best {
+            Some(b) if b.1 <= candidate.1 => Some(b),
+            _ => Some(candidate),
+        })?;
+    let inside = polygon.contains(circle.center);
+    if !inside && distance >= circle.radius {
+        return None;
+    }
+    if distance == 0.0 {
+        let edge = polygon
+            .edges()
+            .find(|e| e.distance_to(closest) == 0.0)
+            .unwrap();
+        let (nx, ny) = segment_normal(&edge);
+        let probe = Point {
+            x: closest.x + nx * 1e-3,
+            y: closest.y + ny * 1e-3,
+        };
+        let flip = if polygon.contains(probe) { -1.0 } else { 1.0 };
+        return Some(contact(closest, flip * nx, flip * ny, circle.radius));
+    }
+    // Outside the normal points from the polygon to the center, inside it has to point away
+    let sign = if inside { -1.0 } else { 1.0 };
+    let depth = if inside {
+        circle.radius + distance
+    } else {
+        circle.radius - distance
+    };
+    Some(contact(
+        closest,
+        sign * (circle.center.x - closest.x) / distance,
+        sign * (circle.center.y - closest.y) / distance,
+        depth,
+    ))
+}
+
+/// Return the first touch of the circle moving by motion against the segment, None if it
+/// passes without contact. A circle already overlapping the segment touches it at t = 0.
+pub fn circle_cast_segment(
+    circle: &Circle,
+    motion: &Vector,
+    segment: &Segment,
+) -> Option<TimeOfImpact> {
+    if let Some(c) = circle_segment(circle, segment) {
+        return Some(at_start(c));
+    }
+    // Sweeping the circle against the segment equals casting its center against the segment
+    // inflated by the radius: two caps around the end points and two parallel sides.
+    let ray = Ray::new(circle.center, *motion);
+    let (nx, ny) = segment_normal(segment);
+    let side = |sign: f32| {
+        let (ox, oy) = (sign * circle.radius * nx, sign * circle.radius * ny);
+        Segment::new(
+            Point {
+                x: segment.point_a.x + ox,
+                y: segment.point_a.y + oy,
+            },
+            Point {
+                x: segment.point_b.x + ox,
+                y: segment.point_b.y + oy,
+            },
+        )
+    };
+    let hits = [
+        Circle::new(segment.point_a, circle.radius).ray_intersection(&ray),
+        Circle::new(segment.point_b, circle.radius).ray_intersection(&ray),
+        side(1.0).ray_intersection(&ray),
+        side(-1.0).ray_intersection(&ray),
+    ];
+    earliest(circle, motion, hits.iter().flatten())
+}
+
+/// Return the first touch of the circle moving by motion against another resting circle,
+/// None if it passes without contact
+pub fn circle_cast_circle(
+    circle: &Circle,
+    motion: &Vector,
+    other: &Circle,
+) -> Option<TimeOfImpact> {
+    let ray = Ray::new(circle.center, *motion);
+    let (dx, dy) = (
+        circle.center.x - other.center.x,
+        circle.center.y - other.center.y,
+    );
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance < circle.radius + other.radius {
+        let (nx, ny) = if distance > 0.0 {
+            (dx / distance, dy / distance)
+        } else {
+            (0.0, 1.0)
+        };
+        let point = Point {
+            x: other.center.x + nx * other.radius,
+            y: other.center.y + ny * other.radius,
+        };
+        return Some(at_start(contact(point, nx, ny, 0.0)));
+    }
+    let hit = Circle::new(other.center, circle.radius + other.radius).ray_intersection(&ray);
+    earliest(circle, motion, hit.iter())
+}
+
+/// Return the first touch of the circle moving by motion against the polygon, None if it
+/// passes without contact. A circle already overlapping the polygon touches it at t = 0.
+pub fn circle_cast_polygon(
+    circle: &Circle,
+    motion: &Vector,
+    polygon: &Polygon,
+) -> Option<TimeOfImpact> {
+    if let Some(c) = circle_polygon(circle, polygon) {
+        return Some(at_start(c));
+    }
+    polygon
+        .edges()
+        .filter_map(|edge| circle_cast_segment(circle, motion, &edge))
+        .fold(None, |best: Option<TimeOfImpact>, toi| match best {
+            Some(b) if b.t <= toi.t => Some(b),
+            _ => Some(toi),
+        })
+}
+
+fn at_start(contact: Contact) -> TimeOfImpact {
+    TimeOfImpact {
+        t: 0.0,
+        point: contact.point,
+        normal: contact.normal,
+    }
+}
+
+/// Turn the closest hit of the circle center against an inflated obstacle into a time of
+/// impact, None if the hit lies beyond the end of the motion
+fn earliest<'a>(
+    circle: &Circle,
+    motion: &Vector,
+    hits: impl Iterator<Item = &'a RayHit>,
+) -> Option<TimeOfImpact> {
+    let length = (motion.dx() * motion.dx() + motion.dy() * motion.dy()).sqrt();
+    let hit = hits.fold(None, |best: Option<&RayHit>, hit| match best {
+        Some(b) if b.distance <= hit.distance => Some(b),
+        _ => Some(hit),
+    })?;
+    if length == 0.0 || hit.distance > length {
+        return None;
+    }
+    let (nx, ny) = (hit.normal.dx(), hit.normal.dy());
+    let point = Point {
+        x: hit.point.x - nx * circle.radius,
+        y: hit.point.y - ny * circle.radius,
+    };
+    let c = contact(point, nx, ny, 0.0);
+    Some(TimeOfImpact {
+        t: hit.distance / length,
+        point: c.point,
+        normal: c.normal,
+    })
+}
+
+fn contact(point: Point, nx: f32, ny: f32, depth: f32) -> Contact {
+    Contact {
+        point,
+        normal: Vector::new(
+            point,
+            Point {
+                x: point.x + nx,
+                y: point.y + ny,
+            },
+        ),
+        depth,
+    }
+}
+
+/// Unit normal on the left side of the segment, (0, 1) for a degenerate segment
+fn segment_normal(segment: &Segment) -> (f32, f32) {
+    let (dx, dy) = (
+        segment.point_b.x - segment.point_a.x,
+        segment.point_b.y - segment.point_a.y,
+    );
+    let length = (dx * dx + dy * dy).sqrt();
+    if length == 0.0 {
+        (0.0, 1.0)
+    } else {
+        (-dy / length, dx / length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_circle_segment() {
+        let ground = Segment::new(p(-5.0, 0.0), p(5.0, 0.0));
+        let contact = circle_segment(&Circle::new(p(1.0, 0.5), 1.0), &ground).unwrap();
+        assert_eq!(p(1.0, 0.0), contact.point);
+        assert_eq!(0.5, contact.depth);
+        assert_eq!((0.0, 1.0), (contact.normal.dx(), contact.normal.dy()));
+        assert_eq!(
+            None,
+            circle_segment(&Circle::new(p(1.0, 2.0), 1.0), &ground)
+        );
+    }
+
+    #[test]
+    fn test_capsule_segment() {
+        let wall = Segment::new(p(0.0, -5.0), p(0.0, 5.0));
+        let near = Capsule::new(Segment::new(p(0.5, 0.0), p(3.0, 0.0)), 1.0);
+        let contact = capsule_segment(&near, &wall).unwrap();
+        assert_eq!(0.5, contact.depth);
+        assert_eq!((1.0, 0.0), (contact.normal.dx(), contact.normal.dy()));
+        let crossing = Capsule::new(Segment::new(p(-1.0, 0.0), p(3.0, 0.0)), 0.5);
+        let contact = capsule_segment(&crossing, &wall).unwrap();
+        assert_eq!(1.5, contact.depth);
+        assert_eq!((1.0, 0.0), (contact.normal.dx(), contact.normal.dy()));
+    }
+
+    #[test]
+    fn test_fast_circle_does_not_tunnel() {
+        let wall = Segment::new(p(10.0, -1.0), p(10.0, 1.0));
+        let circle = Circle::new(p(0.0, 0.0), 1.0);
+        let motion = Vector::new(p(0.0, 0.0), p(100.0, 0.0));
+        let toi = circle_cast_segment(&circle, &motion, &wall).unwrap();
+        assert_eq!(0.09, toi.t);
+        assert_eq!(p(10.0, 0.0), toi.point);
+        assert_eq!((-1.0, 0.0), (toi.normal.dx(), toi.normal.dy()));
+        // Hitting the end cap from above
+        let motion = Vector::new(p(0.0, 0.0), p(0.0, -10.0));
+        let toi = circle_cast_segment(&Circle::new(p(10.0, 5.0), 1.0), &motion, &wall).unwrap();
+        assert_eq!(0.3, toi.t);
+        assert_eq!(p(10.0, 1.0), toi.point);
+        let short = Vector::new(p(0.0, 0.0), p(5.0, 0.0));
+        assert_eq!(None, circle_cast_segment(&circle, &short, &wall));
+    }
+
+    #[test]
+    fn test_circle_cast_circle_and_polygon() {
+        let motion = Vector::new(p(0.0, 0.0), p(10.0, 0.0));
+        let circle = Circle::new(p(0.0, 0.0), 1.0);
+        let toi = circle_cast_circle(&circle, &motion, &Circle::new(p(8.0, 0.0), 2.0)).unwrap();
+        assert_eq!(0.5, toi.t);
+        assert_eq!(p(6.0, 0.0), toi.point);
+        let square = Polygon::new(vec![p(4.0, -1.0), p(6.0, -1.0), p(6.0, 1.0), p(4.0, 1.0)]);
+        let toi = circle_cast_polygon(&circle, &motion, &square).unwrap();
+        assert_eq!(0.3, toi.t);
+        assert_eq!((-1.0, 0.0), (toi.normal.dx(), toi.normal.dy()));
+    }
+
+    #[test]
+    fn test_circle_polygon() {
+        let square = Polygon::new(vec![p(0.0, 0.0), p(4.0, 0.0), p(4.0, 4.0), p(0.0, 4.0)]);
+        let outside = circle_polygon(&Circle::new(p(5.0, 2.0), 2.0), &square).unwrap();
+        assert_eq!(1.0, outside.depth);
+        assert_eq!((1.0, 0.0), (outside.normal.dx(), outside.normal.dy()));
+        let inside = circle_polygon(&Circle::new(p(3.0, 2.0), 0.5), &square).unwrap();
+        assert_eq!(1.5, inside.depth);
+        assert_eq!(p(4.0, 2.0), inside.point);
+        assert_eq!((1.0, 0.0), (inside.normal.dx(), inside.normal.dy()));
+        assert_eq!(
+            None,
+            circle_polygon(&Circle::new(p(7.0, 2.0), 2.0), &square)
+        );
+    }
+}
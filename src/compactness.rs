@@ -0,0 +1,99 @@
+use std::f32::consts::PI;
+
+use crate::{convex_hull, min_area_rect, Area, Perimeter, Polygon};
+
+impl Polygon {
+    /// Return 4 pi times the area over the squared perimeter, 1 for a circle and smaller
+    /// for every other shape. Ragged outlines lower it even when the shape is round overall.
+    pub fn isoperimetric_quotient(&self) -> f32 {
+        let perimeter = self.perimeter();
+        if perimeter == 0.0 {
+            return 0.0;
+        }
+        4.0 * PI * self.area() / (perimeter * perimeter)
+    }
+
+    /// Return the area over the area of the circle whose diameter is the largest distance
+    /// between two vertices, 1 for a circle. Unlike the isoperimetric quotient it ignores
+    /// how ragged the outline is.
+    pub fn circularity(&self) -> f32 {
+        let hull = convex_hull(&self.points);
+        let diameter_squared = hull
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| {
+                hull[i + 1..]
+                    .iter()
+                    .map(move |b| (b.x - a.x).powi(2) + (b.y - a.y).powi(2))
+            })
+            .fold(0.0f32, f32::max);
+        if diameter_squared == 0.0 {
+            return 0.0;
+        }
+        4.0 * self.area() / (PI * diameter_squared)
+    }
+
+    /// Return the area over the area of the smallest rotated bounding rectangle, 1 for a
+    /// rectangle
+    pub fn rectangularity(&self) -> f32 {
+        match min_area_rect(&self.points) {
+            Some(rect) if rect.area() > 0.0 => self.area() / rect.area(),
+            _ => 0.0,
+        }
+    }
+
+    /// Return the area over the area of the convex hull, 1 for convex polygons and smaller
+    /// the deeper the dents
+    pub fn convexity_ratio(&self) -> f32 {
+        let hull = Polygon::new(convex_hull(&self.points)).area();
+        if hull == 0.0 {
+            return 0.0;
+        }
+        self.area() / hull
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_square_and_circle() {
+        let square = Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+        assert!((square.isoperimetric_quotient() - PI / 4.0).abs() < 1e-5);
+        assert!((square.circularity() - 2.0 / PI).abs() < 1e-5);
+        assert!((square.rectangularity() - 1.0).abs() < 1e-5);
+        assert!((square.convexity_ratio() - 1.0).abs() < 1e-5);
+        let circle = Polygon::new(
+            (0..360)
+                .map(|i| {
+                    let angle = (i as f32).to_radians();
+                    p(angle.cos(), angle.sin())
+                })
+                .collect(),
+        );
+        assert!(circle.isoperimetric_quotient() > 0.999);
+        assert!(circle.circularity() > 0.999);
+        assert!((circle.rectangularity() - PI / 4.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_concave_shape() {
+        // 3 by 3 square with a 1 by 2 notch cut from the top
+        let shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(3.0, 0.0),
+            p(3.0, 3.0),
+            p(2.0, 3.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 3.0),
+            p(0.0, 3.0),
+        ]);
+        assert!((shape.convexity_ratio() - 7.0 / 9.0).abs() < 1e-5);
+        assert!((shape.rectangularity() - 7.0 / 9.0).abs() < 1e-5);
+        assert!((shape.isoperimetric_quotient() - 4.0 * PI * 7.0 / 256.0).abs() < 1e-5);
+        assert_eq!(0.0, Polygon::new(vec![]).isoperimetric_quotient());
+    }
+}
@@ -0,0 +1,109 @@
+//! Comparators for sorting points, to be used with sort_by and friends. All of them are
+//! total orders, NaN coordinates sort last.
+
+use crate::{transcendental, OrderedPoint, Point};
+use std::cmp::Ordering;
+
+/// Compare by x and then by y, the order of a left to right sweep line
+pub fn lexicographic(a: &Point, b: &Point) -> Ordering {
+    OrderedPoint(*a).cmp(&OrderedPoint(*b))
+}
+
+/// Return a comparator ordering points by their distance from pivot, nearest first. Equally
+/// distant points are ordered lexicographically.
+pub fn by_distance_from(pivot: Point) -> impl Fn(&Point, &Point) -> Ordering {
+    move |a, b| {
+        squared_distance(pivot, *a)
+            .total_cmp(&squared_distance(pivot, *b))
+            .then_with(|| lexicographic(a, b))
+    }
+}
+
+/// Return a comparator ordering points by their polar angle around pivot, counter clockwise
+/// starting at the direction of the positive x axis. Points in the same direction are
+/// ordered by distance, nearest first, and the pivot itself comes first. The comparison uses
+/// cross products only, no trigonometry, so collinear points compare exactly as needed by
+/// Graham scan and angular sweeps.
+pub fn by_polar_angle(pivot: Point) -> impl Fn(&Point, &Point) -> Ordering {
+    move |a, b| {
+        let (ax, ay) = (a.x - pivot.x, a.y - pivot.y);
+        let (bx, by) = (b.x - pivot.x, b.y - pivot.y);
+        half_plane(ax, ay)
+            .cmp(&half_plane(bx, by))
+            .then_with(|| {
+                // Counter clockwise from a to b means a comes first
+                let cross = ax * by - ay * bx;
+                0f32.total_cmp(&cross)
+            })
+            .then_with(|| squared_distance(pivot, *a).total_cmp(&squared_distance(pivot, *b)))
+    }
+}
+
+/// Return the polar angle of point around pivot in degrees in [0, 360), usable as sort key
+/// where a comparator does not fit
+pub fn polar_angle(pivot: Point, point: Point) -> f32 {
+    let angle = transcendental::atan2(point.y - pivot.y, point.x - pivot.x).to_degrees();
+    if angle < 0.0 {
+        angle + 360.0
+    } else {
+        angle
+    }
+}
+
+/// 0 for the pivot itself, 1 for angles in [0, 180), 2 for [180, 360)
+fn half_plane(x: f32, y: f32) -> u8 {
+    if x == 0.0 && y == 0.0 {
+        0
+    } else if y > 0.0 || (y == 0.0 && x > 0.0) {
+        1
+    } else {
+        2
+    }
+}
+
+fn squared_distance(a: Point, b: Point) -> f32 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_lexicographic_and_distance() {
+        let mut points = vec![p(1.0, 0.0), p(0.0, 3.0), p(0.0, -1.0)];
+        points.sort_by(lexicographic);
+        assert_eq!(vec![p(0.0, -1.0), p(0.0, 3.0), p(1.0, 0.0)], points);
+        points.sort_by(by_distance_from(p(0.0, 2.0)));
+        assert_eq!(vec![p(0.0, 3.0), p(1.0, 0.0), p(0.0, -1.0)], points);
+    }
+
+    #[test]
+    fn test_polar_angle_order() {
+        let pivot = p(1.0, 1.0);
+        let mut points = vec![
+            p(1.0, 0.0),
+            p(0.0, 1.0),
+            p(3.0, 1.0),
+            p(2.0, 2.0),
+            p(1.0, 1.0),
+            p(2.0, 1.0),
+            p(0.0, 0.0),
+        ];
+        points.sort_by(by_polar_angle(pivot));
+        assert_eq!(
+            vec![
+                p(1.0, 1.0),
+                p(2.0, 1.0),
+                p(3.0, 1.0),
+                p(2.0, 2.0),
+                p(0.0, 1.0),
+                p(0.0, 0.0),
+                p(1.0, 0.0),
+            ],
+            points
+        );
+        assert!((polar_angle(pivot, p(1.0, 0.0)) - 270.0).abs() < 1e-4);
+    }
+}
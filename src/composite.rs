@@ -0,0 +1,102 @@
+use crate::{Area, Centroid, Point, SecondMoment, SecondMoments};
+
+/// Cross section built from solid parts and holes, like an I-beam from three rectangles or a
+/// plate with bolt holes. Holes are subtracted and expected to lie inside the solid parts.
+#[derive(Default)]
+pub struct Composite {
+    parts: Vec<(Box<dyn SecondMoment>, f32)>,
+}
+
+impl Composite {
+    pub fn new() -> Composite {
+        Composite::default()
+    }
+
+    /// Add a solid part
+    pub fn add<S: SecondMoment + 'static>(&mut self, shape: S) -> &mut Self {
+        self.parts.push((Box::new(shape), 1.0));
+        self
+    }
+
+    /// Cut a hole
+    pub fn subtract<S: SecondMoment + 'static>(&mut self, shape: S) -> &mut Self {
+        self.parts.push((Box::new(shape), -1.0));
+        self
+    }
+}
+
+impl Area for Composite {
+    fn area(&self) -> f32 {
+        self.parts.iter().map(|(s, sign)| sign * s.area()).sum()
+    }
+}
+
+impl Centroid for Composite {
+    /// Return the area weighted mean of the part centroids, the origin if the area is 0
+    fn centroid(&self) -> Point {
+        let area = self.area();
+        if area == 0.0 {
+            return Point { x: 0.0, y: 0.0 };
+        }
+        let (x, y) = self.parts.iter().fold((0.0, 0.0), |(x, y), (s, sign)| {
+            let (a, c) = (sign * s.area(), s.centroid());
+            (x + a * c.x, y + a * c.y)
+        });
+        Point {
+            x: x / area,
+            y: y / area,
+        }
+    }
+}
+
+impl SecondMoment for Composite {
+    /// Return the sum of the part moments moved to the common centroid
+    fn second_moment(&self) -> SecondMoments {
+        let center = self.centroid();
+        self.parts
+            .iter()
+            .fold(SecondMoments::default(), |total, (s, sign)| {
+                let (m, a, c) = (s.second_moment(), s.area(), s.centroid());
+                let (dx, dy) = (c.x - center.x, c.y - center.y);
+                SecondMoments {
+                    ixx: total.ixx + sign * (m.ixx + a * dy * dy),
+                    iyy: total.iyy + sign * (m.iyy + a * dx * dx),
+                    ixy: total.ixy + sign * (m.ixy + a * dx * dy),
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::{Circle, Rect};
+    use std::f32::consts::PI;
+
+    #[test]
+    fn test_i_beam() {
+        let mut beam = Composite::new();
+        beam.add(Rect::new(p(0.0, 0.0), p(10.0, 2.0)))
+            .add(Rect::new(p(4.0, 2.0), p(6.0, 12.0)))
+            .add(Rect::new(p(0.0, 12.0), p(10.0, 14.0)));
+        assert_eq!(60.0, beam.area());
+        assert_eq!(p(5.0, 7.0), beam.centroid());
+        // Enclosing 10 x 14 block minus the two 4 x 10 side pockets
+        let expected = (10.0 * 14f32.powi(3) - 2.0 * 4.0 * 10f32.powi(3)) / 12.0;
+        assert!((beam.second_moment().ixx - expected).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_plate_with_hole() {
+        let mut plate = Composite::new();
+        plate
+            .add(Rect::new(p(0.0, 0.0), p(10.0, 10.0)))
+            .subtract(Circle::new(p(7.0, 5.0), 1.0));
+        assert!((plate.area() - (100.0 - PI)).abs() < 1e-4);
+        let center = plate.centroid();
+        assert!(center.x < 5.0 && (center.y - 5.0).abs() < 1e-5);
+        let solid = Rect::new(p(0.0, 0.0), p(10.0, 10.0)).second_moment().ixx;
+        assert!((solid - PI / 4.0 - plate.second_moment().ixx).abs() < 1e-2);
+    }
+}
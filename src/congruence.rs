@@ -0,0 +1,126 @@
+use crate::{align, transcendental, Point, Pose2, Tolerance, Triangle};
+
+/// Transform mapping one triangle onto a congruent one. If reflected is set, points are
+/// mirrored across the x axis before the pose moves them.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Congruence {
+    pub pose: Pose2,
+    pub reflected: bool,
+}
+
+impl Congruence {
+    pub fn transform_point(&self, point: Point) -> Point {
+        let point = if self.reflected {
+            Point {
+                x: point.x,
+                y: -point.y,
+            }
+        } else {
+            point
+        };
+        self.pose.transform_point(point)
+    }
+}
+
+impl Triangle {
+    /// Return the rotation and translation mapping this triangle onto other, in any
+    /// rotation of its vertex order, with every corner landing within the tolerance. None if
+    /// the triangles are not congruent or only their mirror images are.
+    pub fn congruent_transform_to(&self, other: &Triangle, tolerance: Tolerance) -> Option<Pose2> {
+        best_match(&corners(self), &corners(other), tolerance)
+    }
+
+    /// Like congruent_transform_to, but also match mirror images, for templates that may be
+    /// seen from the back. The transform without reflection is preferred.
+    pub fn congruence_to(&self, other: &Triangle, tolerance: Tolerance) -> Option<Congruence> {
+        let source = corners(self);
+        if let Some(pose) = best_match(&source, &corners(other), tolerance) {
+            return Some(Congruence {
+                pose,
+                reflected: false,
+            });
+        }
+        let mirrored = source.map(|p| Point { x: p.x, y: -p.y });
+        best_match(&mirrored, &corners(other), tolerance).map(|pose| Congruence {
+            pose,
+            reflected: true,
+        })
+    }
+}
+
+fn corners(triangle: &Triangle) -> [Point; 3] {
+    [triangle.point_a(), triangle.point_b(), triangle.point_c()]
+}
+
+/// Try the orders of target in both directions and return the pose with the smallest worst
+/// corner distance within tolerance. Orders running the other way only fit when the
+/// triangles are degenerate or symmetric, so they are checked as well.
+fn best_match(source: &[Point; 3], target: &[Point; 3], tolerance: Tolerance) -> Option<Pose2> {
+    const ORDERS: [[usize; 3]; 6] = [
+        [0, 1, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [0, 2, 1],
+        [2, 1, 0],
+        [1, 0, 2],
+    ];
+    ORDERS
+        .iter()
+        .filter_map(|order| {
+            let ordered = [target[order[0]], target[order[1]], target[order[2]]];
+            let pose = align(source, &ordered)?;
+            let worst = source
+                .iter()
+                .zip(&ordered)
+                .map(|(s, t)| {
+                    let moved = pose.transform_point(*s);
+                    transcendental::hypot(moved.x - t.x, moved.y - t.y)
+                })
+                .fold(0.0f32, f32::max);
+            if worst <= tolerance.distance {
+                Some((pose, worst))
+            } else {
+                None
+            }
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(pose, _)| pose)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Angle;
+
+    #[test]
+    fn test_rigid_match() {
+        let template = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(1.0, 2.0));
+        let truth = Pose2::new(p(3.0, -1.0), Angle::from_degrees(75.0));
+        let [a, b, c] = corners(&template).map(|q| truth.transform_point(q));
+        // The detected triangle lists its corners starting elsewhere
+        let detected = Triangle::new(b, c, a);
+        let pose = template
+            .congruent_transform_to(&detected, Tolerance::new(1e-4))
+            .unwrap();
+        assert!((pose.heading - truth.heading).normalized().degrees().abs() < 1e-3);
+        assert!((pose.position.x - 3.0).abs() < 1e-4);
+        let stretched = Triangle::new(b, c, p(a.x + 0.5, a.y));
+        assert_eq!(
+            None,
+            template.congruent_transform_to(&stretched, Tolerance::new(1e-3))
+        );
+    }
+
+    #[test]
+    fn test_mirrored_match() {
+        let template = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(1.0, 2.0));
+        let mirror = Triangle::new(p(0.0, 0.0), p(-4.0, 0.0), p(-1.0, 2.0));
+        let tolerance = Tolerance::new(1e-4);
+        assert_eq!(None, template.congruent_transform_to(&mirror, tolerance));
+        let congruence = template.congruence_to(&mirror, tolerance).unwrap();
+        assert!(congruence.reflected);
+        let moved = congruence.transform_point(p(1.0, 2.0));
+        assert!((moved.x + 1.0).abs() < 1e-4 && (moved.y - 2.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{transcendental, Point, Segment, Tolerance};
+
+/// How two segments meet. Apart from the exact Segment::intersection, the crate's segment
+/// queries classify through Segment::contact with a Tolerance, so touching and crossing mean
+/// the same everywhere.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SegmentContact {
+    Disjoint,
+    /// The interiors cross in a single point
+    Crossing(Point),
+    /// The segments meet in an end point of at least one of them
+    Touching(Point),
+    /// The segments are collinear and share a piece longer than the tolerance
+    Overlapping(Segment),
+}
+
+impl SegmentContact {
+    /// Return the meeting point of a crossing or touch, None otherwise
+    pub fn point(&self) -> Option<Point> {
+        match self {
+            SegmentContact::Crossing(point) | SegmentContact::Touching(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+impl Segment {
+    /// Return how the segments meet. End points within the tolerance of the other segment
+    /// touch it, even if exact arithmetic would have them cross or miss by a hair, and
+    /// segments with all end points within the tolerance of one line count as collinear.
+    pub fn contact(&self, other: &Segment, tolerance: Tolerance) -> SegmentContact {
+        let d = tolerance.distance;
+        let a = self.point_a;
+        let (dx, dy) = (self.point_b.x - a.x, self.point_b.y - a.y);
+        let length = transcendental::hypot(dx, dy);
+        if length > d {
+            let offset = |p: Point| ((p.x - a.x) * dy - (p.y - a.y) * dx) / length;
+            if offset(other.point_a).abs() <= d && offset(other.point_b).abs() <= d {
+                let along = |p: Point| ((p.x - a.x) * dx + (p.y - a.y) * dy) / length;
+                let (s, t) = (along(other.point_a), along(other.point_b));
+                let (low, high) = (s.min(t).max(0.0), s.max(t).min(length));
+                return if high - low > d {
+                    SegmentContact::Overlapping(Segment::new(
+                        self.point_at(low / length),
+                        self.point_at(high / length),
+                    ))
+                } else if high - low >= -d {
+                    let middle = ((low + high) / 2.0 / length).clamp(0.0, 1.0);
+                    SegmentContact::Touching(self.point_at(middle))
+                } else {
+                    SegmentContact::Disjoint
+                };
+            }
+        }
+        let ends = [
+            (self.point_a, other),
+            (self.point_b, other),
+            (other.point_a, self),
+            (other.point_b, self),
+        ];
+        for (point, segment) in ends {
+            if segment.distance_to(point) <= d {
+                return SegmentContact::Touching(point);
+            }
+        }
+        match Segment::intersection(self, other) {
+            Some(point) => SegmentContact::Crossing(point),
+            None => SegmentContact::Disjoint,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_crossing_and_touching() {
+        let tolerance = Tolerance::new(1e-3);
+        let base = Segment::new(p(0.0, 0.0), p(4.0, 0.0));
+        assert_eq!(
+            SegmentContact::Crossing(p(2.0, 0.0)),
+            base.contact(&Segment::new(p(2.0, -1.0), p(2.0, 1.0)), tolerance)
+        );
+        // Stops just short of the base, within the tolerance
+        let t_junction = Segment::new(p(1.0, 2.0), p(1.0, 0.0005));
+        assert_eq!(
+            SegmentContact::Touching(p(1.0, 0.0005)),
+            base.contact(&t_junction, tolerance)
+        );
+        assert_eq!(
+            SegmentContact::Disjoint,
+            base.contact(&t_junction, Tolerance::new(1e-4))
+        );
+        let corner = Segment::new(p(4.0, 0.0), p(5.0, 3.0));
+        assert_eq!(Some(p(4.0, 0.0)), base.contact(&corner, tolerance).point());
+    }
+
+    #[test]
+    fn test_collinear_segments() {
+        let tolerance = Tolerance::default();
+        let base = Segment::new(p(0.0, 0.0), p(4.0, 0.0));
+        assert_eq!(
+            SegmentContact::Overlapping(Segment::new(p(3.0, 0.0), p(4.0, 0.0))),
+            base.contact(&Segment::new(p(6.0, 0.0), p(3.0, 0.0)), tolerance)
+        );
+        assert_eq!(
+            SegmentContact::Touching(p(4.0, 0.0)),
+            base.contact(&Segment::new(p(4.0, 0.0), p(7.0, 0.0)), tolerance)
+        );
+        assert_eq!(
+            SegmentContact::Disjoint,
+            base.contact(&Segment::new(p(5.0, 0.0), p(7.0, 0.0)), tolerance)
+        );
+    }
+}
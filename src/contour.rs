@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use crate::{Point, Polyline};
+
+/// Crossing of the iso line with a grid edge: horizontal edges run from (i, j) to
+/// (i + 1, j), vertical edges from (i, j) to (i, j + 1)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Edge {
+    Horizontal(usize, usize),
+    Vertical(usize, usize),
+}
+
+/// Return the iso lines at level iso of the scalar grid with marching squares. The grid is
+/// stored row by row, value (i, j) at grid[j * width + i] lies at the point (i, j), and
+/// crossings are placed by linear interpolation. Closed contours repeat their first point at
+/// the end, contours leaving the grid are open. Saddle cells are resolved by the mean of
+/// their corners. Panics if the grid is smaller than width * height.
+pub fn contours(grid: &[f32], width: usize, height: usize, iso: f32) -> Vec<Polyline> {
+    assert!(
+        grid.len() >= width * height,
+        "grid smaller than width * height"
+    );
+    let value = |i: usize, j: usize| grid[j * width + i];
+    let mut links: BTreeMap<Edge, Vec<Edge>> = BTreeMap::new();
+    let mut connect = |a: Edge, b: Edge| {
+        links.entry(a).or_default().push(b);
+        links.entry(b).or_default().push(a);
+    };
+    for j in 0..height.saturating_sub(1) {
+        for i in 0..width.saturating_sub(1) {
+            let corners = [
+                value(i, j),
+                value(i + 1, j),
+                value(i + 1, j + 1),
+                value(i, j + 1),
+            ];
+            let above: Vec<bool> = corners.iter().map(|v| *v > iso).collect();
+            let (bottom, right) = (Edge::Horizontal(i, j), Edge::Vertical(i + 1, j));
+            let (top, left) = (Edge::Horizontal(i, j + 1), Edge::Vertical(i, j));
+            // Edges in order around the cell, each between corner k and k + 1
+            let sides = [bottom, right, top, left];
+            let crossed: Vec<Edge> = (0..4)
+                .filter(|k| above[*k] != above[(k + 1) % 4])
+                .map(|k| sides[k])
+                .collect();
+            match crossed.len() {
+                2 => connect(crossed[0], crossed[1]),
+                4 => {
+                    let center = corners.iter().sum::<f32>() / 4.0 > iso;
+                    if center == above[0] {
+                        // Corners 0 and 2 are joined through the center, cut off 1 and 3
+                        connect(bottom, right);
+                        connect(top, left);
+                    } else {
+                        connect(left, bottom);
+                        connect(right, top);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let point = |edge: Edge| {
+        let ((x0, y0), (x1, y1)) = match edge {
+            Edge::Horizontal(i, j) => ((i, j), (i + 1, j)),
+            Edge::Vertical(i, j) => ((i, j), (i, j + 1)),
+        };
+        let (v0, v1) = (value(x0, y0), value(x1, y1));
+        let t = (iso - v0) / (v1 - v0);
+        Point {
+            x: x0 as f32 + (x1 as f32 - x0 as f32) * t,
+            y: y0 as f32 + (y1 as f32 - y0 as f32) * t,
+        }
+    };
+    let mut visited: BTreeMap<Edge, bool> = links.keys().map(|e| (*e, false)).collect();
+    let ends: Vec<Edge> = links
+        .iter()
+        .filter(|(_, l)| l.len() == 1)
+        .map(|(e, _)| *e)
+        .collect();
+    let all: Vec<Edge> = links.keys().copied().collect();
+    let mut lines = Vec::new();
+    for start in ends.into_iter().chain(all) {
+        if visited[&start] {
+            continue;
+        }
+        let mut chain = vec![start];
+        visited.insert(start, true);
+        let mut current = start;
+        while let Some(next) = links[&current].iter().copied().find(|e| !visited[e]) {
+            visited.insert(next, true);
+            chain.push(next);
+            current = next;
+        }
+        if chain.len() > 2 && links[&current].contains(&start) {
+            chain.push(start);
+        }
+        lines.push(Polyline::new(chain.into_iter().map(point).collect()));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_circle() {
+        let (width, height) = (21, 21);
+        let grid: Vec<f32> = (0..width * height)
+            .map(|k| {
+                let (x, y) = ((k % width) as f32 - 10.0, (k / width) as f32 - 10.0);
+                (x * x + y * y).sqrt()
+            })
+            .collect();
+        let lines = contours(&grid, width, height, 5.5);
+        assert_eq!(1, lines.len());
+        let points = &lines[0].points;
+        assert_eq!(points[0], points[points.len() - 1]);
+        assert!(points.iter().all(|p| {
+            let r = ((p.x - 10.0).powi(2) + (p.y - 10.0).powi(2)).sqrt();
+            (r - 5.5).abs() < 0.1
+        }));
+    }
+
+    #[test]
+    fn test_open_ramp() {
+        let (width, height) = (4, 3);
+        let grid: Vec<f32> = (0..width * height).map(|k| (k % width) as f32).collect();
+        let lines = contours(&grid, width, height, 1.25);
+        assert_eq!(1, lines.len());
+        let points = &lines[0].points;
+        assert_eq!(3, points.len());
+        assert!(points.iter().all(|p| (p.x - 1.25).abs() < 1e-6));
+        assert!(contours(&grid, width, height, 10.0).is_empty());
+    }
+}
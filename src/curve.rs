@@ -0,0 +1,238 @@
+use crate::{CircularArc, CubicBezier, Point, QuadraticBezier, Vector};
+
+/// Recursion limit of the adaptive quadrature.
+const MAX_QUADRATURE_DEPTH: u32 = 20;
+/// Iterations used when inverting arc length to a curve parameter.
+const MAX_INVERSION_STEPS: u32 = 32;
+
+/// Curves which can be measured along their length and walked at constant speed.
+/// Distances passed to point_at_distance are clamped to [0, arc_length].
+pub trait ArcLength {
+    /// Return the total length of the curve
+    fn arc_length(&self) -> f32;
+
+    /// Return the point at distance s along the curve, measured from its start
+    fn point_at_distance(&self, s: f32) -> Point;
+}
+
+impl ArcLength for QuadraticBezier {
+    fn arc_length(&self) -> f32 {
+        integrate_speed(&|t| self.derivative(t).length(), 0.0, 1.0)
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        self.point_at(parameter_at_distance(&|t| self.derivative(t).length(), s))
+    }
+}
+
+impl ArcLength for CubicBezier {
+    fn arc_length(&self) -> f32 {
+        integrate_speed(&|t| self.derivative(t).length(), 0.0, 1.0)
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        self.point_at(parameter_at_distance(&|t| self.derivative(t).length(), s))
+    }
+}
+
+impl ArcLength for CircularArc {
+    fn arc_length(&self) -> f32 {
+        (self.radius * self.sweep_angle.to_radians()).abs()
+    }
+
+    /// Arcs are traversed at constant speed, so the parameter is proportional to distance.
+    fn point_at_distance(&self, s: f32) -> Point {
+        let length = self.arc_length();
+        if length == 0.0 {
+            return self.start_point();
+        }
+        self.point_at((s / length).clamp(0.0, 1.0))
+    }
+}
+
+/// A slice of points is treated as an open polyline through all points in order.
+/// An empty slice has length 0 and puts every distance at the origin.
+impl ArcLength for [Point] {
+    fn arc_length(&self) -> f32 {
+        self.windows(2)
+            .map(|w| Vector::new(w[0], w[1]).length())
+            .sum()
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        let mut remaining = s.max(0.0);
+        for w in self.windows(2) {
+            let length = Vector::new(w[0], w[1]).length();
+            if remaining <= length && length > 0.0 {
+                let t = remaining / length;
+                return Point {
+                    x: w[0].x + (w[1].x - w[0].x) * t,
+                    y: w[0].y + (w[1].y - w[0].y) * t,
+                };
+            }
+            remaining -= length;
+        }
+        self.last().copied().unwrap_or(Point { x: 0.0, y: 0.0 })
+    }
+}
+
+/// Return the discrete curvature at every point of an open polyline, estimated as the signed
+/// inverse radius of the circle through each point and its two neighbours (Menger curvature).
+/// Positive values mark counter clockwise turns. The end points and points with a coincident
+/// neighbour have no defined curvature and get 0.
+pub fn polyline_curvature(points: &[Point]) -> Vec<f32> {
+    let mut curvature = vec![0.0; points.len()];
+    for (i, w) in points.windows(3).enumerate() {
+        let (a, b, c) = (w[0], w[1], w[2]);
+        let cross = (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x);
+        let lengths =
+            Vector::new(a, b).length() * Vector::new(b, c).length() * Vector::new(c, a).length();
+        if lengths > 0.0 {
+            // 4 * triangle area / product of side lengths, the area being half the cross product
+            curvature[i + 1] = 2.0 * cross / lengths;
+        }
+    }
+    curvature
+}
+
+/// Signed curvature of a parametric curve from its first (dx, dy) and second (ddx, ddy)
+/// derivative. Returns 0 where the curve stands still and the curvature is undefined.
+pub(crate) fn signed_curvature(dx: f32, dy: f32, ddx: f32, ddy: f32) -> f32 {
+    let speed_squared = dx * dx + dy * dy;
+    if speed_squared == 0.0 {
+        return 0.0;
+    }
+    (dx * ddy - dy * ddx) / (speed_squared * speed_squared.sqrt())
+}
+
+/// Integrate the speed of a curve between the parameters t0 and t1 with adaptive Simpson
+/// quadrature, returning the length of that part of the curve.
+pub(crate) fn integrate_speed(speed: &dyn Fn(f32) -> f32, t0: f32, t1: f32) -> f32 {
+    let (a, b) = (t0 as f64, t1 as f64);
+    let f = |t: f64| speed(t as f32) as f64;
+    let (fa, fm, fb) = (f(a), f((a + b) / 2.0), f(b));
+    let whole = (b - a) / 6.0 * (fa + 4.0 * fm + fb);
+    let tolerance = 1e-7 * whole.abs().max(1e-12);
+    simpson(&f, a, b, fa, fm, fb, whole, tolerance, MAX_QUADRATURE_DEPTH) as f32
+}
+
+#[allow(clippy::too_many_arguments)]
+fn simpson(
+    f: &dyn Fn(f64) -> f64,
+    a: f64,
+    b: f64,
+    fa: f64,
+    fm: f64,
+    fb: f64,
+    whole: f64,
+    tolerance: f64,
+    depth: u32,
+) -> f64 {
+    let m = (a + b) / 2.0;
+    let (lm, rm) = ((a + m) / 2.0, (m + b) / 2.0);
+    let (flm, frm) = (f(lm), f(rm));
+    let left = (m - a) / 6.0 * (fa + 4.0 * flm + fm);
+    let right = (b - m) / 6.0 * (fm + 4.0 * frm + fb);
+    let delta = left + right - whole;
+    if depth == 0 || delta.abs() <= 15.0 * tolerance {
+        left + right + delta / 15.0
+    } else {
+        simpson(f, a, m, fa, flm, fm, left, tolerance / 2.0, depth - 1)
+            + simpson(f, m, b, fm, frm, fb, right, tolerance / 2.0, depth - 1)
+    }
+}
+
+/// Return the parameter t at which the curve with the passed speed has travelled the
+/// distance s. Newton's method is used, falling back to bisection whenever a step would
+/// leave the bracket known to contain the solution.
+pub(crate) fn parameter_at_distance(speed: &dyn Fn(f32) -> f32, s: f32) -> f32 {
+    let total = integrate_speed(speed, 0.0, 1.0);
+    if s <= 0.0 || total == 0.0 {
+        return 0.0;
+    }
+    if s >= total {
+        return 1.0;
+    }
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+    let mut t = s / total;
+    for _ in 0..MAX_INVERSION_STEPS {
+        let error = integrate_speed(speed, 0.0, t) - s;
+        if error.abs() <= 1e-6 * total {
+            break;
+        }
+        if error > 0.0 {
+            high = t;
+        } else {
+            low = t;
+        }
+        let next = t - error / speed(t);
+        t = if next > low && next < high {
+            next
+        } else {
+            (low + high) / 2.0
+        };
+    }
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Polyline;
+
+    #[test]
+    fn test_bezier_arc_length() {
+        let line = CubicBezier::new(p(0.0, 0.0), p(0.5, 0.0), p(3.0, 0.0), p(4.0, 0.0));
+        assert!((line.arc_length() - 4.0).abs() < 1e-4);
+        // Walking a non uniformly parameterized line must still land at exact distances
+        for i in 0..=8 {
+            let s = i as f32 * 0.5;
+            assert!((line.point_at_distance(s).x - s).abs() < 1e-3);
+        }
+        let q = QuadraticBezier::new(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 0.0));
+        // Closed form length of this parabola segment
+        let expected = 2f32.sqrt() + (1.0 + 2f32.sqrt()).ln();
+        assert!((q.arc_length() - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_arc_length_of_circular_arc() {
+        let arc = CircularArc::new(p(0.0, 0.0), 2.0, 0.0, -90.0);
+        assert!((arc.arc_length() - std::f32::consts::PI).abs() < 1e-5);
+        let end = arc.point_at_distance(10.0);
+        assert!((end.x - 0.0).abs() < 1e-5);
+        assert!((end.y + 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_curvature() {
+        // Cubic approximation of a quarter circle with radius 2
+        let k = 0.552_284_8 * 2.0;
+        let c = CubicBezier::new(p(2.0, 0.0), p(2.0, k), p(k, 2.0), p(0.0, 2.0));
+        assert!((c.curvature_at(0.5) - 0.5).abs() < 1e-2);
+        let q = QuadraticBezier::new(p(0.0, 0.0), p(1.0, -1.0), p(2.0, 0.0));
+        assert!(q.curvature_at(0.5) > 0.0);
+        let arc = CircularArc::new(p(0.0, 0.0), 4.0, 0.0, -45.0);
+        assert_eq!(-0.25, arc.curvature_at(0.3));
+        let square_corner = [p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 2.0)];
+        let curvature = polyline_curvature(&square_corner);
+        assert_eq!(0.0, curvature[0]);
+        assert!((curvature[1] - 2f32.sqrt()).abs() < 1e-5);
+        assert!(curvature[2] > 0.0);
+        assert_eq!(0.0, curvature[3]);
+    }
+
+    #[test]
+    fn test_polyline_point_at_distance() {
+        let points = [p(0.0, 0.0), p(3.0, 0.0), p(3.0, 4.0)];
+        assert_eq!(7.0, points.arc_length());
+        assert_eq!(p(2.0, 0.0), points.point_at_distance(2.0));
+        assert_eq!(p(3.0, 1.0), points.point_at_distance(4.0));
+        assert_eq!(p(3.0, 4.0), points.point_at_distance(100.0));
+        assert_eq!(p(0.0, 0.0), points.point_at_distance(-1.0));
+        let empty: [Point; 0] = [];
+        assert_eq!(p(0.0, 0.0), empty.point_at_distance(2.0));
+        assert_eq!(p(0.0, 0.0), Polyline::default().point_at_distance(2.0));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Angle, Point, Vector};
+
+/// Move current towards target like a critically damped spring, for camera follow and UI
+/// motion. velocity carries the speed between calls and should start at zero; smooth_time is
+/// roughly the time to reach the target and dt the time since the last call. The result never
+/// overshoots the target.
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smooth_time: f32,
+    dt: f32,
+) -> f32 {
+    if dt <= 0.0 {
+        return current;
+    }
+    let omega = 2.0 / smooth_time.max(1e-4);
+    let x = omega * dt;
+    // Polynomial approximation of exp(-x), accurate enough for frame sized steps
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    let output = target + (change + temp) * decay;
+    if (target > current) == (output > target) {
+        *velocity = 0.0;
+        return target;
+    }
+    output
+}
+
+/// Types that can follow a moving target with smooth_damp.
+pub trait SmoothDamp: Sized {
+    /// Rate of change carried between calls, starting from its default of zero
+    type Velocity: Default;
+
+    fn smooth_damp(
+        self,
+        target: Self,
+        velocity: &mut Self::Velocity,
+        smooth_time: f32,
+        dt: f32,
+    ) -> Self;
+}
+
+impl SmoothDamp for Point {
+    /// Velocity in units per second along x and y
+    type Velocity = (f32, f32);
+
+    fn smooth_damp(
+        self,
+        target: Point,
+        velocity: &mut (f32, f32),
+        smooth_time: f32,
+        dt: f32,
+    ) -> Point {
+        Point {
+            x: smooth_damp(self.x, target.x, &mut velocity.0, smooth_time, dt),
+            y: smooth_damp(self.y, target.y, &mut velocity.1, smooth_time, dt),
+        }
+    }
+}
+
+impl SmoothDamp for Vector {
+    /// Velocities of point A and point B
+    type Velocity = ((f32, f32), (f32, f32));
+
+    fn smooth_damp(
+        self,
+        target: Vector,
+        velocity: &mut Self::Velocity,
+        smooth_time: f32,
+        dt: f32,
+    ) -> Vector {
+        Vector::new(
+            self.point_a()
+                .smooth_damp(target.point_a(), &mut velocity.0, smooth_time, dt),
+            self.point_b()
+                .smooth_damp(target.point_b(), &mut velocity.1, smooth_time, dt),
+        )
+    }
+}
+
+impl SmoothDamp for Angle {
+    /// Velocity in degrees per second
+    type Velocity = f32;
+
+    /// Turns the shorter way round; the result is not normalized so it stays continuous
+    fn smooth_damp(self, target: Angle, velocity: &mut f32, smooth_time: f32, dt: f32) -> Angle {
+        let target = self + (target - self).normalized();
+        Angle::from_degrees(smooth_damp(
+            self.degrees(),
+            target.degrees(),
+            velocity,
+            smooth_time,
+            dt,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_point_settles_without_overshoot() {
+        let (mut camera, target) = (p(0.0, 0.0), p(10.0, -4.0));
+        let mut velocity = Default::default();
+        let mut previous = camera;
+        for _ in 0..120 {
+            camera = camera.smooth_damp(target, &mut velocity, 0.3, 1.0 / 60.0);
+            assert!(camera.x >= previous.x && camera.x <= target.x);
+            assert!(camera.y <= previous.y && camera.y >= target.y);
+            previous = camera;
+        }
+        assert!((camera.x - target.x).abs() < 0.01);
+        assert!((camera.y - target.y).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_angle_turns_shorter_way() {
+        let (mut heading, target) = (Angle::from_degrees(170.0), Angle::from_degrees(-170.0));
+        let mut velocity = 0.0;
+        heading = heading.smooth_damp(target, &mut velocity, 0.2, 0.05);
+        assert!(heading.degrees() > 170.0 && velocity > 0.0);
+        for _ in 0..100 {
+            heading = heading.smooth_damp(target, &mut velocity, 0.2, 0.05);
+        }
+        assert!((heading.degrees() - 190.0).abs() < 0.01);
+        assert_eq!(3.0, smooth_damp(3.0, 5.0, &mut velocity, 0.2, 0.0));
+    }
+}
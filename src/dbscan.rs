@@ -0,0 +1,124 @@
+use crate::{Point, Rect, SpatialHash};
+
+/// Result of DBSCAN clustering.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DensityClusters {
+    /// Cluster of every input point, None for noise
+    pub labels: Vec<Option<usize>>,
+    /// Number of clusters, labels run from 0 below it
+    pub count: usize,
+}
+
+impl DensityClusters {
+    /// Return the indices of the points in no cluster
+    pub fn noise(&self) -> Vec<usize> {
+        (0..self.labels.len())
+            .filter(|i| self.labels[*i].is_none())
+            .collect()
+    }
+
+    /// Return the indices of the points in the cluster
+    pub fn members(&self, cluster: usize) -> Vec<usize> {
+        (0..self.labels.len())
+            .filter(|i| self.labels[*i] == Some(cluster))
+            .collect()
+    }
+}
+
+/// Return the points clustered by density with DBSCAN. A point with at least min_points
+/// points, itself included, within radius is a core point; clusters are the core points
+/// reachable from each other through such neighborhoods plus the points within radius of
+/// them. Everything else is noise. Neighbors are found through a spatial hash with cells of
+/// the radius, so the run time stays near linear for evenly spread scans.
+pub fn dbscan(points: &[Point], radius: f32, min_points: usize) -> DensityClusters {
+    let mut index = SpatialHash::new(radius.max(f32::MIN_POSITIVE));
+    for point in points {
+        index.insert(Rect::new(*point, *point), ());
+    }
+    let neighbors = |i: usize| -> Vec<usize> {
+        let p = points[i];
+        let region = Rect::new(
+            Point {
+                x: p.x - radius,
+                y: p.y - radius,
+            },
+            Point {
+                x: p.x + radius,
+                y: p.y + radius,
+            },
+        );
+        index
+            .query(&region)
+            .into_iter()
+            .map(|id| id.0)
+            .filter(|j| {
+                (points[*j].x - p.x).powi(2) + (points[*j].y - p.y).powi(2) <= radius * radius
+            })
+            .collect()
+    };
+
+    let mut labels: Vec<Option<usize>> = vec![None; points.len()];
+    let mut visited = vec![false; points.len()];
+    let mut count = 0;
+    for start in 0..points.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let around = neighbors(start);
+        if around.len() < min_points {
+            continue;
+        }
+        labels[start] = Some(count);
+        let mut queue = around;
+        while let Some(i) = queue.pop() {
+            if labels[i].is_none() {
+                labels[i] = Some(count);
+            }
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            let around = neighbors(i);
+            if around.len() >= min_points {
+                queue.extend(around);
+            }
+        }
+        count += 1;
+    }
+    DensityClusters { labels, count }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_two_clusters_and_noise() {
+        let mut points: Vec<Point> = (0..10).map(|i| p(i as f32 * 0.5, 0.0)).collect();
+        points.extend((0..5).map(|i| p(20.0, i as f32 * 0.5)));
+        points.push(p(10.0, 10.0));
+        let clusters = dbscan(&points, 0.6, 3);
+        assert_eq!(2, clusters.count);
+        assert_eq!(vec![15], clusters.noise());
+        assert_eq!((0..10).collect::<Vec<_>>(), clusters.members(0));
+        assert_eq!((10..15).collect::<Vec<_>>(), clusters.members(1));
+    }
+
+    #[test]
+    fn test_border_points_join_a_cluster() {
+        // The end points have only one neighbor but lie within reach of a core point
+        let points = [
+            p(0.0, 0.0),
+            p(1.0, 0.0),
+            p(1.0, 0.5),
+            p(1.0, -0.5),
+            p(2.0, 0.0),
+        ];
+        let clusters = dbscan(&points, 1.0, 4);
+        assert_eq!(1, clusters.count);
+        assert!(clusters.noise().is_empty());
+        assert!(dbscan(&[], 1.0, 2).labels.is_empty());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Point, Polygon};
+
+impl Polygon {
+    /// Return true if no corner turns against the others. Collinear corners are allowed,
+    /// self intersecting outlines are not detected.
+    pub fn is_convex(&self) -> bool {
+        let ring: Vec<usize> = (0..self.points.len()).collect();
+        let sign = if self.signed_area() < 0.0 { -1.0 } else { 1.0 };
+        is_convex_ring(&self.points, &ring, sign)
+    }
+
+    /// Split the polygon into convex counter clockwise pieces with the Hertel-Mehlhorn
+    /// algorithm: triangulate, then drop every diagonal whose removal keeps the merged piece
+    /// convex. The result has at most four times as many pieces as the optimum, which is
+    /// what SAT and GJK need to handle concave shapes.
+    pub fn convex_decomposition(&self) -> Vec<Polygon> {
+        if self.points.len() < 3 {
+            return Vec::new();
+        }
+        if self.is_convex() {
+            let mut piece = self.clone();
+            piece.fix_orientation();
+            return vec![piece];
+        }
+        let mesh = self.triangulate();
+        let mut pieces: Vec<Vec<usize>> = mesh
+            .indices
+            .chunks(3)
+            .map(|t| t.iter().map(|i| *i as usize).collect())
+            .collect();
+        let mut merged = true;
+        while merged {
+            merged = false;
+            'search: for a in 0..pieces.len() {
+                for b in a + 1..pieces.len() {
+                    if let Some(piece) = merge(&pieces[a], &pieces[b]) {
+                        if is_convex_ring(&mesh.vertices, &piece, 1.0) {
+                            pieces[a] = piece;
+                            pieces.swap_remove(b);
+                            merged = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+        pieces
+            .iter()
+            .map(|piece| Polygon::new(piece.iter().map(|i| mesh.vertices[*i]).collect()))
+            .collect()
+    }
+}
+
+/// Join two counter clockwise pieces along a shared diagonal, None if they share no edge
+fn merge(a: &[usize], b: &[usize]) -> Option<Vec<usize>> {
+    let (n, m) = (a.len(), b.len());
+    for i in 0..n {
+        let (u, v) = (a[i], a[(i + 1) % n]);
+        if let Some(j) = (0..m).find(|j| b[*j] == v && b[(j + 1) % m] == u) {
+            // a from v around to u, then the inner points of b from u around to v
+            let mut piece: Vec<usize> = (0..n).map(|k| a[(i + 1 + k) % n]).collect();
+            piece.extend((2..m).map(|k| b[(j + k) % m]));
+            return Some(piece);
+        }
+    }
+    None
+}
+
+/// Return true if every corner of the ring turns the way of sign, 1 for counter clockwise
+fn is_convex_ring(points: &[Point], ring: &[usize], sign: f32) -> bool {
+    let n = ring.len();
+    (0..n).all(|i| {
+        let (a, b, c) = (
+            points[ring[i]],
+            points[ring[(i + 1) % n]],
+            points[ring[(i + 2) % n]],
+        );
+        let cross = (b.x - a.x) * (c.y - b.y) - (b.y - a.y) * (c.x - b.x);
+        sign * cross >= -1e-6
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Area;
+
+    #[test]
+    fn test_l_shape() {
+        let l_shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 2.0),
+            p(0.0, 2.0),
+        ]);
+        assert!(!l_shape.is_convex());
+        let pieces = l_shape.convex_decomposition();
+        assert_eq!(2, pieces.len());
+        assert!(pieces
+            .iter()
+            .all(|p| p.is_convex() && p.is_counter_clockwise()));
+        let area: f32 = pieces.iter().map(|p| p.area()).sum();
+        assert!((area - 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_convex_input_and_comb() {
+        let square = Polygon::new(vec![p(0.0, 0.0), p(0.0, 1.0), p(1.0, 1.0), p(1.0, 0.0)]);
+        let pieces = square.convex_decomposition();
+        assert_eq!(1, pieces.len());
+        assert!(pieces[0].is_counter_clockwise());
+        let comb = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(5.0, 0.0),
+            p(5.0, 3.0),
+            p(4.0, 1.0),
+            p(3.0, 3.0),
+            p(2.0, 1.0),
+            p(1.0, 3.0),
+            p(0.0, 1.0),
+        ]);
+        let pieces = comb.convex_decomposition();
+        assert!(pieces.len() >= 3 && pieces.len() <= 6);
+        let area: f32 = pieces.iter().map(|p| p.area()).sum();
+        assert!((area - comb.area()).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,74 @@
+//! Trigonometric functions taking and returning degrees, the unit of the whole crate.
+
+use crate::transcendental;
+
+pub fn sin_deg(degrees: f32) -> f32 {
+    transcendental::sin(degrees.to_radians())
+}
+
+pub fn cos_deg(degrees: f32) -> f32 {
+    transcendental::cos(degrees.to_radians())
+}
+
+pub fn tan_deg(degrees: f32) -> f32 {
+    transcendental::tan(degrees.to_radians())
+}
+
+/// Secant, 1 / cos. Infinite where the cosine is zero.
+pub fn sec_deg(degrees: f32) -> f32 {
+    1.0 / cos_deg(degrees)
+}
+
+/// Cosecant, 1 / sin. Infinite where the sine is zero.
+pub fn csc_deg(degrees: f32) -> f32 {
+    1.0 / sin_deg(degrees)
+}
+
+/// Cotangent, cos / sin. Infinite where the sine is zero.
+pub fn cot_deg(degrees: f32) -> f32 {
+    let (sin, cos) = transcendental::sin_cos(degrees.to_radians());
+    cos / sin
+}
+
+/// Return the angle in [-90, 90] whose sine is value, NaN outside [-1, 1]
+pub fn asin_deg(value: f32) -> f32 {
+    transcendental::asin(value).to_degrees()
+}
+
+/// Return the angle in [0, 180] whose cosine is value, NaN outside [-1, 1]
+pub fn acos_deg(value: f32) -> f32 {
+    transcendental::acos(value).to_degrees()
+}
+
+/// Return the angle in (-90, 90) whose tangent is value
+pub fn atan_deg(value: f32) -> f32 {
+    transcendental::atan(value).to_degrees()
+}
+
+/// Return the direction of (x, y) in (-180, 180]
+pub fn atan2_deg(y: f32, x: f32) -> f32 {
+    transcendental::atan2(y, x).to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-5
+    }
+
+    #[test]
+    fn test_degree_functions() {
+        assert!(close(0.5, sin_deg(30.0)));
+        assert!(close(0.5, cos_deg(60.0)));
+        assert!(close(1.0, tan_deg(45.0)));
+        assert!(close(2.0, sec_deg(60.0)));
+        assert!(close(2.0, csc_deg(30.0)));
+        assert!(close(1.0, cot_deg(45.0)));
+        assert!(close(30.0, asin_deg(0.5)));
+        assert!(close(60.0, acos_deg(0.5)));
+        assert!(close(45.0, atan_deg(1.0)));
+        assert!(close(135.0, atan2_deg(1.0, -1.0)));
+    }
+}
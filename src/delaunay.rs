@@ -0,0 +1,703 @@
+use crate::{orientation, Mesh, Point, VertexId};
+
+/// Return the Delaunay triangulation of the points, computed incrementally with the
+/// Bowyer-Watson algorithm. The vertices of the mesh are the points in their order, repeated
+/// points are left out of the triangulation. Points all on one line give an empty mesh.
+pub fn delaunay(points: &[Point]) -> Mesh {
+    let mut vertices: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+    let n = vertices.len();
+    if n < 3 {
+        return Mesh::new(points.to_vec(), Vec::new());
+    }
+    // Super triangle enclosing all points far away, removed again at the end
+    let bounds = bounds(vertices.iter().copied());
+    vertices.extend(super_triangle(bounds).iter());
+    let mut triangles: Vec<Circumscribed> = vec![Circumscribed::new(&vertices, [n, n + 1, n + 2])];
+    for p in 0..n {
+        add_vertex(&vertices, &mut triangles, p);
+    }
+    let indices = triangles
+        .iter()
+        .filter(|t| t.corners.iter().all(|c| *c < n))
+        .flat_map(|t| t.corners.iter().map(|c| *c as u32).collect::<Vec<u32>>())
+        .collect();
+    Mesh::new(points.to_vec(), indices)
+}
+
+/// Delaunay triangulation that is updated as points are inserted, moved and removed, for
+/// neighbor graphs of moving points. Ids stay valid until the vertex is removed; the
+/// triangulation grows its enclosing triangle when points leave the area seen so far.
+/// Triangles know their neighbors, so point location walks from the last changed triangle
+/// and insertion and removal only touch the triangles around the vertex.
+#[derive(Debug, Clone, Default)]
+pub struct DynamicDelaunay {
+    points: Vec<Point>,
+    live: Vec<bool>,
+    // Corners of the enclosing triangle first, then one vertex per point
+    vertices: Vec<(f64, f64)>,
+    bounds: Option<[f64; 4]>,
+    // Removed triangles are None and their slots listed in free for reuse
+    faces: Vec<Option<Face>>,
+    free: Vec<usize>,
+    // One triangle at each vertex, where walks around the vertex start
+    vertex_faces: Vec<usize>,
+    // Triangle of the last change, where point location starts
+    hint: usize,
+}
+
+/// Triangle of a DynamicDelaunay with the triangles across its edges
+#[derive(Debug, Clone)]
+struct Face {
+    circle: Circumscribed,
+    // neighbors[i] lies across the edge from corner i to corner i + 1, None on the outside
+    neighbors: [Option<usize>; 3],
+}
+
+impl DynamicDelaunay {
+    pub fn new() -> DynamicDelaunay {
+        DynamicDelaunay::default()
+    }
+
+    /// Add a point and return its id, None if a point is already at that position
+    pub fn insert(&mut self, point: Point) -> Option<VertexId> {
+        if self.find(point).is_some() {
+            return None;
+        }
+        if self.vertices.is_empty() {
+            self.vertices = vec![(0.0, 0.0); 3];
+            self.vertex_faces = vec![0; 3];
+        }
+        let id = VertexId(self.points.len());
+        self.points.push(point);
+        self.live.push(true);
+        self.vertices.push((point.x as f64, point.y as f64));
+        self.vertex_faces.push(0);
+        self.place(id);
+        Some(id)
+    }
+
+    /// Remove the vertex and fill its hole, return false if it was already removed
+    pub fn remove(&mut self, id: VertexId) -> bool {
+        if self.point(id).is_none() {
+            return false;
+        }
+        self.live[id.0] = false;
+        self.remove_vertex(id.0 + 3);
+        true
+    }
+
+    /// Move the vertex to a new position keeping its id. Return false if it was removed or
+    /// another point is already at the position.
+    pub fn move_to(&mut self, id: VertexId, point: Point) -> bool {
+        if self.point(id).is_none() || self.find(point).is_some() {
+            return false;
+        }
+        self.remove(id);
+        self.points[id.0] = point;
+        self.live[id.0] = true;
+        self.vertices[id.0 + 3] = (point.x as f64, point.y as f64);
+        self.place(id);
+        true
+    }
+
+    /// Return the position of the vertex, None if it was removed
+    pub fn point(&self, id: VertexId) -> Option<Point> {
+        if *self.live.get(id.0)? {
+            Some(self.points[id.0])
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over the vertices that were not removed
+    pub fn vertices(&self) -> impl Iterator<Item = (VertexId, Point)> + '_ {
+        (0..self.points.len()).filter_map(move |i| Some((VertexId(i), self.point(VertexId(i))?)))
+    }
+
+    /// Return the vertex closest to the point, None if there are no vertices. Inside the
+    /// area seen so far the search walks to the triangle of the point and on along edges
+    /// to closer vertices; points beyond it are compared with every vertex.
+    pub fn nearest(&self, point: Point) -> Option<VertexId> {
+        let (x, y) = (point.x as f64, point.y as f64);
+        let distance = |v: usize| {
+            let (vx, vy) = self.vertices[v];
+            (vx - x).powi(2) + (vy - y).powi(2)
+        };
+        if !self.covers((x, y)) {
+            return self
+                .vertices()
+                .map(|(id, _)| (id, distance(id.0 + 3)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(id, _)| id);
+        }
+        let face = self.face(self.locate((x, y)));
+        let mut current = face
+            .circle
+            .corners
+            .iter()
+            .copied()
+            .filter(|c| *c >= 3)
+            .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))?;
+        // In a Delaunay triangulation a vertex that is not the closest one has a closer
+        // neighbor
+        loop {
+            let closer = self
+                .adjacent(current)
+                .into_iter()
+                .filter(|v| *v >= 3)
+                .min_by(|a, b| distance(*a).total_cmp(&distance(*b)))
+                .filter(|v| distance(*v) < distance(current));
+            match closer {
+                Some(v) => current = v,
+                None => return Some(VertexId(current - 3)),
+            }
+        }
+    }
+
+    /// Return the vertices sharing an edge with the vertex in ascending order
+    pub fn neighbors(&self, id: VertexId) -> Vec<VertexId> {
+        if self.point(id).is_none() {
+            return Vec::new();
+        }
+        let mut neighbors: Vec<VertexId> = self
+            .adjacent(id.0 + 3)
+            .into_iter()
+            .filter(|c| *c >= 3)
+            .map(|c| VertexId(c - 3))
+            .collect();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Return the triangulation as a mesh whose vertices are indexed by id. Removed vertices
+    /// stay in the vertex list but no triangle uses them.
+    pub fn to_mesh(&self) -> Mesh {
+        let indices = self
+            .faces
+            .iter()
+            .flatten()
+            .filter(|f| f.circle.corners.iter().all(|c| *c >= 3))
+            .flat_map(|f| {
+                f.circle
+                    .corners
+                    .iter()
+                    .map(|c| (*c - 3) as u32)
+                    .collect::<Vec<u32>>()
+            })
+            .collect();
+        Mesh::new(self.points.clone(), indices)
+    }
+
+    /// Return the live vertex at exactly the point
+    fn find(&self, point: Point) -> Option<VertexId> {
+        let (x, y) = (point.x as f64, point.y as f64);
+        if !self.covers((x, y)) {
+            return None;
+        }
+        // A point on a vertex lies in the triangles around it, so the walk ends at one
+        self.face(self.locate((x, y)))
+            .circle
+            .corners
+            .iter()
+            .filter(|c| **c >= 3)
+            .map(|c| VertexId(*c - 3))
+            .find(|id| self.point(*id) == Some(point))
+    }
+
+    /// Return true if the point lies in the area the enclosing triangle was made for
+    fn covers(&self, (x, y): (f64, f64)) -> bool {
+        match self.bounds {
+            Some([min_x, min_y, max_x, max_y]) => {
+                x >= min_x && x <= max_x && y >= min_y && y <= max_y
+            }
+            None => false,
+        }
+    }
+
+    /// Triangulate a vertex already stored, rebuilding around a larger enclosing triangle
+    /// if the point lies outside the area it was made for
+    fn place(&mut self, id: VertexId) {
+        if self.covers(self.vertices[id.0 + 3]) {
+            self.insert_vertex(id.0 + 3);
+        } else {
+            self.rebuild();
+        }
+    }
+
+    fn rebuild(&mut self) {
+        let live: Vec<usize> = self.vertices().map(|(id, _)| id.0 + 3).collect();
+        let [min_x, min_y, max_x, max_y] = bounds(live.iter().map(|v| self.vertices[*v]));
+        // Leave room to grow so points drifting outward rarely cause another rebuild
+        let margin = (max_x - min_x).max(max_y - min_y).max(1.0) / 2.0;
+        let bounds = [
+            min_x - margin,
+            min_y - margin,
+            max_x + margin,
+            max_y + margin,
+        ];
+        self.vertices[..3].copy_from_slice(&super_triangle(bounds));
+        self.bounds = Some(bounds);
+        self.faces.clear();
+        self.free.clear();
+        self.hint = self.add_face([0, 1, 2], [None; 3]);
+        for v in live {
+            self.insert_vertex(v);
+        }
+    }
+
+    /// Return the triangle containing the point, walking from the last changed triangle
+    /// across every edge the point lies beyond
+    fn locate(&self, point: (f64, f64)) -> usize {
+        let mut current = self.hint;
+        // Walks in a Delaunay triangulation do not cycle, the bound only guards rounding
+        for _ in 0..self.faces.len() {
+            let face = self.face(current);
+            let [a, b, c] = face.circle.corners;
+            let beyond = [(a, b), (b, c), (c, a)]
+                .iter()
+                .position(|(u, v)| orientation(self.vertices[*u], self.vertices[*v], point) < 0.0);
+            match beyond.and_then(|i| face.neighbors[i]) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        current
+    }
+
+    /// Add vertex v with Bowyer-Watson restricted to the triangles next to the one
+    /// containing it: the connected triangles whose circumcircle encloses v are replaced by a
+    /// fan around v
+    fn insert_vertex(&mut self, v: usize) {
+        let point = self.vertices[v];
+        let mut cavity = vec![self.locate(point)];
+        let mut i = 0;
+        while i < cavity.len() {
+            for next in self.face(cavity[i]).neighbors.iter().flatten() {
+                if !cavity.contains(next) && self.face(*next).circle.encloses(point) {
+                    cavity.push(*next);
+                }
+            }
+            i += 1;
+        }
+        let mut border = Vec::new();
+        for f in &cavity {
+            let face = self.face(*f);
+            let [a, b, c] = face.circle.corners;
+            for (k, (u, w)) in [(a, b), (b, c), (c, a)].iter().enumerate() {
+                let outside = face.neighbors[k].filter(|n| !cavity.contains(n));
+                if outside.is_some() || face.neighbors[k].is_none() {
+                    border.push((*u, *w, outside));
+                }
+            }
+        }
+        for f in cavity {
+            self.faces[f] = None;
+            self.free.push(f);
+        }
+        let fan: Vec<usize> = border
+            .iter()
+            .map(|(a, b, outside)| self.attach([*a, *b, v], [*outside, None, None]))
+            .collect();
+        // Fan triangles meet along the edges from v to the border vertices
+        for (k, (a, b, _)) in border.iter().enumerate() {
+            let after = border.iter().position(|(c, _, _)| c == b);
+            let before = border.iter().position(|(_, c, _)| c == a);
+            let face = self.faces[fan[k]].as_mut().expect("fan triangle");
+            face.neighbors[1] = after.map(|i| fan[i]);
+            face.neighbors[2] = before.map(|i| fan[i]);
+        }
+        self.hint = fan[0];
+    }
+
+    /// Remove vertex v and fill only its hole: the ring of its neighbors is cut into
+    /// triangles by clipping ears whose circumcircle holds no other ring vertex, which are
+    /// the Delaunay triangles of the hole
+    fn remove_vertex(&mut self, v: usize) {
+        let around = self.faces_around(v);
+        // Ring vertices counter clockwise with the triangle beyond the edge to the next one
+        let mut ring: Vec<(usize, Option<usize>)> = around
+            .iter()
+            .map(|f| {
+                let face = self.face(*f);
+                let k = face
+                    .circle
+                    .corners
+                    .iter()
+                    .position(|c| *c == v)
+                    .unwrap_or(0);
+                (
+                    face.circle.corners[(k + 1) % 3],
+                    face.neighbors[(k + 1) % 3],
+                )
+            })
+            .collect();
+        for f in around {
+            self.faces[f] = None;
+            self.free.push(f);
+        }
+        while ring.len() > 3 {
+            let n = ring.len();
+            let corners = |i: usize| [ring[i].0, ring[(i + 1) % n].0, ring[(i + 2) % n].0];
+            let convex = |i: usize| {
+                let [a, b, c] = corners(i).map(|c| self.vertices[c]);
+                orientation(a, b, c) > 0.0
+            };
+            let empty = |i: usize| {
+                let circle = Circumscribed::oriented(&self.vertices, corners(i));
+                ring.iter()
+                    .filter(|(w, _)| !corners(i).contains(w))
+                    .all(|(w, _)| !circle.encloses(self.vertices[*w]))
+            };
+            let ear = (0..n)
+                .find(|i| convex(*i) && empty(*i))
+                .or_else(|| (0..n).find(|i| convex(*i)))
+                .unwrap_or(0);
+            ring.rotate_left(ear);
+            let face = self.attach(
+                [ring[0].0, ring[1].0, ring[2].0],
+                [ring[0].1, ring[1].1, None],
+            );
+            ring[0].1 = Some(face);
+            ring.remove(1);
+        }
+        if let [(a, ab), (b, bc), (c, ca)] = ring[..] {
+            self.hint = self.attach([a, b, c], [ab, bc, ca]);
+        }
+    }
+
+    /// Return the triangles around vertex v counter clockwise
+    fn faces_around(&self, v: usize) -> Vec<usize> {
+        let first = self.vertex_faces[v];
+        let mut around = vec![first];
+        loop {
+            let face = self.face(*around.last().unwrap_or(&first));
+            let k = face
+                .circle
+                .corners
+                .iter()
+                .position(|c| *c == v)
+                .unwrap_or(0);
+            // The next triangle shares the edge from the corner before v to v
+            match face.neighbors[(k + 2) % 3] {
+                Some(next) if next != first && around.len() < self.faces.len() => around.push(next),
+                _ => return around,
+            }
+        }
+    }
+
+    /// Return the vertices sharing an edge with vertex v
+    fn adjacent(&self, v: usize) -> Vec<usize> {
+        self.faces_around(v)
+            .iter()
+            .flat_map(|f| self.face(*f).circle.corners.iter().copied())
+            .filter(|c| *c != v)
+            .collect()
+    }
+
+    fn face(&self, f: usize) -> &Face {
+        self.faces[f].as_ref().expect("triangle in use")
+    }
+
+    /// Add the counter clockwise triangle and link the triangles given as its neighbors
+    /// back to it
+    fn attach(&mut self, corners: [usize; 3], neighbors: [Option<usize>; 3]) -> usize {
+        let f = self.add_face(corners, neighbors);
+        for k in 0..3 {
+            if let Some(n) = neighbors[k] {
+                let (a, b) = (corners[k], corners[(k + 1) % 3]);
+                let face = self.faces[n].as_mut().expect("neighbor in use");
+                let [u, w, x] = face.circle.corners;
+                if let Some(i) = [(u, w), (w, x), (x, u)].iter().position(|e| *e == (b, a)) {
+                    face.neighbors[i] = Some(f);
+                }
+            }
+        }
+        f
+    }
+
+    fn add_face(&mut self, corners: [usize; 3], neighbors: [Option<usize>; 3]) -> usize {
+        let face = Face {
+            circle: Circumscribed::oriented(&self.vertices, corners),
+            neighbors,
+        };
+        let f = match self.free.pop() {
+            Some(f) => {
+                self.faces[f] = Some(face);
+                f
+            }
+            None => {
+                self.faces.push(Some(face));
+                self.faces.len() - 1
+            }
+        };
+        for c in &corners {
+            self.vertex_faces[*c] = f;
+        }
+        f
+    }
+}
+
+/// Add vertex p to the triangulation by replacing the triangles whose circumcircle encloses it
+/// with a fan around p
+fn add_vertex(vertices: &[(f64, f64)], triangles: &mut Vec<Circumscribed>, p: usize) {
+    let point = vertices[p];
+    let (bad, good): (Vec<Circumscribed>, Vec<Circumscribed>) = std::mem::take(triangles)
+        .into_iter()
+        .partition(|t| t.encloses(point));
+    *triangles = good;
+    // The cavity border consists of the edges belonging to exactly one bad triangle
+    let edges: Vec<(usize, usize)> = bad
+        .iter()
+        .flat_map(|t| {
+            let [a, b, c] = t.corners;
+            vec![(a, b), (b, c), (c, a)]
+        })
+        .collect();
+    for &(a, b) in &edges {
+        let shared = edges.iter().filter(|(c, d)| (*c, *d) == (b, a)).count() > 0;
+        if !shared {
+            triangles.push(Circumscribed::new(vertices, [a, b, p]));
+        }
+    }
+}
+
+/// Return min x, min y, max x and max y of the points
+fn bounds(points: impl Iterator<Item = (f64, f64)>) -> [f64; 4] {
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for (x, y) in points {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+    [min_x, min_y, max_x, max_y]
+}
+
+/// Return a triangle enclosing the bounds with a wide margin, whose corners are left out of
+/// the final triangulation
+fn super_triangle([min_x, min_y, max_x, max_y]: [f64; 4]) -> [(f64, f64); 3] {
+    let size = (max_x - min_x).max(max_y - min_y).max(1.0);
+    let (cx, cy) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+    [
+        (cx - 20.0 * size, cy - 10.0 * size),
+        (cx + 20.0 * size, cy - 10.0 * size),
+        (cx, cy + 20.0 * size),
+    ]
+}
+
+/// Center of the circle through the three points, None if they are collinear
+pub(crate) fn circumcenter(a: Point, b: Point, c: Point) -> Option<Point> {
+    let d = 2.0 * ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y));
+    if d == 0.0 {
+        return None;
+    }
+    let (sb, sc) = (
+        (b.x - a.x).powi(2) + (b.y - a.y).powi(2),
+        (c.x - a.x).powi(2) + (c.y - a.y).powi(2),
+    );
+    Some(Point {
+        x: a.x + ((c.y - a.y) * sb - (b.y - a.y) * sc) / d,
+        y: a.y + ((b.x - a.x) * sc - (c.x - a.x) * sb) / d,
+    })
+}
+
+/// Counter clockwise triangle with its circumcircle
+#[derive(Debug, Clone)]
+struct Circumscribed {
+    corners: [usize; 3],
+    center: (f64, f64),
+    radius_squared: f64,
+}
+
+impl Circumscribed {
+    fn new(vertices: &[(f64, f64)], corners: [usize; 3]) -> Circumscribed {
+        let [mut a, b, mut c] = corners;
+        if orientation(vertices[a], vertices[b], vertices[c]) < 0.0 {
+            std::mem::swap(&mut a, &mut c);
+        }
+        Circumscribed::oriented(vertices, [a, b, c])
+    }
+
+    /// Return the triangle with the corners in the given order, which must be counter
+    /// clockwise
+    fn oriented(vertices: &[(f64, f64)], [a, b, c]: [usize; 3]) -> Circumscribed {
+        let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+        let d = 2.0 * orientation(pa, pb, pc);
+        let center = if d == 0.0 {
+            // Flat triangles enclose every point, so they are replaced right away
+            (f64::NAN, f64::NAN)
+        } else {
+            let (sa, sb, sc) = (
+                pa.0 * pa.0 + pa.1 * pa.1,
+                pb.0 * pb.0 + pb.1 * pb.1,
+                pc.0 * pc.0 + pc.1 * pc.1,
+            );
+            (
+                (sa * (pb.1 - pc.1) + sb * (pc.1 - pa.1) + sc * (pa.1 - pb.1)) / d,
+                (sa * (pc.0 - pb.0) + sb * (pa.0 - pc.0) + sc * (pb.0 - pa.0)) / d,
+            )
+        };
+        let radius_squared = (pa.0 - center.0).powi(2) + (pa.1 - center.1).powi(2);
+        Circumscribed {
+            corners: [a, b, c],
+            center,
+            radius_squared,
+        }
+    }
+
+    fn encloses(&self, point: (f64, f64)) -> bool {
+        if self.center.0.is_nan() {
+            return true;
+        }
+        let distance = (point.0 - self.center.0).powi(2) + (point.1 - self.center.1).powi(2);
+        distance < self.radius_squared * (1.0 - 1e-12)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_delaunay_square_with_center() {
+        let points = [
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(2.0, 2.0),
+            p(0.0, 2.0),
+            p(1.0, 1.0),
+            p(1.0, 1.0),
+        ];
+        let mesh = delaunay(&points);
+        assert_eq!(4 * 3, mesh.indices.len());
+        assert!((mesh.area() - 4.0).abs() < 1e-5);
+        assert!(mesh.indices.iter().all(|i| *i != 5));
+    }
+
+    #[test]
+    fn test_empty_circumcircles() {
+        let points: Vec<Point> = (0..40)
+            .map(|i| {
+                let t = i as f32;
+                p((t * 7.3) % 11.0, (t * 3.7) % 5.0 + (t * 0.1))
+            })
+            .collect();
+        let mesh = delaunay(&points);
+        assert!(!mesh.indices.is_empty());
+        for [a, b, c] in mesh.triangles() {
+            let vertices = [
+                (a.x as f64, a.y as f64),
+                (b.x as f64, b.y as f64),
+                (c.x as f64, c.y as f64),
+            ];
+            let circle = Circumscribed::new(&vertices, [0, 1, 2]);
+            assert!(circle.corners == [0, 1, 2]);
+            let shrunk = Circumscribed {
+                radius_squared: circle.radius_squared * (1.0 - 1e-4),
+                ..circle
+            };
+            assert!(points
+                .iter()
+                .all(|q| !shrunk.encloses((q.x as f64, q.y as f64))));
+        }
+    }
+
+    #[test]
+    fn test_dynamic_updates() {
+        let mut triangulation = DynamicDelaunay::new();
+        let square = [p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)];
+        for q in &square {
+            triangulation.insert(*q).unwrap();
+        }
+        let center = triangulation.insert(p(1.0, 1.0)).unwrap();
+        assert_eq!(None, triangulation.insert(p(1.0, 1.0)));
+        let mesh = triangulation.to_mesh();
+        assert_eq!(4 * 3, mesh.indices.len());
+        assert!((mesh.area() - 4.0).abs() < 1e-5);
+        assert_eq!(4, triangulation.neighbors(center).len());
+        assert_eq!(Some(center), triangulation.nearest(p(1.2, 0.9)));
+
+        assert!(triangulation.remove(center));
+        assert!(!triangulation.remove(center));
+        let mesh = triangulation.to_mesh();
+        assert_eq!(2 * 3, mesh.indices.len());
+        assert!((mesh.area() - 4.0).abs() < 1e-5);
+        assert_eq!(Some(VertexId(0)), triangulation.nearest(p(0.8, 0.9)));
+    }
+
+    #[test]
+    fn test_removal_and_nearest_match_brute_force() {
+        let points: Vec<Point> = (0..60)
+            .map(|i| {
+                let t = i as f32;
+                p((t * 7.3) % 11.0, (t * 3.7) % 5.0 + (t * 0.1))
+            })
+            .collect();
+        let mut triangulation = DynamicDelaunay::new();
+        for q in &points {
+            triangulation.insert(*q).unwrap();
+        }
+        for i in (0..points.len()).step_by(2) {
+            assert!(triangulation.remove(VertexId(i)));
+        }
+        let kept: Vec<Point> = points.iter().skip(1).step_by(2).copied().collect();
+        let rebuilt = delaunay(&kept);
+        let updated = triangulation.to_mesh();
+        assert_eq!(rebuilt.indices.len(), updated.indices.len());
+        assert!((rebuilt.area() - updated.area()).abs() < 1e-3);
+        for k in 0..50 {
+            let t = k as f32;
+            let query = p((t * 2.9) % 11.0, (t * 1.3) % 8.0);
+            let closest = triangulation
+                .vertices()
+                .map(|(id, q)| (id, (q.x - query.x).powi(2) + (q.y - query.y).powi(2)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(_, distance)| distance);
+            let found = triangulation
+                .nearest(query)
+                .and_then(|id| triangulation.point(id));
+            let distance = found.map(|q| (q.x - query.x).powi(2) + (q.y - query.y).powi(2));
+            assert_eq!(closest, distance);
+        }
+    }
+
+    #[test]
+    fn test_moving_points_match_rebuild() {
+        let mut points: Vec<Point> = (0..30)
+            .map(|i| {
+                let t = i as f32;
+                p((t * 7.3) % 11.0, (t * 3.7) % 5.0 + (t * 0.1))
+            })
+            .collect();
+        let mut triangulation = DynamicDelaunay::new();
+        let ids: Vec<VertexId> = points
+            .iter()
+            .map(|q| triangulation.insert(*q).unwrap())
+            .collect();
+        for (k, id) in ids.iter().enumerate().step_by(3) {
+            // Some points leave the area seen so far and force a larger enclosing triangle
+            let moved = p(points[k].x * 1.5 + 0.25, points[k].y * 2.0 - 0.5);
+            assert!(triangulation.move_to(*id, moved));
+            points[k] = moved;
+        }
+        let rebuilt = delaunay(&points);
+        let updated = triangulation.to_mesh();
+        assert_eq!(rebuilt.indices.len(), updated.indices.len());
+        assert!((rebuilt.area() - updated.area()).abs() < 1e-3);
+        for id in &ids {
+            let mut expected: Vec<VertexId> = rebuilt
+                .indices
+                .chunks(3)
+                .filter(|t| t.contains(&(id.0 as u32)))
+                .flatten()
+                .filter(|i| **i as usize != id.0)
+                .map(|i| VertexId(*i as usize))
+                .collect();
+            expected.sort_unstable();
+            expected.dedup();
+            assert_eq!(expected, triangulation.neighbors(*id));
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Angle;
+
+/// Angle split into whole degrees, whole minutes and seconds, as used in surveying and
+/// astronomy. The sign applies to the whole angle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dms {
+    pub negative: bool,
+    pub degrees: u32,
+    pub minutes: u32,
+    pub seconds: f32,
+}
+
+impl fmt::Display for Dms {
+    /// Format like -48°12'30.5"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        write!(
+            f,
+            "{}{}°{}'{}\"",
+            sign, self.degrees, self.minutes, self.seconds
+        )
+    }
+}
+
+/// Reason an angle string could not be parsed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParseAngleError {
+    /// No number in the string
+    Empty,
+    /// A part is not a number
+    InvalidNumber,
+    /// More than degrees, minutes and seconds, or a sign together with a hemisphere
+    TooManyParts,
+    /// Minutes or seconds negative or not below 60
+    OutOfRange,
+}
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseAngleError::Empty => "no angle given",
+            ParseAngleError::InvalidNumber => "invalid number in angle",
+            ParseAngleError::TooManyParts => "too many parts in angle",
+            ParseAngleError::OutOfRange => "minutes and seconds must be in [0, 60)",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ParseAngleError {}
+
+impl Angle {
+    /// Return the angle of degrees, minutes and seconds. A negative degrees value makes the
+    /// whole angle negative, so from_dms(-48.0, 12.0, 30.0) is -48.2083°.
+    pub fn from_dms(degrees: f32, minutes: f32, seconds: f32) -> Angle {
+        let magnitude = degrees.abs() + minutes / 60.0 + seconds / 3600.0;
+        Angle::from_degrees(if degrees.is_sign_negative() {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+
+    /// Return the angle split into degrees, minutes and seconds, rounded to a tenth of a
+    /// second, about the resolution of f32 degrees
+    pub fn to_dms(self) -> Dms {
+        let tenths = (self.degrees().abs() as f64 * 36_000.0).round() as u64;
+        Dms {
+            negative: self.degrees() < 0.0 && tenths > 0,
+            degrees: (tenths / 36_000) as u32,
+            minutes: (tenths / 600 % 60) as u32,
+            seconds: (tenths % 600) as f32 / 10.0,
+        }
+    }
+
+    /// Format as a latitude like 48°12'30"N
+    pub fn format_latitude(&self) -> String {
+        hemisphere(*self, 'N', 'S')
+    }
+
+    /// Format as a longitude like 16°22'20"E
+    pub fn format_longitude(&self) -> String {
+        hemisphere(*self, 'E', 'W')
+    }
+}
+
+fn hemisphere(angle: Angle, positive: char, negative: char) -> String {
+    let dms = angle.to_dms();
+    let suffix = if dms.negative { negative } else { positive };
+    format!(
+        "{}{}",
+        Dms {
+            negative: false,
+            ..dms
+        },
+        suffix
+    )
+}
+
+impl FromStr for Angle {
+    type Err = ParseAngleError;
+
+    /// Parse decimal degrees or degrees, minutes and seconds separated by °, ', ", ′, ″ or
+    /// spaces, like 48°12'30"N, -48 12 30 or 16.5°. A trailing N or E keeps the angle
+    /// positive, S or W negates it.
+    fn from_str(text: &str) -> Result<Angle, ParseAngleError> {
+        let mut text = text.trim();
+        let mut negative = false;
+        let mut hemisphere = false;
+        if let Some(last) = text.chars().last() {
+            if "NnEeSsWw".contains(last) {
+                negative = "SsWw".contains(last);
+                hemisphere = true;
+                text = text[..text.len() - 1].trim_end();
+            }
+        }
+        if let Some(rest) = text.strip_prefix('-') {
+            if hemisphere {
+                return Err(ParseAngleError::TooManyParts);
+            }
+            negative = true;
+            text = rest;
+        }
+        let parts: Vec<&str> = text
+            .split(|c: char| "°'\"′″ ".contains(c))
+            .filter(|p| !p.is_empty())
+            .collect();
+        if parts.is_empty() {
+            return Err(ParseAngleError::Empty);
+        }
+        if parts.len() > 3 {
+            return Err(ParseAngleError::TooManyParts);
+        }
+        let mut values = [0.0f32; 3];
+        for (value, part) in values.iter_mut().zip(&parts) {
+            *value = part
+                .parse::<f32>()
+                .map_err(|_| ParseAngleError::InvalidNumber)?;
+        }
+        let [degrees, minutes, seconds] = values;
+        if degrees < 0.0 || !(0.0..60.0).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+            return Err(ParseAngleError::OutOfRange);
+        }
+        let angle = Angle::from_dms(degrees, minutes, seconds);
+        Ok(if negative { -angle } else { angle })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dms_round_trip() {
+        let angle = Angle::from_dms(-48.0, 12.0, 30.0);
+        assert!((angle.degrees() + 48.208_336).abs() < 1e-4);
+        let dms = angle.to_dms();
+        assert_eq!((true, 48, 12), (dms.negative, dms.degrees, dms.minutes));
+        assert_eq!(30.0, dms.seconds);
+        assert_eq!("16°30'0\"", Angle::from_degrees(16.5).to_dms().to_string());
+        assert_eq!(
+            "0°0'1\"W",
+            Angle::from_dms(-0.0, 0.0, 1.0).format_longitude()
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let north: Angle = "48°12'30\"N".parse().unwrap();
+        let south: Angle = "48 12 30 S".parse().unwrap();
+        assert!((north.degrees() - 48.208_336).abs() < 1e-4);
+        assert_eq!(-north, south);
+        assert_eq!(Ok(Angle::from_degrees(-16.5)), "-16.5°".parse());
+        assert_eq!(Ok(Angle::from_degrees(10.25)), "10°15′".parse());
+        assert_eq!(Err(ParseAngleError::OutOfRange), "10°75'".parse::<Angle>());
+        assert_eq!(Err(ParseAngleError::InvalidNumber), "ten°".parse::<Angle>());
+        assert_eq!(Err(ParseAngleError::Empty), " N".parse::<Angle>());
+        assert_eq!("48°12'30\"N", north.format_latitude());
+    }
+}
@@ -0,0 +1,294 @@
+use std::f64::consts::PI;
+
+use crate::{transcendental, Angle, Path, Point, Pose2};
+
+/// Steering of one piece of a Dubins path.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Steer {
+    Left,
+    Straight,
+    Right,
+}
+
+/// The six kinds of shortest paths for a car that only drives forward, named by the
+/// steering of their three pieces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DubinsWord {
+    Lsl,
+    Rsr,
+    Lsr,
+    Rsl,
+    Rlr,
+    Lrl,
+}
+
+impl DubinsWord {
+    pub fn steering(&self) -> [Steer; 3] {
+        use Steer::*;
+        match self {
+            DubinsWord::Lsl => [Left, Straight, Left],
+            DubinsWord::Rsr => [Right, Straight, Right],
+            DubinsWord::Lsr => [Left, Straight, Right],
+            DubinsWord::Rsl => [Right, Straight, Left],
+            DubinsWord::Rlr => [Right, Left, Right],
+            DubinsWord::Lrl => [Left, Right, Left],
+        }
+    }
+}
+
+const WORDS: [DubinsWord; 6] = [
+    DubinsWord::Lsl,
+    DubinsWord::Rsr,
+    DubinsWord::Lsr,
+    DubinsWord::Rsl,
+    DubinsWord::Rlr,
+    DubinsWord::Lrl,
+];
+
+/// Shortest path between two poses for a vehicle driving forward with a minimum turning
+/// radius. It consists of three pieces, turns of the radius and a straight line, whose
+/// lengths are measured along the path.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DubinsPath {
+    pub start: Pose2,
+    pub radius: f32,
+    pub word: DubinsWord,
+    pub lengths: [f32; 3],
+}
+
+impl DubinsPath {
+    /// Return the shortest path from start to end, None if the radius is not positive
+    pub fn shortest(start: &Pose2, end: &Pose2, radius: f32) -> Option<DubinsPath> {
+        if radius <= 0.0 || !radius.is_finite() {
+            return None;
+        }
+        let r = radius as f64;
+        let dx = (end.position.x - start.position.x) as f64;
+        let dy = (end.position.y - start.position.y) as f64;
+        let d = transcendental::hypot(dx, dy) / r;
+        let theta = transcendental::atan2(dy, dx);
+        let alpha = mod_two_pi(start.heading.radians() as f64 - theta);
+        let beta = mod_two_pi(end.heading.radians() as f64 - theta);
+        let (word, [t, p, q]) = WORDS
+            .iter()
+            .filter_map(|w| Some((*w, normalized_lengths(*w, alpha, beta, d)?)))
+            .min_by(|a, b| a.1.iter().sum::<f64>().total_cmp(&b.1.iter().sum::<f64>()))?;
+        Some(DubinsPath {
+            start: *start,
+            radius,
+            word,
+            lengths: [(t * r) as f32, (p * r) as f32, (q * r) as f32],
+        })
+    }
+
+    pub fn length(&self) -> f32 {
+        self.lengths.iter().sum()
+    }
+
+    /// Return the pose after driving distance along the path, clamped to [0, length]
+    pub fn pose_at(&self, distance: f32) -> Pose2 {
+        let mut pose = self.start;
+        let mut remaining = distance.max(0.0);
+        for (steer, length) in self.word.steering().iter().zip(&self.lengths) {
+            let step = remaining.min(*length);
+            pose = self.advance(&pose, *steer, step);
+            remaining -= step;
+        }
+        pose
+    }
+
+    pub fn end(&self) -> Pose2 {
+        self.pose_at(self.length())
+    }
+
+    /// Return the path as a line and arcs, leaving out pieces of zero length
+    pub fn to_path(&self) -> Path {
+        let mut path = Path::new();
+        path.move_to(self.start.position);
+        let mut pose = self.start;
+        for (steer, length) in self.word.steering().iter().zip(&self.lengths) {
+            if *length <= 0.0 {
+                continue;
+            }
+            let end = self.advance(&pose, *steer, *length);
+            let sweep = (*length / self.radius).to_degrees();
+            match steer {
+                Steer::Straight => path.line_to(end.position),
+                Steer::Left => path.arc_to(pose.transform_point(p(0.0, self.radius)), sweep),
+                Steer::Right => path.arc_to(pose.transform_point(p(0.0, -self.radius)), -sweep),
+            };
+            pose = end;
+        }
+        path
+    }
+
+    /// Pose after driving length from pose with the given steering
+    fn advance(&self, pose: &Pose2, steer: Steer, length: f32) -> Pose2 {
+        let turn = length / self.radius;
+        let (offset, heading) = match steer {
+            Steer::Straight => (p(length, 0.0), 0.0),
+            Steer::Left => (
+                p(
+                    self.radius * transcendental::sin(turn),
+                    self.radius * (1.0 - transcendental::cos(turn)),
+                ),
+                turn,
+            ),
+            Steer::Right => (
+                p(
+                    self.radius * transcendental::sin(turn),
+                    -self.radius * (1.0 - transcendental::cos(turn)),
+                ),
+                -turn,
+            ),
+        };
+        Pose2::new(
+            pose.transform_point(offset),
+            (pose.heading + Angle::from_radians(heading)).normalized(),
+        )
+    }
+}
+
+/// Lengths of the three pieces on a circle of radius 1 for the path of the given word, where
+/// the goal lies at distance d along the x axis and alpha and beta are the headings at start
+/// and goal. None if the word can not connect the poses.
+fn normalized_lengths(word: DubinsWord, alpha: f64, beta: f64, d: f64) -> Option<[f64; 3]> {
+    let (sa, sb, ca, cb) = (
+        transcendental::sin(alpha),
+        transcendental::sin(beta),
+        transcendental::cos(alpha),
+        transcendental::cos(beta),
+    );
+    let c_ab = transcendental::cos(alpha - beta);
+    let lengths = match word {
+        DubinsWord::Lsl => {
+            let p_squared = 2.0 + d * d - 2.0 * c_ab + 2.0 * d * (sa - sb);
+            let turn = transcendental::atan2(cb - ca, d + sa - sb);
+            [
+                mod_two_pi(turn - alpha),
+                non_negative_sqrt(p_squared)?,
+                mod_two_pi(beta - turn),
+            ]
+        }
+        DubinsWord::Rsr => {
+            let p_squared = 2.0 + d * d - 2.0 * c_ab + 2.0 * d * (sb - sa);
+            let turn = transcendental::atan2(ca - cb, d - sa + sb);
+            [
+                mod_two_pi(alpha - turn),
+                non_negative_sqrt(p_squared)?,
+                mod_two_pi(turn - beta),
+            ]
+        }
+        DubinsWord::Lsr => {
+            let p = non_negative_sqrt(-2.0 + d * d + 2.0 * c_ab + 2.0 * d * (sa + sb))?;
+            let turn =
+                transcendental::atan2(-ca - cb, d + sa + sb) - transcendental::atan2(-2.0f64, p);
+            [mod_two_pi(turn - alpha), p, mod_two_pi(turn - beta)]
+        }
+        DubinsWord::Rsl => {
+            let p = non_negative_sqrt(-2.0 + d * d + 2.0 * c_ab - 2.0 * d * (sa + sb))?;
+            let turn =
+                transcendental::atan2(ca + cb, d - sa - sb) - transcendental::atan2(2.0f64, p);
+            [mod_two_pi(alpha - turn), p, mod_two_pi(beta - turn)]
+        }
+        DubinsWord::Rlr => {
+            let cos_p = (6.0 - d * d + 2.0 * c_ab + 2.0 * d * (sa - sb)) / 8.0;
+            if cos_p.abs() > 1.0 {
+                return None;
+            }
+            let phi = transcendental::atan2(ca - cb, d - sa + sb);
+            let p = mod_two_pi(2.0 * PI - transcendental::acos(cos_p));
+            let t = mod_two_pi(alpha - phi + p / 2.0);
+            [t, p, mod_two_pi(alpha - beta - t + p)]
+        }
+        DubinsWord::Lrl => {
+            let cos_p = (6.0 - d * d + 2.0 * c_ab + 2.0 * d * (sb - sa)) / 8.0;
+            if cos_p.abs() > 1.0 {
+                return None;
+            }
+            let phi = transcendental::atan2(ca - cb, d + sa - sb);
+            let p = mod_two_pi(2.0 * PI - transcendental::acos(cos_p));
+            let t = mod_two_pi(-alpha - phi + p / 2.0);
+            [t, p, mod_two_pi(beta - alpha - t + p)]
+        }
+    };
+    Some(lengths)
+}
+
+fn non_negative_sqrt(value: f64) -> Option<f64> {
+    if value < 0.0 {
+        None
+    } else {
+        Some(value.sqrt())
+    }
+}
+
+fn mod_two_pi(angle: f64) -> f64 {
+    angle.rem_euclid(2.0 * PI)
+}
+
+fn p(x: f32, y: f32) -> Point {
+    Point { x, y }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pose(x: f32, y: f32, degrees: f32) -> Pose2 {
+        Pose2::new(p(x, y), Angle::from_degrees(degrees))
+    }
+
+    fn assert_pose(expected: Pose2, result: Pose2) {
+        assert!(
+            (expected.position.x - result.position.x).abs() < 1e-3,
+            "{:?}",
+            result
+        );
+        assert!(
+            (expected.position.y - result.position.y).abs() < 1e-3,
+            "{:?}",
+            result
+        );
+        let turn = (expected.heading - result.heading).normalized().degrees();
+        assert!(turn.abs() < 1e-2, "{:?}", result);
+    }
+
+    #[test]
+    fn test_simple_paths() {
+        let straight =
+            DubinsPath::shortest(&pose(0.0, 0.0, 0.0), &pose(10.0, 0.0, 0.0), 1.0).unwrap();
+        assert!((straight.length() - 10.0).abs() < 1e-4);
+        let u_turn =
+            DubinsPath::shortest(&pose(0.0, 0.0, 0.0), &pose(0.0, 4.0, 180.0), 2.0).unwrap();
+        assert_eq!(DubinsWord::Lsl, u_turn.word);
+        assert!((u_turn.length() - 2.0 * std::f32::consts::PI).abs() < 1e-4);
+        assert_pose(pose(2.0, 2.0, 90.0), u_turn.pose_at(u_turn.length() / 2.0));
+        assert_eq!(
+            None,
+            DubinsPath::shortest(&pose(0.0, 0.0, 0.0), &pose(1.0, 0.0, 0.0), 0.0)
+        );
+    }
+
+    #[test]
+    fn test_paths_reach_goal() {
+        let start = pose(1.0, -2.0, 30.0);
+        for (k, goal) in [
+            pose(6.0, 3.0, -120.0),
+            pose(-4.0, 1.0, 90.0),
+            pose(1.5, -1.0, 200.0),
+            pose(0.0, -6.0, 10.0),
+        ]
+        .iter()
+        .enumerate()
+        {
+            let path = DubinsPath::shortest(&start, goal, 1.5).unwrap();
+            assert_pose(*goal, path.end());
+            let drawn = path.to_path();
+            let end = drawn.segments().last().unwrap().end_point();
+            assert!((end.x - goal.position.x).abs() < 1e-3, "goal {}", k);
+            assert!((end.y - goal.position.y).abs() < 1e-3, "goal {}", k);
+            assert!((drawn.length() - path.length()).abs() < 1e-3);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{transcendental, Point, Vector};
+
+/// Iterations of the closest point search, enough for f32 precision on any eccentricity.
+const CLOSEST_POINT_ITERATIONS: usize = 4;
+
+/// Ellipse around center. rotation is the angle in degrees of the major axis, counter
+/// clockwise from the positive x axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ellipse {
+    pub center: Point,
+    pub semi_major: f32,
+    pub semi_minor: f32,
+    pub rotation: f32,
+}
+
+impl Ellipse {
+    pub fn new(center: Point, semi_major: f32, semi_minor: f32, rotation: f32) -> Ellipse {
+        Ellipse {
+            center,
+            semi_major,
+            semi_minor,
+            rotation,
+        }
+    }
+
+    /// Return the point at parameter t, running once around the ellipse for t from 0 to 1
+    /// starting at the positive end of the major axis
+    pub fn point_at(&self, t: f32) -> Point {
+        let (sin, cos) = transcendental::sin_cos(t * std::f32::consts::PI * 2.0);
+        self.world(self.semi_major * cos, self.semi_minor * sin)
+    }
+
+    /// Return true if the point lies inside or on the ellipse
+    pub fn contains(&self, point: Point) -> bool {
+        let (x, y) = self.local(point);
+        (x / self.semi_major).powi(2) + (y / self.semi_minor).powi(2) <= 1.0
+    }
+
+    /// Return the point on the ellipse closest to the passed point
+    pub fn closest_point(&self, point: Point) -> Point {
+        let (px, py) = self.local(point);
+        let (a, b) = (self.semi_major, self.semi_minor);
+        // Iterate on the unit direction (tx, ty) of the closest point in the first quadrant,
+        // approximating the ellipse locally by its circle of curvature
+        let (mut tx, mut ty) = (
+            std::f32::consts::FRAC_1_SQRT_2,
+            std::f32::consts::FRAC_1_SQRT_2,
+        );
+        let (qx0, qy0) = (px.abs(), py.abs());
+        for _ in 0..CLOSEST_POINT_ITERATIONS {
+            let (x, y) = (a * tx, b * ty);
+            let ex = (a * a - b * b) * tx.powi(3) / a;
+            let ey = (b * b - a * a) * ty.powi(3) / b;
+            let (rx, ry) = (x - ex, y - ey);
+            let (qx, qy) = (qx0 - ex, qy0 - ey);
+            let r = (rx * rx + ry * ry).sqrt();
+            let q = (qx * qx + qy * qy).sqrt();
+            if q == 0.0 {
+                break;
+            }
+            tx = ((qx * r / q + ex) / a).clamp(0.0, 1.0);
+            ty = ((qy * r / q + ey) / b).clamp(0.0, 1.0);
+            let t = (tx * tx + ty * ty).sqrt();
+            tx /= t;
+            ty /= t;
+        }
+        self.world((a * tx).copysign(px), (b * ty).copysign(py))
+    }
+
+    /// Return the shortest distance between the point and the outline of the ellipse
+    pub fn distance_to(&self, point: Point) -> f32 {
+        Vector::new(self.closest_point(point), point).length()
+    }
+
+    /// Transform a point into the frame of the ellipse, with the major axis along x
+    fn local(&self, point: Point) -> (f32, f32) {
+        let (sin, cos) = transcendental::sin_cos(self.rotation.to_radians());
+        let (dx, dy) = (point.x - self.center.x, point.y - self.center.y);
+        (cos * dx + sin * dy, -sin * dx + cos * dy)
+    }
+
+    fn world(&self, x: f32, y: f32) -> Point {
+        let (sin, cos) = transcendental::sin_cos(self.rotation.to_radians());
+        Point {
+            x: self.center.x + cos * x - sin * y,
+            y: self.center.y + sin * x + cos * y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_point_at_and_contains() {
+        let ellipse = Ellipse::new(p(1.0, 1.0), 4.0, 2.0, 90.0);
+        let top = ellipse.point_at(0.0);
+        assert!((top.x - 1.0).abs() < 1e-5 && (top.y - 5.0).abs() < 1e-5);
+        assert!(ellipse.contains(p(1.0, 4.5)));
+        assert!(!ellipse.contains(p(3.5, 1.0)));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let ellipse = Ellipse::new(p(0.0, 0.0), 5.0, 1.0, 0.0);
+        let closest = ellipse.closest_point(p(0.0, 3.0));
+        assert!(closest.x.abs() < 1e-4 && (closest.y - 1.0).abs() < 1e-4);
+        assert!((ellipse.distance_to(p(-7.0, 0.0)) - 2.0).abs() < 1e-4);
+        let outside = p(3.0, 2.0);
+        let closest = ellipse.closest_point(outside);
+        let on_curve = (closest.x / 5.0).powi(2) + closest.y.powi(2);
+        assert!((on_curve - 1.0).abs() < 1e-3);
+        for i in 0..64 {
+            let other = ellipse.point_at(i as f32 / 64.0);
+            let d = Vector::new(other, outside).length();
+            assert!(ellipse.distance_to(outside) <= d + 1e-4);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeSet;
+
+use crate::delaunay::circumcenter;
+use crate::{delaunay, transcendental, Circle, Point, Rect};
+
+/// Return the largest axis aligned rectangle inside bounds with none of the points in its
+/// interior. Every maximal empty rectangle is held up on each side by a point or the bounds,
+/// so sweeping right and left from every point while narrowing the vertical gap finds it in
+/// quadratic time. Points outside bounds are ignored, None if bounds has no area.
+pub fn largest_empty_rect(points: &[Point], bounds: &Rect) -> Option<Rect> {
+    if bounds.width() <= 0.0 || bounds.height() <= 0.0 {
+        return None;
+    }
+    let inside = |p: &&Point| {
+        p.x > bounds.min.x && p.x < bounds.max.x && p.y > bounds.min.y && p.y < bounds.max.y
+    };
+    let mut sorted: Vec<Point> = points.iter().filter(inside).copied().collect();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+    let mut best = *bounds;
+    let mut best_area = -1.0;
+    let mut offer = |min: Point, max: Point| {
+        let area = (max.x - min.x) * (max.y - min.y);
+        if area > best_area {
+            best_area = area;
+            best = Rect { min, max };
+        }
+    };
+    let corner = |x, y| Point { x, y };
+
+    // Strips spanning the full height or width, held by the bounds on two sides
+    let mut xs: Vec<f32> = sorted.iter().map(|p| p.x).collect();
+    let mut ys: Vec<f32> = sorted.iter().map(|p| p.y).collect();
+    ys.sort_by(|a, b| a.total_cmp(b));
+    for (values, min, max) in [
+        (&mut xs, bounds.min.x, bounds.max.x),
+        (&mut ys, bounds.min.y, bounds.max.y),
+    ] {
+        values.insert(0, min);
+        values.push(max);
+    }
+    for pair in xs.windows(2) {
+        offer(corner(pair[0], bounds.min.y), corner(pair[1], bounds.max.y));
+    }
+    for pair in ys.windows(2) {
+        offer(corner(bounds.min.x, pair[0]), corner(bounds.max.x, pair[1]));
+    }
+
+    // Rectangles with a point on their left or right edge
+    for (i, start) in sorted.iter().enumerate() {
+        for rightwards in [true, false] {
+            let (mut bottom, mut top) = (bounds.min.y, bounds.max.y);
+            let others: Box<dyn Iterator<Item = &Point>> = if rightwards {
+                Box::new(sorted[i + 1..].iter())
+            } else {
+                Box::new(sorted[..i].iter().rev())
+            };
+            let mut end = if rightwards {
+                bounds.max.x
+            } else {
+                bounds.min.x
+            };
+            for other in others {
+                if other.x == start.x || other.y <= bottom || other.y >= top {
+                    continue;
+                }
+                offer(
+                    corner(start.x.min(other.x), bottom),
+                    corner(start.x.max(other.x), top),
+                );
+                if other.y > start.y {
+                    top = other.y;
+                } else if other.y < start.y {
+                    bottom = other.y;
+                } else {
+                    end = other.x;
+                    break;
+                }
+            }
+            offer(
+                corner(start.x.min(end), bottom),
+                corner(start.x.max(end), top),
+            );
+        }
+    }
+    Some(best)
+}
+
+/// Return the largest circle centered inside bounds with none of the points inside it. Its
+/// center is a vertex of the Voronoi diagram, the circumcenter of a Delaunay triangle, or
+/// where a Voronoi edge or a corner meets the bounds, and those candidates are all tried.
+/// The circle may reach beyond bounds. None if there are no points.
+pub fn largest_empty_circle(points: &[Point], bounds: &Rect) -> Option<Circle> {
+    if points.is_empty() {
+        return None;
+    }
+    let mesh = delaunay(points);
+    let mut neighbors: BTreeSet<(usize, usize)> = BTreeSet::new();
+    let mut candidates: Vec<Point> = vec![
+        bounds.min,
+        bounds.max,
+        Point {
+            x: bounds.min.x,
+            y: bounds.max.y,
+        },
+        Point {
+            x: bounds.max.x,
+            y: bounds.min.y,
+        },
+    ];
+    for t in mesh.indices.chunks(3) {
+        let [a, b, c] = [t[0] as usize, t[1] as usize, t[2] as usize];
+        for (u, v) in [(a, b), (b, c), (c, a)] {
+            neighbors.insert((u.min(v), u.max(v)));
+        }
+        if let Some(center) = circumcenter(points[a], points[b], points[c]) {
+            candidates.push(center);
+        }
+    }
+    if neighbors.is_empty() {
+        // Collinear points have no triangles; neighbors along the line bound the cells
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        order.sort_by(|a, b| {
+            (points[*a].x, points[*a].y)
+                .partial_cmp(&(points[*b].x, points[*b].y))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        neighbors.extend(order.windows(2).map(|w| (w[0], w[1])));
+    }
+    let sides = [
+        (bounds.min, (bounds.width(), 0.0)),
+        (bounds.min, (0.0, bounds.height())),
+        (bounds.max, (-bounds.width(), 0.0)),
+        (bounds.max, (0.0, -bounds.height())),
+    ];
+    for (u, v) in neighbors {
+        let (a, b) = (points[u], points[v]);
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        for (start, (sx, sy)) in sides {
+            // Point of the side equally far from a and b
+            let denominator = 2.0 * (sx * dx + sy * dy);
+            if denominator == 0.0 {
+                continue;
+            }
+            let t = (b.x * b.x + b.y * b.y
+                - a.x * a.x
+                - a.y * a.y
+                - 2.0 * (start.x * dx + start.y * dy))
+                / denominator;
+            if (0.0..=1.0).contains(&t) {
+                candidates.push(Point {
+                    x: start.x + sx * t,
+                    y: start.y + sy * t,
+                });
+            }
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|c| {
+            c.x >= bounds.min.x && c.x <= bounds.max.x && c.y >= bounds.min.y && c.y <= bounds.max.y
+        })
+        .map(|center| {
+            let radius = points
+                .iter()
+                .map(|p| transcendental::hypot(p.x - center.x, p.y - center.y))
+                .fold(f32::MAX, f32::min);
+            Circle::new(center, radius)
+        })
+        .max_by(|a, b| a.radius.total_cmp(&b.radius))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_largest_empty_rect() {
+        let bounds = Rect::new(p(0.0, 0.0), p(10.0, 10.0));
+        assert_eq!(Some(bounds), largest_empty_rect(&[], &bounds));
+        let points = [p(2.0, 5.0), p(5.0, 8.0), p(7.0, 3.0), p(20.0, 20.0)];
+        let rect = largest_empty_rect(&points, &bounds).unwrap();
+        assert_eq!(40.0, rect.width() * rect.height());
+        assert!(points.iter().all(|q| {
+            !(q.x > rect.min.x && q.x < rect.max.x && q.y > rect.min.y && q.y < rect.max.y)
+        }));
+        assert_eq!(
+            None,
+            largest_empty_rect(&points, &Rect::new(p(1.0, 1.0), p(1.0, 5.0)))
+        );
+    }
+
+    #[test]
+    fn test_largest_empty_circle() {
+        let bounds = Rect::new(p(0.0, 0.0), p(4.0, 4.0));
+        let corners = [p(0.0, 0.0), p(4.0, 0.0), p(4.0, 4.0), p(0.0, 4.0)];
+        let circle = largest_empty_circle(&corners, &bounds).unwrap();
+        assert!((circle.center.x - 2.0).abs() < 1e-5 && (circle.center.y - 2.0).abs() < 1e-5);
+        assert!((circle.radius - 8.0f32.sqrt()).abs() < 1e-5);
+        // Two points near the bottom leave the most room at the upper corners
+        let pair = [p(1.5, 1.0), p(2.5, 1.0)];
+        let circle = largest_empty_circle(&pair, &bounds).unwrap();
+        assert_eq!(4.0, circle.center.y);
+        assert!((circle.radius - 11.25f32.sqrt()).abs() < 1e-5);
+        assert_eq!(None, largest_empty_circle(&[], &bounds));
+    }
+}
@@ -0,0 +1,162 @@
+//! Errors of the solvers and of the angle accessors, for callers that need the offending
+//! values instead of a NaN or a None. With the std feature, on by default, they implement
+//! Display and std::error::Error through thiserror.
+
+use crate::{Point, Residuals, Segment, SketchError, Triangle, Vector};
+
+/// Reason why a computation has no meaningful result.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum TrigError {
+    /// The corners lie on one line, so the angles are undefined
+    #[cfg_attr(
+        feature = "std",
+        error("degenerate triangle, corners {a:?}, {b:?} and {c:?} are collinear")
+    )]
+    DegenerateTriangle { a: Point, b: Point, c: Point },
+    /// Both ends of the vector are at point, so it has no direction
+    #[cfg_attr(feature = "std", error("zero length vector at {point:?}"))]
+    ZeroLengthVector { point: Point },
+    #[cfg_attr(feature = "std", error("{first:?} and {second:?} do not intersect"))]
+    NoIntersection { first: Segment, second: Segment },
+    /// An iterative solver stopped with constraints still violated
+    #[cfg_attr(
+        feature = "std",
+        error("solver did not converge, largest violation {}", .residuals.max)
+    )]
+    NonConvergence { residuals: Residuals },
+    /// The constraints of a TriangleBuilder fix no single triangle
+    #[cfg_attr(feature = "std", error(transparent))]
+    UnsolvableTriangle(TriangleError),
+}
+
+/// Reason why a TriangleBuilder could not be solved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum TriangleError {
+    /// Too few constraints, for example only angles or only two sides
+    #[cfg_attr(feature = "std", error("triangle is under constrained"))]
+    UnderConstrained,
+    /// Redundant constraints contradict each other
+    #[cfg_attr(feature = "std", error("triangle constraints contradict each other"))]
+    OverConstrained,
+    /// The constraints fit two different triangles, two sides and an angle opposite the
+    /// shorter one
+    #[cfg_attr(feature = "std", error("triangle constraints have two solutions"))]
+    Ambiguous,
+    /// No triangle satisfies the constraints, for example sides violating the triangle
+    /// inequality or angles summing to 180 degrees or more
+    #[cfg_attr(feature = "std", error("no triangle satisfies the constraints"))]
+    Impossible,
+}
+
+impl From<TriangleError> for TrigError {
+    fn from(error: TriangleError) -> TrigError {
+        TrigError::UnsolvableTriangle(error)
+    }
+}
+
+impl From<SketchError> for TrigError {
+    fn from(error: SketchError) -> TrigError {
+        TrigError::NonConvergence {
+            residuals: error.residuals,
+        }
+    }
+}
+
+impl Vector {
+    /// Return alpha and beta together, or the error of a vector without length
+    pub fn try_angles(&mut self) -> Result<(f32, f32), TrigError> {
+        Ok((self.alpha()?, self.beta()?))
+    }
+}
+
+impl Triangle {
+    /// Return alpha, beta and gamma together, or the error of collinear corners
+    pub fn try_angles(&mut self) -> Result<[f32; 3], TrigError> {
+        Ok([self.alpha()?, self.beta()?, self.gamma()?])
+    }
+}
+
+impl Segment {
+    /// Return the crossing point like intersection, with an error naming both segments
+    /// when they miss each other or are parallel
+    pub fn try_intersection(&self, other: &Segment) -> Result<Point, TrigError> {
+        Segment::intersection(self, other).ok_or(TrigError::NoIntersection {
+            first: *self,
+            second: *other,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::{Constraint, Sketch, TriangleBuilder};
+
+    #[test]
+    fn test_checked_angles() {
+        let mut flat = Triangle::new(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0));
+        assert_eq!(
+            Err(TrigError::DegenerateTriangle {
+                a: p(0.0, 0.0),
+                b: p(1.0, 1.0),
+                c: p(2.0, 2.0)
+            }),
+            flat.try_angles()
+        );
+        let angles = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0))
+            .try_angles()
+            .unwrap();
+        assert!((angles[0] - 90.0).abs() < 1e-3);
+        let mut point = Vector::new(p(1.0, 2.0), p(1.0, 2.0));
+        assert_eq!(
+            Err(TrigError::ZeroLengthVector { point: p(1.0, 2.0) }),
+            point.try_angles()
+        );
+        assert!(point.alpha().is_err() && point.beta().is_err());
+        let mut coinciding = Triangle::new_initialized(p(1.0, 1.0), p(1.0, 1.0), p(3.0, 0.0));
+        assert!(matches!(
+            coinciding.gamma(),
+            Err(TrigError::DegenerateTriangle { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unsolvable_triangle() {
+        let error = TriangleBuilder::new().alpha(60.0).solve().unwrap_err();
+        assert_eq!(
+            TrigError::UnsolvableTriangle(TriangleError::UnderConstrained),
+            error
+        );
+        #[cfg(feature = "std")]
+        assert_eq!("triangle is under constrained", error.to_string());
+    }
+
+    #[test]
+    fn test_intersection_and_convergence() {
+        let (a, b) = (
+            Segment::new(p(0.0, 0.0), p(1.0, 0.0)),
+            Segment::new(p(0.0, 1.0), p(1.0, 1.0)),
+        );
+        assert_eq!(
+            Err(TrigError::NoIntersection {
+                first: a,
+                second: b
+            }),
+            a.try_intersection(&b)
+        );
+        let crossing = Segment::new(p(0.5, -1.0), p(0.5, 1.0));
+        assert_eq!(Ok(p(0.5, 0.0)), a.try_intersection(&crossing));
+
+        let mut sketch = Sketch::new();
+        let (start, end) = (sketch.add_point(p(0.0, 0.0)), sketch.add_point(p(1.0, 0.0)));
+        sketch.fix(start);
+        sketch.fix(end);
+        let segment = sketch.add_segment(start, end);
+        sketch.add_constraint(Constraint::Length(segment, 5.0));
+        let error: TrigError = sketch.solve().unwrap_err().into();
+        assert!(matches!(error, TrigError::NonConvergence { residuals } if residuals.max > 1.0));
+    }
+}
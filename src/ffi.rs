@@ -0,0 +1,154 @@
+//! C interface, enabled by the ffi feature. Point and SolvedTriangle are repr(C) and cross
+//! the boundary as they are; solver failures come back as a TrigStatus. Angles are in
+//! degrees. A matching C declaration of the interface:
+//!
+//! ```c
+//! typedef struct { float x, y; } TrigPoint;
+//! typedef struct { float ab, bc, ca, alpha, beta, gamma; } TrigSolvedTriangle;
+//! float trig_vector_length(TrigPoint a, TrigPoint b);
+//! float trig_vector_angle(TrigPoint a, TrigPoint b);
+//! int trig_triangle_solve(float ab, float bc, float ca, float alpha, float beta,
+//!                         float gamma, TrigSolvedTriangle *out);
+//! float trig_polygon_area(const TrigPoint *points, size_t count);
+//! ```
+
+use crate::{
+    transcendental, Area, Point, Polygon, SolvedTriangle, TriangleBuilder, TriangleError,
+    TrigError, Vector,
+};
+
+/// Result of the C functions that can fail. Values of TriangleError keep their meaning.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TrigStatus {
+    Ok = 0,
+    UnderConstrained = 1,
+    OverConstrained = 2,
+    Ambiguous = 3,
+    Impossible = 4,
+    NullPointer = 5,
+}
+
+impl From<TrigError> for TrigStatus {
+    /// Errors other than an unsolvable triangle have no status of their own and count as
+    /// Impossible
+    fn from(error: TrigError) -> TrigStatus {
+        match error {
+            TrigError::UnsolvableTriangle(TriangleError::UnderConstrained) => {
+                TrigStatus::UnderConstrained
+            }
+            TrigError::UnsolvableTriangle(TriangleError::OverConstrained) => {
+                TrigStatus::OverConstrained
+            }
+            TrigError::UnsolvableTriangle(TriangleError::Ambiguous) => TrigStatus::Ambiguous,
+            _ => TrigStatus::Impossible,
+        }
+    }
+}
+
+/// Return the length of vector ab
+#[no_mangle]
+pub extern "C" fn trig_vector_length(a: Point, b: Point) -> f32 {
+    Vector::new(a, b).length()
+}
+
+/// Return the direction of vector ab counter clockwise from the positive x axis, in
+/// (-180, 180]
+#[no_mangle]
+pub extern "C" fn trig_vector_angle(a: Point, b: Point) -> f32 {
+    transcendental::atan2(b.y - a.y, b.x - a.x).to_degrees()
+}
+
+/// Solve a triangle from the known sides and angles, passing NaN for unknown values, and
+/// write the result to out.
+///
+/// # Safety
+///
+/// out must be null or point to memory valid for writing a SolvedTriangle.
+#[no_mangle]
+pub unsafe extern "C" fn trig_triangle_solve(
+    ab: f32,
+    bc: f32,
+    ca: f32,
+    alpha: f32,
+    beta: f32,
+    gamma: f32,
+    out: *mut SolvedTriangle,
+) -> TrigStatus {
+    if out.is_null() {
+        return TrigStatus::NullPointer;
+    }
+    let mut builder = TriangleBuilder::new();
+    let known = |value: f32| if value.is_nan() { None } else { Some(value) };
+    if let Some(v) = known(ab) {
+        builder.ab(v);
+    }
+    if let Some(v) = known(bc) {
+        builder.bc(v);
+    }
+    if let Some(v) = known(ca) {
+        builder.ca(v);
+    }
+    if let Some(v) = known(alpha) {
+        builder.alpha(v);
+    }
+    if let Some(v) = known(beta) {
+        builder.beta(v);
+    }
+    if let Some(v) = known(gamma) {
+        builder.gamma(v);
+    }
+    match builder.solve() {
+        Ok(solved) => {
+            out.write(solved);
+            TrigStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Return the area of the polygon through count points, 0 for a null pointer.
+///
+/// # Safety
+///
+/// points must be null or point to count initialized points.
+#[no_mangle]
+pub unsafe extern "C" fn trig_polygon_area(points: *const Point, count: usize) -> f32 {
+    if points.is_null() {
+        return 0.0;
+    }
+    let points = std::slice::from_raw_parts(points, count);
+    Polygon::new(points.to_vec()).area()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_vector_and_polygon() {
+        assert_eq!(5.0, trig_vector_length(p(1.0, 1.0), p(4.0, 5.0)));
+        assert_eq!(90.0, trig_vector_angle(p(1.0, 1.0), p(1.0, 3.0)));
+        let square = [p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)];
+        assert_eq!(4.0, unsafe {
+            trig_polygon_area(square.as_ptr(), square.len())
+        });
+        assert_eq!(0.0, unsafe { trig_polygon_area(std::ptr::null(), 4) });
+    }
+
+    #[test]
+    fn test_triangle_solve() {
+        let mut out = std::mem::MaybeUninit::<SolvedTriangle>::uninit();
+        let (nan, target) = (f32::NAN, out.as_mut_ptr());
+        let status = unsafe { trig_triangle_solve(3.0, 4.0, 5.0, nan, nan, nan, target) };
+        assert_eq!(TrigStatus::Ok, status);
+        let solved = unsafe { out.assume_init() };
+        assert!((solved.beta - 90.0).abs() < 1e-3);
+        let status = unsafe { trig_triangle_solve(3.0, nan, nan, nan, nan, nan, target) };
+        assert_eq!(TrigStatus::UnderConstrained, status);
+        let null =
+            unsafe { trig_triangle_solve(3.0, 4.0, 5.0, nan, nan, nan, std::ptr::null_mut()) };
+        assert_eq!(TrigStatus::NullPointer, null);
+    }
+}
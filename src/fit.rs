@@ -0,0 +1,592 @@
+use crate::{transcendental, Circle, Ellipse, Line, Plane, Point, Point3};
+
+/// Iteration limit of the geometric circle fit.
+const MAX_FIT_ITERATIONS: usize = 100;
+
+/// How well a fitted shape matches the points it was fitted to, from the distances of the
+/// points to the shape.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Residuals {
+    /// Root mean square of the distances
+    pub rms: f32,
+    /// Largest single distance
+    pub max: f32,
+}
+
+impl Residuals {
+    /// Summarize the distances of all points, which must not be empty
+    pub(crate) fn from_distances(distances: impl Iterator<Item = f32>) -> Residuals {
+        let (mut count, mut squares, mut max) = (0usize, 0.0f64, 0.0f32);
+        for d in distances {
+            count += 1;
+            squares += (d as f64) * (d as f64);
+            max = max.max(d.abs());
+        }
+        Residuals {
+            rms: (squares / count as f64).sqrt() as f32,
+            max,
+        }
+    }
+}
+
+impl Line {
+    /// Fit a line by total least squares (orthogonal regression), minimizing the
+    /// perpendicular distances of the points rather than vertical offsets, so steep and
+    /// vertical walls fit as well as flat ones. None for less than two points.
+    pub fn fit(points: &[Point]) -> Option<(Line, Residuals)> {
+        if points.len() < 2 {
+            return None;
+        }
+        let n = points.len() as f64;
+        let (mx, my) = points.iter().fold((0.0f64, 0.0f64), |(x, y), p| {
+            (x + p.x as f64 / n, y + p.y as f64 / n)
+        });
+        let (mut sxx, mut sxy, mut syy) = (0.0f64, 0.0f64, 0.0f64);
+        for p in points {
+            let (dx, dy) = (p.x as f64 - mx, p.y as f64 - my);
+            sxx += dx * dx;
+            sxy += dx * dy;
+            syy += dy * dy;
+        }
+        // The line runs along the principal axis of the scatter matrix
+        let angle = 0.5 * transcendental::atan2(2.0 * sxy, sxx - syy);
+        let (a, b) = (-transcendental::sin(angle), transcendental::cos(angle));
+        let line = Line::new(a as f32, b as f32, -(a * mx + b * my) as f32);
+        let residuals = Residuals::from_distances(points.iter().map(|p| line.distance_to(*p)));
+        Some((line, residuals))
+    }
+}
+
+impl Plane {
+    /// Fit a plane by total least squares, minimizing the perpendicular distances of the
+    /// points, so floors and walls fit alike. The normal is the direction of least spread of
+    /// the points around their centroid. None for less than three points or when all points
+    /// are collinear.
+    pub fn fit(points: &[Point3]) -> Option<(Plane, Residuals)> {
+        if points.len() < 3 {
+            return None;
+        }
+        let n = points.len() as f64;
+        let mut mean = [0.0f64; 3];
+        for p in points {
+            for (m, v) in mean.iter_mut().zip([p.x, p.y, p.z]) {
+                *m += v as f64 / n;
+            }
+        }
+        let mut scatter = [[0.0f64; 3]; 3];
+        for p in points {
+            let d = [
+                p.x as f64 - mean[0],
+                p.y as f64 - mean[1],
+                p.z as f64 - mean[2],
+            ];
+            for (i, row) in scatter.iter_mut().enumerate() {
+                for (j, s) in row.iter_mut().enumerate() {
+                    *s += d[i] * d[j];
+                }
+            }
+        }
+        let spread = |v: &[f64; 3]| {
+            let mv = [0, 1, 2].map(|i| (0..3).map(|j| scatter[i][j] * v[j]).sum::<f64>());
+            (0..3).map(|i| v[i] * mv[i]).sum::<f64>() / v.iter().map(|x| x * x).sum::<f64>()
+        };
+        let normal = real_eigenvectors3(scatter).into_iter().min_by(|a, b| {
+            spread(a)
+                .partial_cmp(&spread(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        // With the least eigenvalue removed, the sum of the 2x2 minors is the product of the
+        // other two, which vanishes when the points spread along one direction only
+        let trace = scatter[0][0] + scatter[1][1] + scatter[2][2];
+        let minors = scatter[0][0] * scatter[1][1] - scatter[0][1] * scatter[1][0]
+            + scatter[0][0] * scatter[2][2]
+            - scatter[0][2] * scatter[2][0]
+            + scatter[1][1] * scatter[2][2]
+            - scatter[1][2] * scatter[2][1];
+        let least = spread(&normal);
+        if minors - least * (trace - least) <= 1e-12 * trace * trace {
+            return None;
+        }
+        let centroid = Point3::new(mean[0] as f32, mean[1] as f32, mean[2] as f32);
+        let direction = Point3::new(normal[0] as f32, normal[1] as f32, normal[2] as f32);
+        let plane = Plane::through(centroid, direction)?;
+        let residuals = Residuals::from_distances(points.iter().map(|p| plane.distance_to(*p)));
+        Some((plane, residuals))
+    }
+}
+
+impl Circle {
+    /// Fit a circle algebraically (Kåsa method) by solving a linear least squares problem.
+    /// Fast and exact for points on a circle, but biased towards smaller circles when the
+    /// points cover only a short arc. None for less than three points or collinear points.
+    pub fn fit_algebraic(points: &[Point]) -> Option<(Circle, Residuals)> {
+        let circle = kasa(points)?;
+        Some((circle, circle_residuals(&circle, points)))
+    }
+
+    /// Fit a circle geometrically by minimizing the distances of the points to the circle
+    /// with Levenberg-Marquardt iterations, starting from the algebraic fit. More accurate
+    /// for noisy partial arcs. None for less than three points or collinear points.
+    pub fn fit_geometric(points: &[Point]) -> Option<(Circle, Residuals)> {
+        let start = kasa(points)?;
+        let (mut cx, mut cy, mut r) = (
+            start.center.x as f64,
+            start.center.y as f64,
+            start.radius as f64,
+        );
+        let cost = |cx: f64, cy: f64, r: f64| -> f64 {
+            points
+                .iter()
+                .map(|p| {
+                    let d = ((p.x as f64 - cx).powi(2) + (p.y as f64 - cy).powi(2)).sqrt() - r;
+                    d * d
+                })
+                .sum()
+        };
+        let mut current = cost(cx, cy, r);
+        let mut damping = 1e-3;
+        for _ in 0..MAX_FIT_ITERATIONS {
+            let mut jtj = [[0.0f64; 3]; 3];
+            let mut jtr = [0.0f64; 3];
+            for p in points {
+                let (dx, dy) = (p.x as f64 - cx, p.y as f64 - cy);
+                let distance = (dx * dx + dy * dy).sqrt();
+                if distance == 0.0 {
+                    continue;
+                }
+                let row = [-dx / distance, -dy / distance, -1.0];
+                let residual = distance - r;
+                for i in 0..3 {
+                    jtr[i] += row[i] * residual;
+                    for j in 0..3 {
+                        jtj[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+            for (i, row) in jtj.iter_mut().enumerate() {
+                row[i] *= 1.0 + damping;
+            }
+            let step = match solve3(jtj, [-jtr[0], -jtr[1], -jtr[2]]) {
+                Some(step) => step,
+                None => break,
+            };
+            let next = cost(cx + step[0], cy + step[1], r + step[2]);
+            if next < current {
+                cx += step[0];
+                cy += step[1];
+                r += step[2];
+                damping /= 10.0;
+                let converged = current - next <= 1e-12 * current.max(1e-30);
+                current = next;
+                if converged {
+                    break;
+                }
+            } else {
+                damping *= 10.0;
+                if damping > 1e10 {
+                    break;
+                }
+            }
+        }
+        let circle = Circle::new(
+            Point {
+                x: cx as f32,
+                y: cy as f32,
+            },
+            r.abs() as f32,
+        );
+        Some((circle, circle_residuals(&circle, points)))
+    }
+}
+
+impl Ellipse {
+    /// Fit an ellipse by direct least squares (Fitzgibbon, in the numerically stable form of
+    /// Halíř and Flusser). The algebraic distance is minimized under a constraint that
+    /// guarantees an ellipse, never a parabola or hyperbola. The residuals are the true
+    /// distances of the points to the fitted ellipse. None for less than five points or
+    /// degenerate input such as collinear points.
+    pub fn fit(points: &[Point]) -> Option<(Ellipse, Residuals)> {
+        if points.len() < 5 {
+            return None;
+        }
+        // Center and scale the points, so the quartic sums stay well conditioned
+        let n = points.len() as f64;
+        let (mx, my) = points.iter().fold((0.0f64, 0.0f64), |(x, y), p| {
+            (x + p.x as f64 / n, y + p.y as f64 / n)
+        });
+        let scale = (points
+            .iter()
+            .map(|p| (p.x as f64 - mx).powi(2) + (p.y as f64 - my).powi(2))
+            .sum::<f64>()
+            / n)
+            .sqrt();
+        if scale == 0.0 {
+            return None;
+        }
+        let (mut s1, mut s2, mut s3) = ([[0.0f64; 3]; 3], [[0.0f64; 3]; 3], [[0.0f64; 3]; 3]);
+        for p in points {
+            let (x, y) = ((p.x as f64 - mx) / scale, (p.y as f64 - my) / scale);
+            let quadratic = [x * x, x * y, y * y];
+            let linear = [x, y, 1.0];
+            for i in 0..3 {
+                for j in 0..3 {
+                    s1[i][j] += quadratic[i] * quadratic[j];
+                    s2[i][j] += quadratic[i] * linear[j];
+                    s3[i][j] += linear[i] * linear[j];
+                }
+            }
+        }
+        let s3_inverse = invert3(s3)?;
+        // The linear coefficients follow from the quadratic ones: linear = t * quadratic
+        let mut t = [[0.0f64; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                t[i][j] = -(0..3).map(|k| s3_inverse[i][k] * s2[j][k]).sum::<f64>();
+            }
+        }
+        let mut reduced = [[0.0f64; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                reduced[i][j] = s1[i][j] + (0..3).map(|k| s2[i][k] * t[k][j]).sum::<f64>();
+            }
+        }
+        // Multiply with the inverse of the constraint matrix of 4ac - b^2 = 1
+        let m = [
+            [
+                reduced[2][0] / 2.0,
+                reduced[2][1] / 2.0,
+                reduced[2][2] / 2.0,
+            ],
+            [-reduced[1][0], -reduced[1][1], -reduced[1][2]],
+            [
+                reduced[0][0] / 2.0,
+                reduced[0][1] / 2.0,
+                reduced[0][2] / 2.0,
+            ],
+        ];
+        let quadratic = real_eigenvectors3(m)
+            .into_iter()
+            .find(|v| 4.0 * v[0] * v[2] - v[1] * v[1] > 0.0)?;
+        let linear: Vec<f64> = (0..3)
+            .map(|i| (0..3).map(|k| t[i][k] * quadratic[k]).sum())
+            .collect();
+        let ellipse = conic_to_ellipse(
+            [
+                quadratic[0],
+                quadratic[1],
+                quadratic[2],
+                linear[0],
+                linear[1],
+                linear[2],
+            ],
+            (mx, my),
+            scale,
+        )?;
+        let residuals = Residuals::from_distances(points.iter().map(|p| ellipse.distance_to(*p)));
+        Some((ellipse, residuals))
+    }
+}
+
+/// Convert the conic a x^2 + b xy + c y^2 + d x + e y + f = 0, given in coordinates shifted
+/// by offset and divided by scale, into an ellipse in original coordinates
+fn conic_to_ellipse(conic: [f64; 6], offset: (f64, f64), scale: f64) -> Option<Ellipse> {
+    let [a, b, c, d, e, f] = conic;
+    let denominator = 4.0 * a * c - b * b;
+    if denominator <= 0.0 {
+        return None;
+    }
+    let x0 = (b * e - 2.0 * c * d) / denominator;
+    let y0 = (b * d - 2.0 * a * e) / denominator;
+    let f0 = a * x0 * x0 + b * x0 * y0 + c * y0 * y0 + d * x0 + e * y0 + f;
+    let theta = 0.5 * transcendental::atan2(b, a - c);
+    let (sin, cos) = transcendental::sin_cos(theta);
+    let along = a * cos * cos + b * cos * sin + c * sin * sin;
+    let across = a * sin * sin - b * cos * sin + c * cos * cos;
+    let (axis_along, axis_across) = ((-f0 / along).sqrt(), (-f0 / across).sqrt());
+    if !axis_along.is_finite() || !axis_across.is_finite() {
+        return None;
+    }
+    let (semi_major, semi_minor, mut rotation) = if axis_along >= axis_across {
+        (axis_along, axis_across, theta.to_degrees())
+    } else {
+        (axis_across, axis_along, theta.to_degrees() + 90.0)
+    };
+    if rotation > 90.0 {
+        rotation -= 180.0;
+    }
+    Some(Ellipse::new(
+        Point {
+            x: (offset.0 + x0 * scale) as f32,
+            y: (offset.1 + y0 * scale) as f32,
+        },
+        (semi_major * scale) as f32,
+        (semi_minor * scale) as f32,
+        rotation as f32,
+    ))
+}
+
+/// Return an eigenvector for every real eigenvalue of the 3x3 matrix m
+fn real_eigenvectors3(m: [[f64; 3]; 3]) -> Vec<[f64; 3]> {
+    let trace = m[0][0] + m[1][1] + m[2][2];
+    let minors = m[0][0] * m[1][1] - m[0][1] * m[1][0] + m[0][0] * m[2][2] - m[0][2] * m[2][0]
+        + m[1][1] * m[2][2]
+        - m[1][2] * m[2][1];
+    let det = determinant3(&m);
+    real_cubic_roots(-trace, minors, -det)
+        .into_iter()
+        .filter_map(|lambda| {
+            let mut shifted = m;
+            for (i, row) in shifted.iter_mut().enumerate() {
+                row[i] -= lambda;
+            }
+            // The eigenvector is orthogonal to all rows of m - lambda I
+            let candidates = [
+                cross3(shifted[0], shifted[1]),
+                cross3(shifted[0], shifted[2]),
+                cross3(shifted[1], shifted[2]),
+            ];
+            let norm = |v: &[f64; 3]| v.iter().map(|x| x * x).sum::<f64>();
+            let best = candidates
+                .iter()
+                .max_by(|a, b| {
+                    norm(a)
+                        .partial_cmp(&norm(b))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .copied()?;
+            if norm(&best) == 0.0 {
+                None
+            } else {
+                Some(best)
+            }
+        })
+        .collect()
+}
+
+/// Real roots of x^3 + a x^2 + b x + c = 0
+fn real_cubic_roots(a: f64, b: f64, c: f64) -> Vec<f64> {
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+    let shift = -a / 3.0;
+    let discriminant = q * q / 4.0 + p * p * p / 27.0;
+    if discriminant > 0.0 {
+        let root = discriminant.sqrt();
+        vec![transcendental::cbrt(-q / 2.0 + root) + transcendental::cbrt(-q / 2.0 - root) + shift]
+    } else if p == 0.0 {
+        vec![shift]
+    } else {
+        let r = (-p / 3.0).sqrt();
+        let phi = transcendental::acos((-q / (2.0 * r * r * r)).clamp(-1.0, 1.0));
+        (0..3)
+            .map(|k| {
+                2.0 * r * transcendental::cos((phi + 2.0 * std::f64::consts::PI * k as f64) / 3.0)
+                    + shift
+            })
+            .collect()
+    }
+}
+
+fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Kåsa fit on coordinates centered at the mean, for numerical stability
+fn kasa(points: &[Point]) -> Option<Circle> {
+    if points.len() < 3 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let (mx, my) = points.iter().fold((0.0f64, 0.0f64), |(x, y), p| {
+        (x + p.x as f64 / n, y + p.y as f64 / n)
+    });
+    // Least squares solution of x^2 + y^2 + d * x + e * y + f = 0
+    let mut m = [[0.0f64; 3]; 3];
+    let mut v = [0.0f64; 3];
+    for p in points {
+        let (x, y) = (p.x as f64 - mx, p.y as f64 - my);
+        let row = [x, y, 1.0];
+        let z = -(x * x + y * y);
+        for i in 0..3 {
+            v[i] += row[i] * z;
+            for j in 0..3 {
+                m[i][j] += row[i] * row[j];
+            }
+        }
+    }
+    let [d, e, f] = solve3(m, v)?;
+    let radius_squared = (d * d + e * e) / 4.0 - f;
+    if radius_squared <= 0.0 || !radius_squared.is_finite() {
+        return None;
+    }
+    Some(Circle::new(
+        Point {
+            x: (mx - d / 2.0) as f32,
+            y: (my - e / 2.0) as f32,
+        },
+        radius_squared.sqrt() as f32,
+    ))
+}
+
+fn circle_residuals(circle: &Circle, points: &[Point]) -> Residuals {
+    Residuals::from_distances(points.iter().map(|p| {
+        let (dx, dy) = (p.x - circle.center.x, p.y - circle.center.y);
+        (dx * dx + dy * dy).sqrt() - circle.radius
+    }))
+}
+
+fn determinant3(m: &[[f64; 3]; 3]) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Solve the 3x3 linear system m * x = v by Cramer's rule, None if m is singular
+fn solve3(m: [[f64; 3]; 3], v: [f64; 3]) -> Option<[f64; 3]> {
+    let determinant = determinant3(&m);
+    let scale = m.iter().flatten().fold(0.0f64, |a, b| a.max(b.abs()));
+    if determinant.abs() <= 1e-12 * scale.powi(3) {
+        return None;
+    }
+    let mut x = [0.0; 3];
+    for (column, value) in x.iter_mut().enumerate() {
+        let mut replaced = m;
+        for row in 0..3 {
+            replaced[row][column] = v[row];
+        }
+        *value = determinant3(&replaced) / determinant;
+    }
+    Some(x)
+}
+
+/// Invert the 3x3 matrix m column by column, None if it is singular
+fn invert3(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let mut inverse = [[0.0; 3]; 3];
+    for column in 0..3 {
+        let mut unit = [0.0; 3];
+        unit[column] = 1.0;
+        let x = solve3(m, unit)?;
+        for row in 0..3 {
+            inverse[row][column] = x[row];
+        }
+    }
+    Some(inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_fit_vertical_line() {
+        let points = [p(2.1, 0.0), p(1.9, 1.0), p(1.9, 2.0), p(2.1, 3.0)];
+        let (line, residuals) = Line::fit(&points).unwrap();
+        assert!(line.distance_to(p(2.0, 10.0)) < 1e-5);
+        assert!(line.distance_to(p(2.0, -10.0)) < 1e-5);
+        assert!((residuals.max - 0.1).abs() < 1e-5);
+        assert!((residuals.rms - 0.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_fit_exact_line() {
+        let points = [p(0.0, 1.0), p(1.0, 3.0), p(2.0, 5.0)];
+        let (line, residuals) = Line::fit(&points).unwrap();
+        assert!(residuals.max < 1e-5);
+        assert!(line.distance_to(p(-1.0, -1.0)) < 1e-5);
+        assert_eq!(None, Line::fit(&points[..1]));
+    }
+
+    #[test]
+    fn test_fit_circle() {
+        let points: Vec<Point> = (0..12)
+            .map(|i| (i as f32 * 30.0).to_radians())
+            .map(|a| p(3.0 + 2.0 * a.cos(), -1.0 + 2.0 * a.sin()))
+            .collect();
+        for (circle, residuals) in [
+            Circle::fit_algebraic(&points).unwrap(),
+            Circle::fit_geometric(&points).unwrap(),
+        ]
+        .iter()
+        {
+            assert!((circle.center.x - 3.0).abs() < 1e-4);
+            assert!((circle.center.y + 1.0).abs() < 1e-4);
+            assert!((circle.radius - 2.0).abs() < 1e-4);
+            assert!(residuals.rms < 1e-4);
+        }
+        let collinear = [p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)];
+        assert_eq!(None, Circle::fit_algebraic(&collinear));
+    }
+
+    #[test]
+    fn test_fit_ellipse() {
+        let truth = Ellipse::new(p(4.0, -2.0), 5.0, 2.0, 30.0);
+        let points: Vec<Point> = (0..16).map(|i| truth.point_at(i as f32 / 16.0)).collect();
+        let (ellipse, residuals) = Ellipse::fit(&points).unwrap();
+        assert!((ellipse.center.x - 4.0).abs() < 1e-3);
+        assert!((ellipse.center.y + 2.0).abs() < 1e-3);
+        assert!((ellipse.semi_major - 5.0).abs() < 1e-3);
+        assert!((ellipse.semi_minor - 2.0).abs() < 1e-3);
+        assert!((ellipse.rotation - 30.0).abs() < 1e-2);
+        assert!(residuals.max < 1e-3);
+        let collinear: Vec<Point> = (0..6).map(|i| p(i as f32, 2.0 * i as f32)).collect();
+        assert_eq!(None, Ellipse::fit(&collinear));
+    }
+
+    #[test]
+    fn test_geometric_fit_of_noisy_arc() {
+        // Short arc with alternating radial noise, where the algebraic fit is biased
+        let points: Vec<Point> = (0..9)
+            .map(|i| {
+                let a = (i as f32 * 5.0).to_radians();
+                let r = 10.0 + if i % 2 == 0 { 0.05 } else { -0.05 };
+                p(r * a.cos(), r * a.sin())
+            })
+            .collect();
+        let (algebraic, a_res) = Circle::fit_algebraic(&points).unwrap();
+        let (geometric, g_res) = Circle::fit_geometric(&points).unwrap();
+        assert!(g_res.rms <= a_res.rms);
+        assert!(geometric.radius > 0.0 && algebraic.radius > 0.0);
+    }
+
+    #[test]
+    fn test_fit_plane_to_floor_and_wall() {
+        let floor: Vec<Point3> = (0..16)
+            .map(|i| {
+                // Checkerboard noise, which does not tilt the fitted plane
+                let noise = [0.01, -0.01][(i % 4 + i / 4) % 2];
+                Point3::new((i % 4) as f32, (i / 4) as f32, 1.5 + noise)
+            })
+            .collect();
+        let (plane, residuals) = Plane::fit(&floor).unwrap();
+        assert!(plane.normal.z.abs() > 0.999);
+        assert!(plane.distance_to(Point3::new(10.0, -10.0, 1.5)) < 1e-3);
+        assert!((residuals.rms - 0.01).abs() < 1e-4);
+
+        let wall = [
+            Point3::new(0.0, 2.0, 0.0),
+            Point3::new(3.0, -1.0, 0.0),
+            Point3::new(0.0, 2.0, 2.5),
+            Point3::new(3.0, -1.0, 2.5),
+            Point3::new(1.0, 1.0, 1.0),
+        ];
+        let (plane, residuals) = Plane::fit(&wall).unwrap();
+        assert!(plane.normal.z.abs() < 1e-4);
+        assert!(plane.distance_to(Point3::new(-3.0, 5.0, 7.0)) < 1e-3);
+        assert!(residuals.max < 1e-4);
+    }
+
+    #[test]
+    fn test_fit_plane_degenerate() {
+        let line = [
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(2.0, 2.0, 2.0),
+        ];
+        assert_eq!(None, Plane::fit(&line));
+        assert_eq!(None, Plane::fit(&line[..2]));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{Polyline, Vector};
+
+impl Polyline {
+    /// Return the discrete Fréchet distance to other, the shortest leash that lets two walkers
+    /// step through the points of both polylines in order, computed by dynamic programming
+    /// in O(n m) time. None if either polyline has no points.
+    pub fn frechet_distance(&self, other: &Polyline) -> Option<f32> {
+        let (a, b) = (&self.points, &other.points);
+        if a.is_empty() || b.is_empty() {
+            return None;
+        }
+        // Only the previous row of the coupling table is needed
+        let mut previous: Vec<f32> = Vec::with_capacity(b.len());
+        for (i, p) in a.iter().enumerate() {
+            let mut row: Vec<f32> = Vec::with_capacity(b.len());
+            for (j, q) in b.iter().enumerate() {
+                let distance = Vector::new(*p, *q).length();
+                let reach = match (i, j) {
+                    (0, 0) => distance,
+                    (0, _) => row[j - 1],
+                    (_, 0) => previous[0],
+                    _ => previous[j].min(previous[j - 1]).min(row[j - 1]),
+                };
+                row.push(reach.max(distance));
+            }
+            previous = row;
+        }
+        previous.last().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_parallel_tracks() {
+        let a = Polyline::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(2.0, 0.0), p(3.0, 0.0)]);
+        let b = Polyline::new(vec![p(0.0, 1.0), p(1.5, 1.0), p(3.0, 1.0)]);
+        let distance = a.frechet_distance(&b).unwrap();
+        assert!((distance - 1.25f32.sqrt()).abs() < 1e-6);
+        assert_eq!(Some(distance), b.frechet_distance(&a));
+        assert_eq!(None, a.frechet_distance(&Polyline::default()));
+    }
+
+    #[test]
+    fn test_direction_matters() {
+        let a = Polyline::new(vec![p(0.0, 0.0), p(4.0, 0.0)]);
+        let reversed = Polyline::new(vec![p(4.0, 0.0), p(0.0, 0.0)]);
+        assert_eq!(Some(0.0), a.frechet_distance(&a.clone()));
+        assert_eq!(Some(4.0), a.frechet_distance(&reversed));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Point, Polyline, Vector};
+
+/// Return the directed Hausdorff distance from a to b, the largest distance from a point of
+/// a to its nearest point of b. None if either set is empty.
+pub fn directed_hausdorff(a: &[Point], b: &[Point]) -> Option<f32> {
+    if b.is_empty() {
+        return None;
+    }
+    a.iter()
+        .map(|p| {
+            b.iter()
+                .map(|q| Vector::new(*p, *q).length())
+                .fold(f32::INFINITY, f32::min)
+        })
+        .fold(None, |max: Option<f32>, d| Some(max.unwrap_or(d).max(d)))
+}
+
+/// Return the symmetric Hausdorff distance, the larger of both directed distances
+pub fn hausdorff(a: &[Point], b: &[Point]) -> Option<f32> {
+    Some(directed_hausdorff(a, b)?.max(directed_hausdorff(b, a)?))
+}
+
+impl Polyline {
+    /// Return the directed Hausdorff distance from this polyline to other. This polyline is
+    /// sampled every spacing units and each sample is measured against the exact segments
+    /// of other. None if either polyline has no points.
+    pub fn directed_hausdorff(&self, other: &Polyline, spacing: f32) -> Option<f32> {
+        self.resample(spacing)
+            .points
+            .iter()
+            .map(|p| other.distance_to(*p))
+            .fold(None, |max: Option<f32>, d| match (max, d) {
+                (_, None) => None,
+                (None, d) => d,
+                (Some(max), Some(d)) => Some(max.max(d)),
+            })
+    }
+
+    /// Return the symmetric Hausdorff distance to other, see directed_hausdorff
+    pub fn hausdorff(&self, other: &Polyline, spacing: f32) -> Option<f32> {
+        let forward = self.directed_hausdorff(other, spacing)?;
+        Some(forward.max(other.directed_hausdorff(self, spacing)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_point_sets() {
+        let a = [p(0.0, 0.0), p(1.0, 0.0)];
+        let b = [p(0.0, 0.0), p(1.0, 0.0), p(1.0, 3.0)];
+        assert_eq!(Some(0.0), directed_hausdorff(&a, &b));
+        assert_eq!(Some(3.0), directed_hausdorff(&b, &a));
+        assert_eq!(Some(3.0), hausdorff(&a, &b));
+        assert_eq!(None, hausdorff(&a, &[]));
+    }
+
+    #[test]
+    fn test_simplified_polyline() {
+        let original = Polyline::new(vec![p(0.0, 0.0), p(5.0, 1.0), p(10.0, 0.0)]);
+        let simplified = Polyline::new(vec![p(0.0, 0.0), p(10.0, 0.0)]);
+        let distance = original.hausdorff(&simplified, 0.1).unwrap();
+        assert!((distance - 1.0).abs() < 0.01);
+        assert!(simplified.directed_hausdorff(&original, 0.1).unwrap() < 1.0);
+        assert_eq!(None, original.hausdorff(&Polyline::default(), 0.1));
+    }
+}
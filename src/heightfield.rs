@@ -0,0 +1,142 @@
+use crate::{Point3, Triangle3};
+
+/// Return the surface of the height field as triangles, two per grid cell. The grid is
+/// stored row by row like for contours: height (i, j) at grid[j * width + i] lies at x = i *
+/// spacing, y = j * spacing. Triangles run counter clockwise seen from above, so their
+/// normals point up. Panics if the grid is smaller than width * height.
+pub fn heightfield_mesh(grid: &[f32], width: usize, height: usize, spacing: f32) -> Vec<Triangle3> {
+    let field = Field::new(grid, width, height, spacing);
+    let mut triangles = Vec::new();
+    for j in 0..height.saturating_sub(1) {
+        for i in 0..width.saturating_sub(1) {
+            field.quad(i, j, i + 1, j + 1, &mut triangles);
+        }
+    }
+    triangles
+}
+
+/// Return the surface of the height field like heightfield_mesh, but with flat areas merged
+/// greedily into large rectangles. Starting at each uncovered cell a rectangle grows right
+/// and then up while every grid height inside lies within tolerance of the plane through
+/// its corners. Neighbors of a merged rectangle may meet it with T junctions, leaving gaps
+/// no larger than tolerance.
+pub fn heightfield_mesh_simplified(
+    grid: &[f32],
+    width: usize,
+    height: usize,
+    spacing: f32,
+    tolerance: f32,
+) -> Vec<Triangle3> {
+    let field = Field::new(grid, width, height, spacing);
+    let (columns, rows) = (width.saturating_sub(1), height.saturating_sub(1));
+    let mut covered = vec![false; columns * rows];
+    let mut triangles = Vec::new();
+    for j in 0..rows {
+        for i in 0..columns {
+            if covered[j * columns + i] {
+                continue;
+            }
+            let mut right = i + 1;
+            while right < columns
+                && !covered[j * columns + right]
+                && field.is_flat(i, j, right + 1, j + 1, tolerance)
+            {
+                right += 1;
+            }
+            let mut top = j + 1;
+            while top < rows
+                && (i..right).all(|k| !covered[top * columns + k])
+                && field.is_flat(i, j, right, top + 1, tolerance)
+            {
+                top += 1;
+            }
+            for row in j..top {
+                for column in i..right {
+                    covered[row * columns + column] = true;
+                }
+            }
+            field.quad(i, j, right, top, &mut triangles);
+        }
+    }
+    triangles
+}
+
+struct Field<'a> {
+    grid: &'a [f32],
+    width: usize,
+    spacing: f32,
+}
+
+impl<'a> Field<'a> {
+    fn new(grid: &'a [f32], width: usize, height: usize, spacing: f32) -> Field<'a> {
+        assert!(
+            grid.len() >= width * height,
+            "grid smaller than width * height"
+        );
+        Field {
+            grid,
+            width,
+            spacing,
+        }
+    }
+
+    fn point(&self, i: usize, j: usize) -> Point3 {
+        Point3::new(
+            i as f32 * self.spacing,
+            j as f32 * self.spacing,
+            self.grid[j * self.width + i],
+        )
+    }
+
+    /// Push the two triangles of the rectangle between grid points (i0, j0) and (i1, j1)
+    fn quad(&self, i0: usize, j0: usize, i1: usize, j1: usize, triangles: &mut Vec<Triangle3>) {
+        let (a, b) = (self.point(i0, j0), self.point(i1, j0));
+        let (c, d) = (self.point(i1, j1), self.point(i0, j1));
+        triangles.push(Triangle3::new(a, b, c));
+        triangles.push(Triangle3::new(a, c, d));
+    }
+
+    /// Return true if every grid height of the rectangle lies within tolerance of the plane
+    /// through its corners (i0, j0), (i1, j0) and (i0, j1)
+    fn is_flat(&self, i0: usize, j0: usize, i1: usize, j1: usize, tolerance: f32) -> bool {
+        let base = self.grid[j0 * self.width + i0];
+        let slope_i = (self.grid[j0 * self.width + i1] - base) / (i1 - i0) as f32;
+        let slope_j = (self.grid[j1 * self.width + i0] - base) / (j1 - j0) as f32;
+        (j0..=j1).all(|j| {
+            (i0..=i1).all(|i| {
+                let expected = base + slope_i * (i - i0) as f32 + slope_j * (j - j0) as f32;
+                (self.grid[j * self.width + i] - expected).abs() <= tolerance
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_mesh() {
+        let grid = [0.0, 1.0, 2.0, 0.0, 1.0, 2.0];
+        let triangles = heightfield_mesh(&grid, 3, 2, 0.5);
+        assert_eq!(4, triangles.len());
+        assert_eq!(Point3::new(1.0, 0.5, 2.0), triangles[2].c);
+        assert!(triangles.iter().all(|t| t.normal().unwrap().z > 0.0));
+        assert!(heightfield_mesh(&grid, 1, 2, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_simplified_mesh() {
+        // A 5 by 5 ramp rising along x, with a bump in one corner
+        let mut grid: Vec<f32> = (0..25).map(|k| (k % 5) as f32 * 0.5).collect();
+        grid[24] += 3.0;
+        let full = heightfield_mesh(&grid, 5, 5, 1.0);
+        let simplified = heightfield_mesh_simplified(&grid, 5, 5, 1.0, 1e-4);
+        assert_eq!(32, full.len());
+        assert!(simplified.len() < full.len());
+        let area = |triangles: &[Triangle3]| triangles.iter().map(|t| t.area()).sum::<f32>();
+        assert!((area(&full) - area(&simplified)).abs() < 1e-3 * area(&full));
+        let flat = heightfield_mesh_simplified(&[1.0; 16], 4, 4, 1.0, 0.0);
+        assert_eq!(2, flat.len());
+    }
+}
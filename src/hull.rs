@@ -0,0 +1,205 @@
+use crate::{orientation, Point, Polygon};
+
+/// Return the convex hull of the points counter clockwise, starting at the lowest leftmost
+/// point, without collinear vertices (Andrew's monotone chain). Fewer than three points are
+/// returned when all points are collinear.
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+    let mut hull: Vec<Point> = Vec::with_capacity(sorted.len() + 1);
+    for pass in 0..2 {
+        let start = hull.len();
+        let chain: Box<dyn Iterator<Item = &Point>> = if pass == 0 {
+            Box::new(sorted.iter())
+        } else {
+            Box::new(sorted.iter().rev())
+        };
+        for p in chain {
+            while hull.len() >= start + 2
+                && orientation(hull[hull.len() - 2], hull[hull.len() - 1], *p) <= 0.0
+            {
+                hull.pop();
+            }
+            hull.push(*p);
+        }
+        hull.pop();
+    }
+    hull
+}
+
+/// Convex hull maintained point by point. Points inside the hull are only stored, points
+/// outside replace the vertices they can see, so inserting costs time proportional to the
+/// hull size instead of rebuilding from all points.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IncrementalHull {
+    points: Vec<Point>,
+    hull: Vec<Point>,
+}
+
+impl IncrementalHull {
+    pub fn new() -> IncrementalHull {
+        IncrementalHull::default()
+    }
+
+    /// Return the hull vertices counter clockwise
+    pub fn vertices(&self) -> &[Point] {
+        &self.hull
+    }
+
+    /// Return all points inserted and not removed
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    /// Return the hull as a polygon, None while all points are collinear
+    pub fn to_polygon(&self) -> Option<Polygon> {
+        if self.hull.len() < 3 {
+            return None;
+        }
+        Some(Polygon::new(self.hull.clone()))
+    }
+
+    /// Return true if the point lies inside or on the hull
+    pub fn contains(&self, point: Point) -> bool {
+        self.hull.len() >= 3 && self.visible_edges(point).iter().all(|v| !v)
+    }
+
+    /// Add a point and return true if the hull changed
+    pub fn insert(&mut self, point: Point) -> bool {
+        self.points.push(point);
+        if self.hull.len() < 3 {
+            let hull = convex_hull(&self.points);
+            let changed = hull != self.hull;
+            self.hull = hull;
+            return changed;
+        }
+        let visible = self.visible_edges(point);
+        let n = self.hull.len();
+        // Visible edges form one run, from the edge after a hidden one to the edge before
+        // the next hidden one
+        let first = match (0..n).find(|&i| visible[i] && !visible[(i + n - 1) % n]) {
+            Some(first) => first,
+            None => return false,
+        };
+        let last = (0..n)
+            .map(|k| (first + k) % n)
+            .take_while(|&i| visible[i])
+            .last()
+            .unwrap();
+        // Keep the vertices from the end of the last visible edge round to the start of the
+        // first, then close with the new point
+        let mut hull = Vec::with_capacity(n + 1);
+        let mut i = (last + 1) % n;
+        loop {
+            hull.push(self.hull[i]);
+            if i == first {
+                break;
+            }
+            i = (i + 1) % n;
+        }
+        hull.push(point);
+        // A kept neighbour in line with the new point and the vertex beyond it is no longer
+        // a corner, and convex_hull would not list it
+        while hull.len() > 3
+            && orientation(hull[hull.len() - 3], hull[hull.len() - 2], point) <= 0.0
+        {
+            hull.remove(hull.len() - 2);
+        }
+        while hull.len() > 3 && orientation(point, hull[0], hull[1]) <= 0.0 {
+            hull.remove(0);
+        }
+        self.hull = hull;
+        true
+    }
+
+    /// Remove one copy of the point and return false if it was not inserted. Removing a hull
+    /// vertex rebuilds the hull from the remaining points.
+    pub fn remove(&mut self, point: Point) -> bool {
+        let index = match self.points.iter().position(|p| *p == point) {
+            Some(index) => index,
+            None => return false,
+        };
+        self.points.swap_remove(index);
+        if self.hull.contains(&point) {
+            self.hull = convex_hull(&self.points);
+        }
+        true
+    }
+
+    /// Edge i runs from vertex i to i + 1 and is visible if the point lies strictly right of it
+    fn visible_edges(&self, point: Point) -> Vec<bool> {
+        let n = self.hull.len();
+        (0..n)
+            .map(|i| orientation(self.hull[i], self.hull[(i + 1) % n], point) < 0.0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_convex_hull() {
+        let points = [
+            p(1.0, 1.0),
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(1.0, 0.0),
+            p(2.0, 2.0),
+            p(0.0, 2.0),
+        ];
+        assert_eq!(
+            vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)],
+            convex_hull(&points)
+        );
+        assert_eq!(
+            2,
+            convex_hull(&[p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0)]).len()
+        );
+    }
+
+    #[test]
+    fn test_incremental_hull() {
+        let mut hull = IncrementalHull::new();
+        assert!(hull.insert(p(0.0, 0.0)));
+        assert!(hull.insert(p(4.0, 0.0)));
+        assert!(!hull.insert(p(2.0, 0.0)));
+        assert!(hull.to_polygon().is_none());
+        assert!(hull.insert(p(0.0, 4.0)));
+        assert!(!hull.insert(p(1.0, 1.0)));
+        assert!(hull.insert(p(4.0, 4.0)));
+        assert_eq!(4, hull.vertices().len());
+        assert_eq!(Some(16.0), hull.to_polygon().map(|h| h.signed_area()));
+        assert!(hull.insert(p(6.0, 2.0)));
+        assert!(hull.contains(p(5.0, 2.0)));
+        assert_eq!(convex_hull(hull.points()).len(), hull.vertices().len());
+        assert!(hull.remove(p(6.0, 2.0)));
+        assert!(!hull.contains(p(5.0, 2.0)));
+        assert!(!hull.remove(p(6.0, 2.0)));
+    }
+
+    #[test]
+    fn test_incremental_hull_drops_collinear_vertices() {
+        let mut hull = IncrementalHull::new();
+        for point in [p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)].iter() {
+            hull.insert(*point);
+        }
+        assert!(!hull.insert(p(1.0, 0.0)));
+        assert_eq!(4, hull.vertices().len());
+        assert!(hull.insert(p(4.0, 0.0)));
+        assert!(hull.insert(p(0.0, 4.0)));
+        let mut vertices = hull.vertices().to_vec();
+        let mut expected = convex_hull(hull.points());
+        let by_position = |a: &Point, b: &Point| (a.x, a.y).partial_cmp(&(b.x, b.y)).unwrap();
+        vertices.sort_by(by_position);
+        expected.sort_by(by_position);
+        assert_eq!(expected, vertices);
+        assert_eq!(vec![p(0.0, 0.0), p(0.0, 4.0), p(4.0, 0.0)], vertices);
+    }
+}
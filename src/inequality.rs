@@ -0,0 +1,120 @@
+//! Triangle inequality checks on plain side lengths. The sides follow the naming of
+//! Triangle: a is the side opposite point_a (BC), b opposite point_b (CA) and c opposite
+//! point_c (AB).
+
+use std::fmt;
+
+/// Side of a triangle, named after the opposite point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+    C,
+}
+
+/// First violated condition found by check_triangle_inequality.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum InequalityViolation {
+    /// The side is zero, negative or not a finite number
+    NotPositive { side: Side, length: f32 },
+    /// The side is not shorter than the other two together, by at least the margin
+    TooLong {
+        side: Side,
+        length: f32,
+        others: f32,
+    },
+}
+
+impl fmt::Display for InequalityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InequalityViolation::NotPositive { side, length } => {
+                write!(f, "side {:?} has no positive length: {}", side, length)
+            }
+            InequalityViolation::TooLong {
+                side,
+                length,
+                others,
+            } => write!(
+                f,
+                "side {:?} of length {} is not shorter than the other sides together ({})",
+                side, length, others
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InequalityViolation {}
+
+/// Check that the sides a, b and c form a triangle with every side shorter than the other
+/// two together by more than epsilon, and return the first violation otherwise. An epsilon
+/// of 0 only rules out degenerate triangles with all points on a line.
+pub fn check_triangle_inequality(
+    a: f32,
+    b: f32,
+    c: f32,
+    epsilon: f32,
+) -> Result<(), InequalityViolation> {
+    let sides = [(Side::A, a), (Side::B, b), (Side::C, c)];
+    for (side, length) in sides.iter() {
+        if !(length.is_finite() && *length > 0.0) {
+            return Err(InequalityViolation::NotPositive {
+                side: *side,
+                length: *length,
+            });
+        }
+    }
+    for i in 0..3 {
+        let (side, length) = sides[i];
+        let others = sides[(i + 1) % 3].1 + sides[(i + 2) % 3].1;
+        if length >= others - epsilon {
+            return Err(InequalityViolation::TooLong {
+                side,
+                length,
+                others,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Return true if the sides form a non degenerate triangle
+pub fn can_form_triangle(a: f32, b: f32, c: f32) -> bool {
+    check_triangle_inequality(a, b, c, 0.0).is_ok()
+}
+
+/// Return true if the sides form a triangle with every side shorter than the other two
+/// together by more than epsilon, rejecting nearly flat triangles
+pub fn can_form_triangle_strict(a: f32, b: f32, c: f32, epsilon: f32) -> bool {
+    check_triangle_inequality(a, b, c, epsilon).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_form_triangle() {
+        assert!(can_form_triangle(3.0, 4.0, 5.0));
+        assert!(!can_form_triangle(1.0, 2.0, 3.0));
+        assert!(!can_form_triangle(0.0, 1.0, 1.0));
+        assert!(can_form_triangle(1.0, 1.0, 1.999));
+        assert!(!can_form_triangle_strict(1.0, 1.0, 1.999, 0.01));
+    }
+
+    #[test]
+    fn test_diagnostic_names_the_long_side() {
+        assert_eq!(
+            Err(InequalityViolation::TooLong {
+                side: Side::B,
+                length: 7.0,
+                others: 5.0
+            }),
+            check_triangle_inequality(2.0, 7.0, 3.0, 0.0)
+        );
+        match check_triangle_inequality(2.0, 2.0, f32::NAN, 0.0) {
+            Err(InequalityViolation::NotPositive { side, .. }) => assert_eq!(Side::C, side),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
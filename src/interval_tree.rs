@@ -0,0 +1,155 @@
+use crate::{Angle, Segment};
+
+/// Static interval tree answering which of its closed intervals contain a value or overlap
+/// a range. The intervals are kept sorted by their start in an implicit balanced tree where
+/// every node stores the largest end below it, so a query only visits the branches that can
+/// still overlap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalTree<T> {
+    entries: Vec<(f32, f32, T)>,
+    max_end: Vec<f32>,
+}
+
+impl<T> IntervalTree<T> {
+    /// Build the tree from (start, end, item) triples. Reversed intervals are flipped.
+    pub fn new(intervals: Vec<(f32, f32, T)>) -> IntervalTree<T> {
+        let mut entries: Vec<(f32, f32, T)> = intervals
+            .into_iter()
+            .map(|(a, b, item)| (a.min(b), a.max(b), item))
+            .collect();
+        entries.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut tree = IntervalTree {
+            max_end: vec![f32::MIN; entries.len()],
+            entries,
+        };
+        tree.augment(0, tree.entries.len());
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over (start, end, item) ordered by start
+    pub fn iter(&self) -> impl Iterator<Item = (f32, f32, &T)> + '_ {
+        self.entries.iter().map(|(a, b, item)| (*a, *b, item))
+    }
+
+    /// Return the items whose interval contains the value, ordered by start
+    pub fn stab(&self, value: f32) -> Vec<&T> {
+        self.overlapping(value, value)
+    }
+
+    /// Return the items whose interval shares at least one value with [min, max], ordered
+    /// by start
+    pub fn overlapping(&self, min: f32, max: f32) -> Vec<&T> {
+        let mut found = Vec::new();
+        self.search(
+            0,
+            self.entries.len(),
+            min.min(max),
+            min.max(max),
+            &mut found,
+        );
+        found
+    }
+
+    /// Store the largest end of the entries in [low, high) at their middle, return it
+    fn augment(&mut self, low: usize, high: usize) -> f32 {
+        if low >= high {
+            return f32::MIN;
+        }
+        let middle = (low + high) / 2;
+        let left = self.augment(low, middle);
+        let right = self.augment(middle + 1, high);
+        self.max_end[middle] = self.entries[middle].1.max(left).max(right);
+        self.max_end[middle]
+    }
+
+    fn search<'a>(&'a self, low: usize, high: usize, min: f32, max: f32, found: &mut Vec<&'a T>) {
+        if low >= high {
+            return;
+        }
+        let middle = (low + high) / 2;
+        if self.max_end[middle] < min {
+            return;
+        }
+        self.search(low, middle, min, max, found);
+        let (start, end, item) = &self.entries[middle];
+        // Everything right of here starts later still
+        if *start > max {
+            return;
+        }
+        if *end >= min {
+            found.push(item);
+        }
+        self.search(middle + 1, high, min, max, found);
+    }
+}
+
+impl IntervalTree<usize> {
+    /// Return the tree of the projections of the segments onto the axis through the origin
+    /// in the direction, each labeled with the index of its segment. With a direction of 0
+    /// degrees it answers which segments overlap the vertical line x = c.
+    pub fn of_segments(segments: &[Segment], direction: Angle) -> IntervalTree<usize> {
+        let (sin, cos) = (direction.sin(), direction.cos());
+        IntervalTree::new(
+            segments
+                .iter()
+                .enumerate()
+                .map(|(i, s)| {
+                    let a = s.point_a.x * cos + s.point_a.y * sin;
+                    let b = s.point_b.x * cos + s.point_b.y * sin;
+                    (a, b, i)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_stab_and_overlap() {
+        let tree = IntervalTree::new(vec![
+            (0.0, 2.0, 'a'),
+            (5.0, 1.0, 'b'),
+            (3.0, 4.0, 'c'),
+            (6.0, 9.0, 'd'),
+            (-3.0, -1.0, 'e'),
+        ]);
+        assert_eq!(5, tree.len());
+        assert_eq!(vec![&'a', &'b'], tree.stab(1.5));
+        assert_eq!(vec![&'b', &'c'], tree.stab(4.0));
+        assert!(tree.stab(10.0).is_empty());
+        assert_eq!(vec![&'e', &'a'], tree.overlapping(-2.0, 0.5));
+        assert_eq!(vec![&'b', &'c', &'d'], tree.overlapping(3.5, 6.0));
+        assert_eq!(
+            vec![-3.0, 0.0, 1.0, 3.0, 6.0],
+            tree.iter().map(|i| i.0).collect::<Vec<f32>>()
+        );
+    }
+
+    #[test]
+    fn test_segments_crossing_vertical_line() {
+        let segments = [
+            Segment::new(p(0.0, 0.0), p(4.0, 1.0)),
+            Segment::new(p(5.0, 2.0), p(2.0, 3.0)),
+            Segment::new(p(6.0, 0.0), p(6.0, 5.0)),
+        ];
+        let tree = IntervalTree::of_segments(&segments, Angle::from_degrees(0.0));
+        assert_eq!(vec![&0, &1], tree.stab(3.0));
+        assert_eq!(vec![&2], tree.stab(6.0));
+        let by_y = IntervalTree::of_segments(&segments, Angle::from_degrees(90.0));
+        let mut hits = by_y.stab(0.5);
+        hits.sort();
+        assert_eq!(vec![&0, &2], hits);
+    }
+}
@@ -0,0 +1,156 @@
+//! K-means clustering of points, enabled by the rand feature for the k-means++ seeding.
+
+use crate::{centroid, Point};
+use rand::Rng;
+
+/// Result of k-means clustering.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clustering {
+    pub centroids: Vec<Point>,
+    /// Index into centroids for every input point
+    pub assignments: Vec<usize>,
+    /// Sum of squared distances of the points from their centroids
+    pub inertia: f32,
+    /// Number of refinement steps run, at most the cap passed to kmeans
+    pub iterations: usize,
+}
+
+impl Clustering {
+    /// Return the points assigned to the cluster
+    pub fn members(&self, points: &[Point], cluster: usize) -> Vec<Point> {
+        points
+            .iter()
+            .zip(&self.assignments)
+            .filter(|(_, c)| **c == cluster)
+            .map(|(p, _)| *p)
+            .collect()
+    }
+}
+
+/// Return the points grouped into k clusters by Lloyd's algorithm. The centroids are seeded
+/// with k-means++, which picks every further seed with probability proportional to its
+/// squared distance from the seeds so far. Refinement stops when no point changes its
+/// cluster or after max_iterations steps. k is capped at the number of points, None if
+/// there are no points or k is 0.
+pub fn kmeans<R: Rng + ?Sized>(
+    rng: &mut R,
+    points: &[Point],
+    k: usize,
+    max_iterations: usize,
+) -> Option<Clustering> {
+    let k = k.min(points.len());
+    if k == 0 {
+        return None;
+    }
+    let mut centroids = seed(rng, points, k);
+    let mut assignments: Vec<usize> = points.iter().map(|p| nearest(&centroids, *p).0).collect();
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        iterations += 1;
+        for (cluster, center) in centroids.iter_mut().enumerate() {
+            let members: Vec<Point> = points
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, c)| **c == cluster)
+                .map(|(p, _)| *p)
+                .collect();
+            // An empty cluster keeps its centroid
+            if let Some(mean) = centroid(&members) {
+                *center = mean;
+            }
+        }
+        let next: Vec<usize> = points.iter().map(|p| nearest(&centroids, *p).0).collect();
+        if next == assignments {
+            break;
+        }
+        assignments = next;
+    }
+    let inertia = points.iter().map(|p| nearest(&centroids, *p).1).sum();
+    Some(Clustering {
+        centroids,
+        assignments,
+        inertia,
+        iterations,
+    })
+}
+
+/// Return k seeds chosen by k-means++
+fn seed<R: Rng + ?Sized>(rng: &mut R, points: &[Point], k: usize) -> Vec<Point> {
+    let pick = |rng: &mut R, n: usize| ((rng.gen::<f32>() * n as f32) as usize).min(n - 1);
+    let mut seeds = vec![points[pick(rng, points.len())]];
+    let mut weights: Vec<f32> = points.iter().map(|p| squared(*p, seeds[0])).collect();
+    while seeds.len() < k {
+        let total: f32 = weights.iter().sum();
+        let index = if total > 0.0 {
+            let mut target = rng.gen::<f32>() * total;
+            weights
+                .iter()
+                .position(|w| {
+                    target -= w;
+                    target < 0.0 && *w > 0.0
+                })
+                .unwrap_or_else(|| weights.iter().rposition(|w| *w > 0.0).unwrap_or(0))
+        } else {
+            // Every point coincides with a seed
+            pick(rng, points.len())
+        };
+        let next = points[index];
+        seeds.push(next);
+        for (weight, p) in weights.iter_mut().zip(points) {
+            *weight = weight.min(squared(*p, next));
+        }
+    }
+    seeds
+}
+
+/// Return the index of the nearest centroid and the squared distance to it
+fn nearest(centroids: &[Point], point: Point) -> (usize, f32) {
+    centroids
+        .iter()
+        .map(|c| squared(*c, point))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, 0.0))
+}
+
+fn squared(a: Point, b: Point) -> f32 {
+    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_separated_blobs() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut points = Vec::new();
+        for (cx, cy) in &[(0.0, 0.0), (10.0, 0.0), (5.0, 8.0)] {
+            for (dx, dy) in &[(-0.5, 0.0), (0.5, 0.0), (0.0, -0.5), (0.0, 0.5)] {
+                points.push(p(cx + dx, cy + dy));
+            }
+        }
+        let clustering = kmeans(&mut rng, &points, 3, 100).unwrap();
+        assert_eq!(3, clustering.centroids.len());
+        assert!((clustering.inertia - 3.0).abs() < 1e-4);
+        for blob in points.chunks(4).zip(clustering.assignments.chunks(4)) {
+            assert!(blob.1.iter().all(|c| *c == blob.1[0]));
+        }
+        let members = clustering.members(&points, clustering.assignments[4]);
+        assert_eq!(points[4..8].to_vec(), members);
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        let mut rng = StdRng::seed_from_u64(5);
+        assert_eq!(None, kmeans(&mut rng, &[], 3, 10));
+        assert_eq!(None, kmeans(&mut rng, &[p(1.0, 1.0)], 0, 10));
+        let same = [p(1.0, 1.0); 4];
+        let clustering = kmeans(&mut rng, &same, 6, 10).unwrap();
+        assert_eq!(4, clustering.centroids.len());
+        assert_eq!(0.0, clustering.inertia);
+    }
+}
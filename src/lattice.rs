@@ -0,0 +1,115 @@
+use crate::{Point, Polygon};
+
+/// Polygon with integer corners, for exact lattice point counts. The ring is simple and
+/// closes back to the first corner; it may run either way.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LatticePolygon {
+    pub vertices: Vec<(i64, i64)>,
+}
+
+impl LatticePolygon {
+    pub fn new(vertices: Vec<(i64, i64)>) -> LatticePolygon {
+        LatticePolygon { vertices }
+    }
+
+    /// Return twice the area, which is always an integer
+    pub fn double_area(&self) -> u64 {
+        let n = self.vertices.len();
+        let sum: i64 = (0..n)
+            .map(|i| {
+                let ((x1, y1), (x2, y2)) = (self.vertices[i], self.vertices[(i + 1) % n]);
+                x1 * y2 - x2 * y1
+            })
+            .sum();
+        sum.unsigned_abs()
+    }
+
+    /// Return the number of lattice points on the outline, corners included
+    pub fn boundary_points(&self) -> u64 {
+        let n = self.vertices.len();
+        (0..n)
+            .map(|i| {
+                let ((x1, y1), (x2, y2)) = (self.vertices[i], self.vertices[(i + 1) % n]);
+                gcd((x2 - x1).unsigned_abs(), (y2 - y1).unsigned_abs())
+            })
+            .sum()
+    }
+
+    /// Return the number of lattice points strictly inside, by Pick's theorem solved for
+    /// the interior points. 0 for fewer than three corners.
+    pub fn interior_points(&self) -> u64 {
+        if self.vertices.len() < 3 {
+            return 0;
+        }
+        (self.double_area() + 2 - self.boundary_points()) / 2
+    }
+
+    /// Return the area from the lattice point counts by Pick's theorem, which equals
+    /// double_area / 2
+    pub fn area(&self) -> f64 {
+        pick_area(self.interior_points(), self.boundary_points())
+    }
+}
+
+/// Return the area of a simple lattice polygon with the given number of interior and
+/// boundary lattice points, I + B / 2 - 1
+pub fn pick_area(interior: u64, boundary: u64) -> f64 {
+    interior as f64 + boundary as f64 / 2.0 - 1.0
+}
+
+impl Polygon {
+    /// Return the polygon as a lattice polygon, None if a coordinate is not a whole number
+    pub fn to_lattice(&self) -> Option<LatticePolygon> {
+        let whole = |v: f32| {
+            if v.fract() == 0.0 {
+                Some(v as i64)
+            } else {
+                None
+            }
+        };
+        self.points
+            .iter()
+            .map(|p: &Point| Some((whole(p.x)?, whole(p.y)?)))
+            .collect::<Option<Vec<(i64, i64)>>>()
+            .map(LatticePolygon::new)
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_lattice_counts() {
+        let triangle = LatticePolygon::new(vec![(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(16, triangle.double_area());
+        assert_eq!(12, triangle.boundary_points());
+        assert_eq!(3, triangle.interior_points());
+        assert_eq!(8.0, triangle.area());
+        let square = LatticePolygon::new(vec![(0, 0), (0, 3), (3, 3), (3, 0)]);
+        assert_eq!(4, square.interior_points());
+        assert_eq!(9.0, pick_area(4, 12));
+    }
+
+    #[test]
+    fn test_from_polygon() {
+        let polygon = Polygon::new(vec![p(0.0, 0.0), p(5.0, 1.0), p(2.0, 4.0)]);
+        let lattice = polygon.to_lattice().unwrap();
+        assert_eq!(polygon.signed_area().abs() as f64, lattice.area());
+        assert_eq!(6, lattice.boundary_points());
+        assert_eq!(
+            None,
+            Polygon::new(vec![p(0.5, 0.0), p(1.0, 0.0), p(0.0, 1.0)]).to_lattice()
+        );
+    }
+}
@@ -1,9 +1,249 @@
+pub mod align;
+pub mod alpha_shape;
+pub mod angle;
+pub mod angle_tracker;
+pub mod arc;
+pub mod arrangement;
+pub mod backend;
+pub mod bearing;
+pub mod bezier;
+pub mod bounce;
+pub mod catenary;
+pub mod circle;
+pub mod collision;
+pub mod compactness;
+pub mod compare;
+pub mod composite;
+pub mod congruence;
+pub mod contact;
+pub mod contour;
+pub mod curve;
+pub mod damp;
+pub mod dbscan;
+pub mod decompose;
+pub mod degrees;
+pub mod delaunay;
+pub mod dms;
+pub mod dubins;
+pub mod ellipse;
+pub mod empty_space;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fit;
+pub mod frechet;
+pub mod hausdorff;
+pub mod heightfield;
+pub mod hull;
+pub mod inequality;
+pub mod interval_tree;
+#[cfg(feature = "rand")]
+pub mod kmeans;
+pub mod lattice;
+pub mod line;
+pub mod measure;
+pub mod medial_axis;
+pub mod mesh;
+pub mod moments;
+pub mod morph;
+pub mod narrow_phase;
+pub mod navmesh;
+pub mod offset;
+pub mod ordered;
+pub mod oriented_rect;
+pub mod packing;
+pub mod path;
+pub mod pole;
+pub mod polygon;
+pub mod polygon_with_holes;
+pub mod polyline;
+pub mod pose;
+pub mod problems;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod raster;
+pub mod ray;
+pub mod rect;
+pub mod rect_set;
+pub mod refine;
+pub mod repair;
+pub mod script;
+pub mod sdf;
+pub mod segment;
+pub mod shape;
+pub mod side_lengths;
+pub mod skeleton;
+pub mod sketch;
+pub mod snap;
+pub mod space;
+pub mod spatial_hash;
+pub mod statistics;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod subdivide;
+mod sweep;
+pub mod sweep_prune;
+pub mod symmetry;
+mod transcendental;
+pub mod traversal;
+pub mod triangle_builder;
+pub mod turn;
+#[cfg(feature = "uom")]
+pub mod units;
+pub mod validate;
+pub mod vertex;
+pub mod viewport;
+pub mod visibility;
+pub mod visibility_graph;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use align::{align, align_scaled};
+pub use alpha_shape::{alpha_shape, concave_hull};
+pub use angle::Angle;
+pub use angle_tracker::AngleTracker;
+pub use arc::CircularArc;
+pub use arrangement::{Arrangement, EdgeId, FaceId};
+#[cfg(feature = "fast-math")]
+pub use backend::FastTrig;
+#[cfg(feature = "deterministic")]
+pub use backend::LibmTrig;
+pub use backend::{StdTrig, TrigBackend};
+pub use bearing::CompassPoints;
+pub use bezier::{CubicBezier, QuadraticBezier};
+pub use bounce::{bounces, trace_bounces, Bounces};
+pub use catenary::Catenary;
+pub use circle::Circle;
+pub use collision::{Capsule, Contact, TimeOfImpact};
+pub use composite::Composite;
+pub use congruence::Congruence;
+pub use contact::SegmentContact;
+pub use contour::contours;
+pub use curve::{polyline_curvature, ArcLength};
+pub use damp::{smooth_damp, SmoothDamp};
+pub use dbscan::{dbscan, DensityClusters};
+pub use delaunay::{delaunay, DynamicDelaunay};
+pub use dms::{Dms, ParseAngleError};
+pub use dubins::{DubinsPath, DubinsWord, Steer};
+pub use ellipse::Ellipse;
+pub use empty_space::{largest_empty_circle, largest_empty_rect};
+pub use error::{TriangleError, TrigError};
+pub use fit::Residuals;
+pub use hausdorff::{directed_hausdorff, hausdorff};
+pub use heightfield::{heightfield_mesh, heightfield_mesh_simplified};
+pub use hull::{convex_hull, IncrementalHull};
+pub use inequality::{
+    can_form_triangle, can_form_triangle_strict, check_triangle_inequality, InequalityViolation,
+    Side,
+};
+pub use interval_tree::IntervalTree;
+#[cfg(feature = "rand")]
+pub use kmeans::{kmeans, Clustering};
+pub use lattice::{pick_area, LatticePolygon};
+pub use line::Line;
+pub use mesh::{Mesh, Resolution};
+pub use moments::{SecondMoment, SecondMoments};
+pub use morph::Morph;
+pub use navmesh::NavMesh;
+pub use offset::OffsetSide;
+pub use ordered::OrderedPoint;
+pub use oriented_rect::{min_area_rect, oriented_bbox, OrientedRect};
+pub use packing::{pack, Placement, Skyline};
+pub use path::{Path, PathCommand, PathSegment};
+pub use polygon::Polygon;
+pub use polygon_with_holes::PolygonWithHoles;
+pub use polyline::Polyline;
+pub use pose::Pose2;
+pub use raster::{GridLayout, Rasterize};
+pub use ray::{raycast, Ray, RayHit};
+pub use rect::Rect;
+pub use rect_set::{union_area, RectSet};
+pub use refine::MeshQuality;
+pub use repair::SelfIntersection;
+pub use script::{Script, ScriptError, ScriptErrorKind, Value};
+pub use sdf::{Intersection, SignedDistance, SmoothUnion, Subtraction, Union};
+pub use segment::Segment;
+pub use shape::{Area, Centroid, Contains, Perimeter, Shape};
+pub use side_lengths::SideLengths;
+pub use skeleton::{SkeletonEdge, SkeletonFace, StraightSkeleton};
+pub use sketch::{Constraint, PointId, SegmentId, Sketch, SketchError};
+pub use snap::{snap_round, Snap, Tolerance};
+pub use space::{Plane, Point3, Triangle3};
+pub use spatial_hash::{ItemId, SpatialHash};
+pub use statistics::{centroid, Covariance, Summary};
+pub use subdivide::Subdivision;
+pub use sweep_prune::{PairChanges, SweepAndPrune};
+pub use traversal::{Bresenham, GridCells};
+pub use triangle_builder::{SolvedTriangle, TriangleBuilder};
+pub use validate::{Issue, Validate, ValidationReport};
+pub use vertex::VertexId;
+pub use viewport::{ViewTransform, Viewport};
+pub use visibility::visibility_polygon;
+pub use visibility_graph::VisibilityGraph;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Point {
     pub x: f32,
     pub y: f32,
 }
 
+/// Coordinates the orientation test accepts: points, and the f32 and f64 pairs some
+/// algorithms work in.
+pub(crate) trait Planar: Copy {
+    type Scalar: Copy + std::ops::Sub<Output = Self::Scalar> + std::ops::Mul<Output = Self::Scalar>;
+
+    fn xy(self) -> (Self::Scalar, Self::Scalar);
+}
+
+impl Planar for Point {
+    type Scalar = f32;
+
+    fn xy(self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+}
+
+impl Planar for (f32, f32) {
+    type Scalar = f32;
+
+    fn xy(self) -> (f32, f32) {
+        self
+    }
+}
+
+impl Planar for (f64, f64) {
+    type Scalar = f64;
+
+    fn xy(self) -> (f64, f64) {
+        self
+    }
+}
+
+/// Return twice the signed area of the triangle abc, positive if the corners run counter
+/// clockwise and 0 if they are collinear
+pub(crate) fn orientation<P: Planar>(a: P, b: P, c: P) -> P::Scalar {
+    let ((ax, ay), (bx, by), (cx, cy)) = (a.xy(), b.xy(), c.xy());
+    (bx - ax) * (cy - ay) - (cx - ax) * (by - ay)
+}
+
+/// Point fixture shared by the tests of every module
+#[cfg(test)]
+pub(crate) fn p(x: f32, y: f32) -> Point {
+    Point { x, y }
+}
+
+/// Counter clockwise square fixture with its lower left corner at (x, y)
+#[cfg(test)]
+pub(crate) fn square(x: f32, y: f32, size: f32) -> Polygon {
+    Polygon::new(vec![
+        p(x, y),
+        p(x + size, y),
+        p(x + size, y + size),
+        p(x, y + size),
+    ])
+}
+
 /// Describe vector AB. As not all values of a vector are always needed vectors
 /// are initialized by default. Values will be initialized when called or when
 /// calling the vectors init method.
@@ -41,10 +281,50 @@ impl Vector {
         v
     }
 
-    /// Initialize the vector by setting length, alpha and beta
-    pub fn init(&mut self) -> () {
+    pub fn point_a(&self) -> Point {
+        self.point_a
+    }
+
+    pub fn point_b(&self) -> Point {
+        self.point_b
+    }
+
+    /// Return the x component, the horizontal distance from point A to point B
+    pub fn dx(&self) -> f32 {
+        self.point_b.x - self.point_a.x
+    }
+
+    /// Return the y component, the vertical distance from point A to point B
+    pub fn dy(&self) -> f32 {
+        self.point_b.y - self.point_a.y
+    }
+
+    /// Move point A of the vector. Cached length and angles are cleared and will be
+    /// recalculated on the next request.
+    pub fn set_point_a(&mut self, point_a: Point) {
+        self.point_a = point_a;
+        self.clear();
+    }
+
+    /// Move point B of the vector. Cached length and angles are cleared and will be
+    /// recalculated on the next request.
+    pub fn set_point_b(&mut self, point_b: Point) {
+        self.point_b = point_b;
+        self.clear();
+    }
+
+    /// Reset all cached values to None
+    fn clear(&mut self) {
+        self.length = None;
+        self.alpha = None;
+        self.beta = None;
+    }
+
+    /// Initialize the vector by setting length, alpha and beta. The angles stay unset for a
+    /// vector without length.
+    pub fn init(&mut self) {
         self.length();
-        self.set_alpha_beta();
+        self.set_alpha_beta().ok();
     }
 
     /// If length is not None length will be returned, else length will be calculated.
@@ -55,7 +335,7 @@ impl Vector {
             None => {
                 let opposite = self.point_a.x - self.point_b.x;
                 let adjacent = self.point_a.y - self.point_b.y;
-                let hypotenuse = (opposite.powf(2.0) + adjacent.powf(2.0)).sqrt();
+                let hypotenuse = (opposite * opposite + adjacent * adjacent).sqrt();
                 self.length = Some(hypotenuse);
                 hypotenuse
             }
@@ -64,34 +344,36 @@ impl Vector {
 
     /// Intitialize angle alpha and beta by creating a right angled triangle
     /// and calculating the remaining angles.
-    fn set_alpha_beta(&mut self) -> () {
+    fn set_alpha_beta(&mut self) -> Result<(f32, f32), TrigError> {
+        if self.point_a == self.point_b {
+            return Err(TrigError::ZeroLengthVector {
+                point: self.point_a,
+            });
+        }
         let opposite = self.point_a.x - self.point_b.x;
         let adjacent = self.point_a.y - self.point_b.y;
-        let beta =
-            (opposite.powf(2f32) / adjacent.powf(2f32)).atan() * 180f32 / std::f32::consts::PI;
+        let beta = transcendental::atan((opposite * opposite) / (adjacent * adjacent)) * 180f32
+            / std::f32::consts::PI;
         self.alpha = Some(90f32 - beta);
         self.beta = Some(beta);
+        Ok((90f32 - beta, beta))
     }
 
-    /// If alpha is None, initialize angles and return alpha, else return alpha
-    pub fn alpha(&mut self) -> f32 {
+    /// If alpha is None, initialize angles and return alpha, else return alpha. A vector
+    /// without length has no angles.
+    pub fn alpha(&mut self) -> Result<f32, TrigError> {
         match self.alpha {
-            Some(f) => f,
-            None => {
-                self.set_alpha_beta();
-                self.alpha.unwrap()
-            }
+            Some(f) => Ok(f),
+            None => self.set_alpha_beta().map(|(alpha, _)| alpha),
         }
     }
 
-    /// If beta is None, initialize angles and return beta, else return alpha
-    pub fn beta(&mut self) -> f32 {
+    /// If beta is None, initialize angles and return beta, else return beta. A vector
+    /// without length has no angles.
+    pub fn beta(&mut self) -> Result<f32, TrigError> {
         match self.beta {
-            Some(f) => f,
-            None => {
-                self.set_alpha_beta();
-                self.beta.unwrap()
-            }
+            Some(f) => Ok(f),
+            None => self.set_alpha_beta().map(|(_, beta)| beta),
         }
     }
 }
@@ -101,11 +383,13 @@ impl Vector {
 /// vector/stretch opposite point point_a (BC, respectively CB), b opposite point_b (CA/AC)
 /// and c describes the stretch opposite point_c (AB/BA).
 /// alpha is the angle at point point_a, beta at point point_b, and gamma at point point_c.
+/// The points are only accessible through getters and setters, so cached lengths and angles
+/// can never describe a different triangle than the one stored.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Triangle {
-    pub point_a: Point,
-    pub point_b: Point,
-    pub point_c: Point,
+    point_a: Point,
+    point_b: Point,
+    point_c: Point,
     ab: Option<f32>,
     bc: Option<f32>,
     ca: Option<f32>,
@@ -146,88 +430,176 @@ impl Triangle {
         t
     }
 
+    pub fn point_a(&self) -> Point {
+        self.point_a
+    }
+
+    pub fn point_b(&self) -> Point {
+        self.point_b
+    }
+
+    pub fn point_c(&self) -> Point {
+        self.point_c
+    }
+
+    /// Move point A. Cached lengths and angles are cleared and will be recalculated on the
+    /// next request.
+    pub fn set_point_a(&mut self, point_a: Point) {
+        self.point_a = point_a;
+        self.clear();
+    }
+
+    /// Move point B. Cached lengths and angles are cleared and will be recalculated on the
+    /// next request.
+    pub fn set_point_b(&mut self, point_b: Point) {
+        self.point_b = point_b;
+        self.clear();
+    }
+
+    /// Move point C. Cached lengths and angles are cleared and will be recalculated on the
+    /// next request.
+    pub fn set_point_c(&mut self, point_c: Point) {
+        self.point_c = point_c;
+        self.clear();
+    }
+
+    /// Reset all cached values to None
+    fn clear(&mut self) {
+        self.ab = None;
+        self.bc = None;
+        self.ca = None;
+        self.alpha = None;
+        self.beta = None;
+        self.gamma = None;
+    }
+
     /// All length values are initialized together, as it is likely to request more than just on length
     /// when using triangle  calculations.
-    fn init_lengths(&mut self) -> () {
-        self.ab = Some(Vector::new(self.point_a, self.point_b).length());
-        self.bc = Some(Vector::new(self.point_b, self.point_c).length());
-        self.ca = Some(Vector::new(self.point_c, self.point_a).length());
+    fn init_lengths(&mut self) -> [f32; 3] {
+        let length = |side| {
+            let (point_a, point_b) = self.side(side);
+            Vector::new(point_a, point_b).length()
+        };
+        let lengths = [length(0), length(1), length(2)];
+        self.ab = Some(lengths[0]);
+        self.bc = Some(lengths[1]);
+        self.ca = Some(lengths[2]);
+        lengths
     }
 
     /// Applied law of cosines -> This function might move outside this struct in the future!
     fn get_angle(adj1: f32, adj2: f32, opp: f32) -> f32 {
-        ((adj1.powf(2.0) + adj2.powf(2.0) - opp.powf(2.0)) / (2.0 * adj1 * adj2)).acos() * 180.0
-            / std::f32::consts::PI
+        let cosine = (adj1 * adj1 + adj2 * adj2 - opp * opp) / (2.0 * adj1 * adj2);
+        transcendental::acos(cosine.clamp(-1.0, 1.0)) * 180.0 / std::f32::consts::PI
     }
 
-    fn init(&mut self) -> () {
+    /// The angles stay unset for a degenerate triangle
+    fn init(&mut self) {
         self.init_lengths();
-        self.init_angles();
+        self.init_angles().ok();
     }
 
-    fn init_angles(&mut self) -> () {
-        self.alpha = Some(Triangle::get_angle(self.ab(), self.ca(), self.bc()));
-        self.beta = Some(Triangle::get_angle(self.bc(), self.ab(), self.ca()));
-        self.gamma = Some(Triangle::get_angle(self.ca(), self.bc(), self.ab()));
+    /// Collinear corners have no angles, as the law of cosines would divide by zero for
+    /// coinciding ones
+    fn init_angles(&mut self) -> Result<[f32; 3], TrigError> {
+        let (a, b, c) = (self.point_a, self.point_b, self.point_c);
+        if (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y) == 0.0 {
+            return Err(TrigError::DegenerateTriangle { a, b, c });
+        }
+        let angles = [
+            Triangle::get_angle(self.ab(), self.ca(), self.bc()),
+            Triangle::get_angle(self.bc(), self.ab(), self.ca()),
+            Triangle::get_angle(self.ca(), self.bc(), self.ab()),
+        ];
+        self.alpha = Some(angles[0]);
+        self.beta = Some(angles[1]);
+        self.gamma = Some(angles[2]);
+        Ok(angles)
     }
 
     pub fn ab(&mut self) -> f32 {
         match self.ab {
             Some(f) => f,
-            None => {
-                self.init_lengths();
-                self.ab.unwrap()
-            }
+            None => self.init_lengths()[0],
         }
     }
 
     pub fn bc(&mut self) -> f32 {
         match self.bc {
             Some(f) => f,
-            None => {
-                self.init_lengths();
-                self.bc.unwrap()
-            }
+            None => self.init_lengths()[1],
         }
     }
 
     pub fn ca(&mut self) -> f32 {
         match self.ca {
             Some(f) => f,
-            None => {
-                self.init_lengths();
-                self.ca.unwrap()
-            }
+            None => self.init_lengths()[2],
+        }
+    }
+
+    /// Return the side from A to B as a Vector. The length is shared with the triangle, so it
+    /// is calculated at most once for both.
+    pub fn edge_ab(&mut self) -> Vector {
+        self.edge(0)
+    }
+
+    /// Return the side from B to C as a Vector with the length of the triangle
+    pub fn edge_bc(&mut self) -> Vector {
+        self.edge(1)
+    }
+
+    /// Return the side from C to A as a Vector with the length of the triangle
+    pub fn edge_ca(&mut self) -> Vector {
+        self.edge(2)
+    }
+
+    /// Return the end points of side 0 (ab), 1 (bc) or 2 (ca)
+    fn side(&self, side: usize) -> (Point, Point) {
+        match side {
+            0 => (self.point_a, self.point_b),
+            1 => (self.point_b, self.point_c),
+            _ => (self.point_c, self.point_a),
+        }
+    }
+
+    fn edge(&mut self, side: usize) -> Vector {
+        let length = match side {
+            0 => self.ab(),
+            1 => self.bc(),
+            _ => self.ca(),
+        };
+        let (point_a, point_b) = self.side(side);
+        Vector {
+            point_a,
+            point_b,
+            length: Some(length),
+            alpha: None,
+            beta: None,
         }
     }
 
-    pub fn alpha(&mut self) -> f32 {
+    /// Return the angle at point_a in degrees, an error if the corners are collinear
+    pub fn alpha(&mut self) -> Result<f32, TrigError> {
         match self.alpha {
-            Some(f) => f,
-            None => {
-                self.init_angles();
-                self.alpha.unwrap()
-            }
+            Some(f) => Ok(f),
+            None => self.init_angles().map(|angles| angles[0]),
         }
     }
 
-    pub fn beta(&mut self) -> f32 {
+    /// Return the angle at point_b in degrees, an error if the corners are collinear
+    pub fn beta(&mut self) -> Result<f32, TrigError> {
         match self.beta {
-            Some(f) => f,
-            None => {
-                self.init_angles();
-                self.beta.unwrap()
-            }
+            Some(f) => Ok(f),
+            None => self.init_angles().map(|angles| angles[1]),
         }
     }
 
-    pub fn gamma(&mut self) -> f32 {
+    /// Return the angle at point_c in degrees, an error if the corners are collinear
+    pub fn gamma(&mut self) -> Result<f32, TrigError> {
         match self.gamma {
-            Some(f) => f,
-            None => {
-                self.init_angles();
-                self.gamma.unwrap()
-            }
+            Some(f) => Ok(f),
+            None => self.init_angles().map(|angles| angles[2]),
         }
     }
 }
@@ -252,7 +624,7 @@ mod tests {
         let b = Point { x: 3.0, y: 1.0 };
         let mut v = Vector::new(a, b);
         let expected = 45f64;
-        let result = round::half_away_from_zero(v.alpha().into(), 1);
+        let result = round::half_away_from_zero(v.alpha().unwrap().into(), 1);
         assert_eq!(result, expected);
     }
 
@@ -266,8 +638,51 @@ mod tests {
         let expected_gamma = 91.8;
         let mut result = Triangle::new_initialized(point_a, point_b, point_c);
         println!("{:?}", result);
-        assert_eq!(expected_alpha, round::half_away_from_zero(result.alpha().into(), 1));
-        assert_eq!(expected_beta, round::half_away_from_zero(result.beta().into(), 1));
-        assert_eq!(expected_gamma, round::half_away_from_zero(result.gamma().into(), 1));
+        assert_eq!(expected_alpha, round::half_away_from_zero(result.alpha().unwrap().into(), 1));
+        assert_eq!(expected_beta, round::half_away_from_zero(result.beta().unwrap().into(), 1));
+        assert_eq!(expected_gamma, round::half_away_from_zero(result.gamma().unwrap().into(), 1));
+    }
+
+    #[test]
+    fn test_vector_set_point_clears_cache() {
+        let a = Point { x: 0.0, y: 0.0 };
+        let b = Point { x: 3.0, y: 4.0 };
+        let mut v = Vector::new_initialized(a, b);
+        assert_eq!(5.0, v.length());
+        v.set_point_b(Point { x: 6.0, y: 8.0 });
+        assert_eq!(10.0, v.length());
+    }
+
+    #[test]
+    fn test_triangle_set_point_clears_cache() {
+        let point_a = Point { x: 0.0, y: 0.0 };
+        let point_b = Point { x: 4.0, y: 0.0 };
+        let point_c = Point { x: 0.0, y: 3.0 };
+        let mut t = Triangle::new_initialized(point_a, point_b, point_c);
+        assert_eq!(5.0, t.bc());
+        assert_eq!(
+            90.0,
+            round::half_away_from_zero(t.alpha().unwrap().into(), 1)
+        );
+        t.set_point_c(Point { x: 4.0, y: 3.0 });
+        assert_eq!(3.0, t.bc());
+        assert_eq!(
+            90.0,
+            round::half_away_from_zero(t.beta().unwrap().into(), 1)
+        );
+    }
+
+    #[test]
+    fn test_triangle_edges_share_lengths() {
+        let point_a = Point { x: 0.0, y: 0.0 };
+        let point_b = Point { x: 4.0, y: 0.0 };
+        let point_c = Point { x: 0.0, y: 3.0 };
+        let mut t = Triangle::new(point_a, point_b, point_c);
+        let mut bc = t.edge_bc();
+        assert_eq!(point_b, bc.point_a());
+        assert_eq!(point_c, bc.point_b());
+        assert_eq!(t.bc(), bc.length());
+        assert_eq!(4.0, t.edge_ab().length());
+        assert_eq!(-3.0, t.edge_ca().dy());
     }
 }
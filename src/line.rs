@@ -0,0 +1,166 @@
+use crate::{transcendental, Angle, Point};
+
+/// Infinite straight line in general form a * x + b * y + c = 0. Lines built by this crate
+/// are normalized so that (a, b) is the unit normal, which makes signed_distance a true
+/// distance, but lines created through new may use any scale.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Line {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+}
+
+impl Line {
+    pub fn new(a: f32, b: f32, c: f32) -> Line {
+        Line { a, b, c }
+    }
+
+    /// Return the line through both points, None if they coincide
+    pub fn through(point_a: Point, point_b: Point) -> Option<Line> {
+        let (dx, dy) = (point_b.x - point_a.x, point_b.y - point_a.y);
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            return None;
+        }
+        let (a, b) = (-dy / length, dx / length);
+        Some(Line {
+            a,
+            b,
+            c: -(a * point_a.x + b * point_a.y),
+        })
+    }
+
+    /// Return the line y = slope * x + intercept. Vertical lines have no slope, build them
+    /// with through or from_point_direction.
+    pub fn from_slope_intercept(slope: f32, intercept: f32) -> Line {
+        Line::from_point_slope(
+            Point {
+                x: 0.0,
+                y: intercept,
+            },
+            slope,
+        )
+    }
+
+    /// Return the line through the point with the given slope
+    pub fn from_point_slope(point: Point, slope: f32) -> Line {
+        let length = transcendental::hypot(slope, 1.0);
+        Line {
+            a: -slope / length,
+            b: 1.0 / length,
+            c: (slope * point.x - point.y) / length,
+        }
+    }
+
+    /// Return the line through the point running in the direction
+    pub fn from_point_direction(point: Point, direction: Angle) -> Line {
+        let (sin, cos) = transcendental::sin_cos(direction.radians());
+        Line {
+            a: -sin,
+            b: cos,
+            c: sin * point.x - cos * point.y,
+        }
+    }
+
+    /// Return true if the line runs parallel to the y axis, up to the rounding of b relative
+    /// to the length of (a, b), as left by a direction of 90 degrees
+    pub fn is_vertical(&self) -> bool {
+        self.a != 0.0 && self.b.abs() <= f32::EPSILON * transcendental::hypot(self.a, self.b)
+    }
+
+    /// Return slope and intercept of y = slope * x + intercept, None for vertical lines
+    pub fn slope_intercept(&self) -> Option<(f32, f32)> {
+        if self.b == 0.0 || self.is_vertical() {
+            return None;
+        }
+        Some((-self.a / self.b, -self.c / self.b))
+    }
+
+    /// Return the point of the line closest to the origin and the direction of the line, so
+    /// that (a, b) lies to its left. None if a and b are 0.
+    pub fn point_direction(&self) -> Option<(Point, Angle)> {
+        let line = self.normalized()?;
+        let point = Point {
+            x: -line.a * line.c,
+            y: -line.b * line.c,
+        };
+        Some((
+            point,
+            Angle::from_radians(transcendental::atan2(-line.a, line.b)),
+        ))
+    }
+
+    /// Return two distinct points of the line one unit apart, None if a and b are 0
+    pub fn two_points(&self) -> Option<(Point, Point)> {
+        let line = self.normalized()?;
+        let (point, _) = line.point_direction()?;
+        let other = Point {
+            x: point.x + line.b,
+            y: point.y - line.a,
+        };
+        Some((point, other))
+    }
+
+    /// Return the same line scaled so that (a, b) has unit length, None if a and b are 0
+    pub fn normalized(&self) -> Option<Line> {
+        let length = (self.a * self.a + self.b * self.b).sqrt();
+        if length == 0.0 {
+            return None;
+        }
+        Some(Line {
+            a: self.a / length,
+            b: self.b / length,
+            c: self.c / length,
+        })
+    }
+
+    /// Return the distance of the point from the line, positive on the side (a, b) points to
+    pub fn signed_distance(&self, point: Point) -> f32 {
+        (self.a * point.x + self.b * point.y + self.c) / (self.a * self.a + self.b * self.b).sqrt()
+    }
+
+    pub fn distance_to(&self, point: Point) -> f32 {
+        self.signed_distance(point).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn same_line(l: Line, m: Line) -> bool {
+        let (l, m) = (l.normalized().unwrap(), m.normalized().unwrap());
+        let close = |u: f32, v: f32| (u - v).abs() < 1e-5;
+        (close(l.a, m.a) && close(l.b, m.b) && close(l.c, m.c))
+            || (close(l.a, -m.a) && close(l.b, -m.b) && close(l.c, -m.c))
+    }
+
+    #[test]
+    fn test_slope_forms() {
+        let line = Line::from_slope_intercept(2.0, 1.0);
+        assert!(same_line(
+            line,
+            Line::through(p(0.0, 1.0), p(1.0, 3.0)).unwrap()
+        ));
+        assert!(same_line(line, Line::from_point_slope(p(1.0, 3.0), 2.0)));
+        let (slope, intercept) = line.slope_intercept().unwrap();
+        assert!((slope - 2.0).abs() < 1e-5 && (intercept - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_vertical_line() {
+        let vertical = Line::from_point_direction(p(3.0, 5.0), Angle::from_degrees(90.0));
+        assert!(vertical.distance_to(p(3.0, -2.0)) < 1e-5);
+        assert!(vertical.is_vertical());
+        assert_eq!(None, vertical.slope_intercept());
+        assert_eq!(None, Line::new(1.0, 0.0, -3.0).slope_intercept());
+        assert!(Line::new(1.0, 0.0, -3.0).is_vertical());
+        let (point, direction) = Line::new(1.0, 0.0, -3.0).point_direction().unwrap();
+        assert_eq!(p(3.0, 0.0), point);
+        assert!((direction.degrees() + 90.0).abs() < 1e-5);
+        let (a, b) = Line::new(0.0, 2.0, -4.0).two_points().unwrap();
+        assert_eq!((2.0, 2.0), (a.y, b.y));
+        assert!(((b.x - a.x).abs() - 1.0).abs() < 1e-6);
+    }
+}
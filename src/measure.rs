@@ -0,0 +1,237 @@
+//! Area, perimeter, centroid and containment of the shapes of the crate.
+
+use crate::{
+    orientation, Area, Centroid, Circle, Contains, Ellipse, Perimeter, Point, Polygon, Rect,
+    Segment, Tolerance, Triangle,
+};
+use std::f32::consts::PI;
+
+impl Area for Segment {
+    fn area(&self) -> f32 {
+        0.0
+    }
+}
+
+impl Perimeter for Segment {
+    fn perimeter(&self) -> f32 {
+        self.length()
+    }
+}
+
+impl Centroid for Segment {
+    fn centroid(&self) -> Point {
+        midpoint(self.point_a, self.point_b)
+    }
+}
+
+impl Contains<Point> for Segment {
+    /// Return true if the point lies on the segment within the default tolerance
+    fn contains(&self, point: Point) -> bool {
+        self.distance_to(point) <= Tolerance::default().distance
+    }
+}
+
+impl Area for Circle {
+    fn area(&self) -> f32 {
+        PI * self.radius * self.radius
+    }
+}
+
+impl Perimeter for Circle {
+    fn perimeter(&self) -> f32 {
+        2.0 * PI * self.radius
+    }
+}
+
+impl Centroid for Circle {
+    fn centroid(&self) -> Point {
+        self.center
+    }
+}
+
+impl Contains<Point> for Circle {
+    fn contains(&self, point: Point) -> bool {
+        (point.x - self.center.x).powi(2) + (point.y - self.center.y).powi(2)
+            <= self.radius * self.radius
+    }
+}
+
+impl Area for Ellipse {
+    fn area(&self) -> f32 {
+        PI * self.semi_major * self.semi_minor
+    }
+}
+
+impl Perimeter for Ellipse {
+    /// Ramanujan's second approximation, exact for circles
+    fn perimeter(&self) -> f32 {
+        let (a, b) = (self.semi_major, self.semi_minor);
+        if a + b == 0.0 {
+            return 0.0;
+        }
+        let h = ((a - b) / (a + b)).powi(2);
+        PI * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+}
+
+impl Centroid for Ellipse {
+    fn centroid(&self) -> Point {
+        self.center
+    }
+}
+
+impl Contains<Point> for Ellipse {
+    fn contains(&self, point: Point) -> bool {
+        Ellipse::contains(self, point)
+    }
+}
+
+impl Area for Rect {
+    fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+}
+
+impl Perimeter for Rect {
+    fn perimeter(&self) -> f32 {
+        2.0 * (self.width() + self.height())
+    }
+}
+
+impl Centroid for Rect {
+    fn centroid(&self) -> Point {
+        midpoint(self.min, self.max)
+    }
+}
+
+impl Contains<Point> for Rect {
+    fn contains(&self, point: Point) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+}
+
+impl Area for Triangle {
+    fn area(&self) -> f32 {
+        orientation(self.point_a(), self.point_b(), self.point_c()).abs() / 2.0
+    }
+}
+
+impl Perimeter for Triangle {
+    fn perimeter(&self) -> f32 {
+        self.edges().map(|e| e.length()).sum()
+    }
+}
+
+impl Centroid for Triangle {
+    fn centroid(&self) -> Point {
+        let (a, b, c) = (self.point_a(), self.point_b(), self.point_c());
+        Point {
+            x: (a.x + b.x + c.x) / 3.0,
+            y: (a.y + b.y + c.y) / 3.0,
+        }
+    }
+}
+
+impl Contains<Point> for Triangle {
+    fn contains(&self, point: Point) -> bool {
+        let (a, b, c) = (self.point_a(), self.point_b(), self.point_c());
+        let sides = [
+            orientation(a, b, point),
+            orientation(b, c, point),
+            orientation(c, a, point),
+        ];
+        sides.iter().all(|s| *s >= 0.0) || sides.iter().all(|s| *s <= 0.0)
+    }
+}
+
+impl Area for Polygon {
+    fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+}
+
+impl Perimeter for Polygon {
+    fn perimeter(&self) -> f32 {
+        self.edges().map(|e| e.length()).sum()
+    }
+}
+
+impl Centroid for Polygon {
+    /// Return the center of mass of the enclosed area. For polygons without area the mean
+    /// of the points is returned, and the origin if there are no points.
+    fn centroid(&self) -> Point {
+        let signed_area = self.signed_area();
+        if signed_area == 0.0 {
+            return crate::centroid(&self.points).unwrap_or(Point { x: 0.0, y: 0.0 });
+        }
+        let (x, y) = self.edges().fold((0.0, 0.0), |(x, y), e| {
+            let (a, b) = (e.point_a, e.point_b);
+            let factor = a.x * b.y - b.x * a.y;
+            (x + (a.x + b.x) * factor, y + (a.y + b.y) * factor)
+        });
+        Point {
+            x: x / (6.0 * signed_area),
+            y: y / (6.0 * signed_area),
+        }
+    }
+}
+
+impl Contains<Point> for Polygon {
+    /// Even-odd containment, see Polygon::contains
+    fn contains(&self, point: Point) -> bool {
+        Polygon::contains(self, point)
+    }
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Shape;
+
+    #[test]
+    fn test_mixed_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Circle::new(p(0.0, 0.0), 1.0)),
+            Box::new(Rect::new(p(0.0, 0.0), p(2.0, 3.0))),
+            Box::new(Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0))),
+            Box::new(Ellipse::new(p(1.0, 1.0), 2.0, 1.0, 45.0)),
+            Box::new(Segment::new(p(0.0, 0.0), p(3.0, 4.0))),
+        ];
+        let total: f32 = shapes.iter().map(|s| s.area()).sum();
+        assert!((total - (PI + 6.0 + 6.0 + 2.0 * PI)).abs() < 1e-4);
+        assert!((shapes[2].perimeter() - 12.0).abs() < 1e-5);
+        assert!((shapes[4].perimeter() - 5.0).abs() < 1e-6);
+        assert_eq!(p(1.5, 2.0), shapes[4].centroid());
+        let inside = shapes.iter().filter(|s| s.contains(p(0.5, 0.5))).count();
+        assert_eq!(4, inside);
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        let l_shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 2.0),
+            p(0.0, 2.0),
+        ]);
+        let centroid = Centroid::centroid(&l_shape);
+        assert!((centroid.x - 5.0 / 6.0).abs() < 1e-6);
+        assert!((centroid.y - 5.0 / 6.0).abs() < 1e-6);
+        assert!((l_shape.perimeter() - 8.0).abs() < 1e-6);
+        let circle = Ellipse::new(p(0.0, 0.0), 1.0, 1.0, 0.0);
+        assert!((circle.perimeter() - 2.0 * PI).abs() < 1e-5);
+    }
+}
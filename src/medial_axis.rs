@@ -0,0 +1,204 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::delaunay::circumcenter;
+use crate::{delaunay, Point, Polygon, Polyline, Segment, Tolerance};
+
+impl Polygon {
+    /// Return an approximation of the medial axis, the centerline of the polygon, as a set of
+    /// polylines. The boundary is sampled every spacing units and the Voronoi vertices of the
+    /// samples that fall inside the polygon are connected. Side branches running from a
+    /// junction to a corner and shorter than min_branch are pruned away, so a corridor yields
+    /// one line along its middle.
+    pub fn medial_axis(&self, spacing: f32, min_branch: f32) -> Vec<Polyline> {
+        if self.points.len() < 3 || spacing <= 0.0 {
+            return Vec::new();
+        }
+        let samples = self.boundary_samples(spacing);
+        let mesh = delaunay(&samples);
+        let triangles: Vec<&[u32]> = mesh.indices.chunks(3).collect();
+
+        // Voronoi vertices are the circumcenters, coinciding ones are merged into one node
+        let mut nodes: Vec<Point> = Vec::new();
+        let mut node_of: Vec<Option<usize>> = Vec::new();
+        for t in &triangles {
+            let [a, b, c] = [
+                samples[t[0] as usize],
+                samples[t[1] as usize],
+                samples[t[2] as usize],
+            ];
+            let center = circumcenter(a, b, c).filter(|c| self.contains(*c));
+            node_of.push(center.map(|center| {
+                let merge = Tolerance::new(spacing * 1e-3);
+                match nodes.iter().position(|n| merge.coincident(*n, center)) {
+                    Some(i) => i,
+                    None => {
+                        nodes.push(center);
+                        nodes.len() - 1
+                    }
+                }
+            }));
+        }
+
+        // Voronoi edges are dual to Delaunay edges shared by two triangles. Edges between
+        // neighboring samples separate two pieces of the same wall and lead to the boundary.
+        let mut shared: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+        for (i, t) in triangles.iter().enumerate() {
+            for k in 0..3 {
+                let (a, b) = (t[k], t[(k + 1) % 3]);
+                shared.entry((a.min(b), a.max(b))).or_default().push(i);
+            }
+        }
+        let count = samples.len();
+        let mut adjacency: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); nodes.len()];
+        for ((a, b), faces) in &shared {
+            let gap = (b - a) as usize;
+            if faces.len() != 2 || gap.min(count - gap) <= 1 {
+                continue;
+            }
+            if let (Some(u), Some(v)) = (node_of[faces[0]], node_of[faces[1]]) {
+                if u != v {
+                    adjacency[u].insert(v);
+                    adjacency[v].insert(u);
+                }
+            }
+        }
+
+        prune(&nodes, &mut adjacency, min_branch);
+        chains(&nodes, &adjacency)
+    }
+
+    /// Return points along the boundary at most spacing apart, including all corners
+    fn boundary_samples(&self, spacing: f32) -> Vec<Point> {
+        let mut samples = Vec::new();
+        for edge in self.edges() {
+            let (a, b) = (edge.point_a, edge.point_b);
+            let pieces = (Segment::new(a, b).length() / spacing).ceil().max(1.0) as usize;
+            samples.extend((0..pieces).map(|k| {
+                let t = k as f32 / pieces as f32;
+                Point {
+                    x: a.x + (b.x - a.x) * t,
+                    y: a.y + (b.y - a.y) * t,
+                }
+            }));
+        }
+        samples
+    }
+}
+
+/// Repeatedly remove the short branches running from a leaf to a junction. When every branch
+/// at a junction is short, the longest one is kept.
+fn prune(nodes: &[Point], adjacency: &mut [BTreeSet<usize>], min_branch: f32) {
+    loop {
+        let mut branches: HashMap<usize, Vec<(f32, Vec<usize>)>> = HashMap::new();
+        for leaf in (0..nodes.len()).filter(|n| adjacency[*n].len() == 1) {
+            let (path, length) = walk(
+                nodes,
+                adjacency,
+                leaf,
+                adjacency[leaf].iter().next().copied(),
+            );
+            let end = path[path.len() - 1];
+            if adjacency[end].len() >= 3 && length < min_branch {
+                branches.entry(end).or_default().push((length, path));
+            }
+        }
+        let mut removed = false;
+        for (junction, mut candidates) in branches {
+            if candidates.len() == adjacency[junction].len() {
+                candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+                candidates.pop();
+            }
+            for (_, path) in candidates {
+                for pair in path.windows(2) {
+                    adjacency[pair[0]].remove(&pair[1]);
+                    adjacency[pair[1]].remove(&pair[0]);
+                }
+                removed = true;
+            }
+        }
+        if !removed {
+            return;
+        }
+    }
+}
+
+/// Follow the nodes of degree two from start over next, return the visited nodes and the
+/// length of the walk. The walk stops at a leaf, a junction or when it returns to start.
+fn walk(
+    nodes: &[Point],
+    adjacency: &[BTreeSet<usize>],
+    start: usize,
+    next: Option<usize>,
+) -> (Vec<usize>, f32) {
+    let mut path = vec![start];
+    let mut length = 0.0;
+    let (mut previous, mut current) = (start, next);
+    while let Some(node) = current {
+        length += Segment::new(nodes[previous], nodes[node]).length();
+        path.push(node);
+        if adjacency[node].len() != 2 || node == start {
+            break;
+        }
+        current = adjacency[node].iter().copied().find(|n| *n != previous);
+        previous = node;
+    }
+    (path, length)
+}
+
+/// Split the graph into polylines between leaves and junctions, closed loops are returned
+/// with their first point repeated at the end
+fn chains(nodes: &[Point], adjacency: &[BTreeSet<usize>]) -> Vec<Polyline> {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut polylines = Vec::new();
+    let ends = (0..nodes.len()).filter(|n| adjacency[*n].len() != 2);
+    let loops = (0..nodes.len()).filter(|n| adjacency[*n].len() == 2);
+    for start in ends.chain(loops) {
+        for &next in &adjacency[start] {
+            if visited.contains(&(start.min(next), start.max(next))) {
+                continue;
+            }
+            let (path, _) = walk(nodes, adjacency, start, Some(next));
+            for pair in path.windows(2) {
+                visited.insert((pair[0].min(pair[1]), pair[0].max(pair[1])));
+            }
+            polylines.push(Polyline::new(path.iter().map(|n| nodes[*n]).collect()));
+        }
+    }
+    polylines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_corridor_centerline() {
+        let corridor = Polygon::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(10.0, 2.0), p(0.0, 2.0)]);
+        let axis = corridor.medial_axis(0.2, 2.0);
+        assert_eq!(1, axis.len());
+        assert!(axis[0].points.iter().all(|q| (q.y - 1.0).abs() < 0.05));
+        assert!((axis[0].length() - 8.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_bent_corridor() {
+        let corridor = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(10.0, 0.0),
+            p(10.0, 2.0),
+            p(2.0, 2.0),
+            p(2.0, 10.0),
+            p(0.0, 10.0),
+        ]);
+        let axis = corridor.medial_axis(0.2, 2.0);
+        assert_eq!(1, axis.len());
+        let points = &axis[0].points;
+        assert!(points.iter().all(|q| corridor.contains(*q)));
+        let (first, last) = (points[0], points[points.len() - 1]);
+        let ends = [p(9.0, 1.0), p(1.0, 9.0)];
+        assert!(ends.iter().any(|e| Segment::new(first, *e).length() < 0.3));
+        assert!(ends.iter().any(|e| Segment::new(last, *e).length() < 0.3));
+        assert!(Segment::new(first, last).length() > 10.0);
+    }
+}
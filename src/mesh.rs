@@ -0,0 +1,238 @@
+use crate::{transcendental, Circle, CircularArc, Ellipse, Point, Polygon};
+
+/// Indexed triangle mesh: every three entries of indices name the vertices of one counter
+/// clockwise triangle. The buffers can be uploaded as they are to wgpu or OpenGL.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Point>,
+    pub indices: Vec<u32>,
+}
+
+/// How finely curved outlines are approximated when tessellating.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Resolution {
+    /// Fixed number of straight pieces along the full outline
+    Segments(usize),
+    /// Largest allowed distance between the curve and its approximation
+    Tolerance(f32),
+}
+
+impl Resolution {
+    /// Return the number of pieces for an arc of radius sweeping sweep_angle degrees, at
+    /// least one. Segments are distributed relative to a full turn.
+    fn segments(&self, radius: f32, sweep_angle: f32) -> usize {
+        let fraction = (sweep_angle.abs() / 360.0).min(1.0);
+        let count = match *self {
+            Resolution::Segments(n) => (n as f32 * fraction).ceil(),
+            Resolution::Tolerance(tolerance) => {
+                if tolerance <= 0.0 || tolerance >= radius {
+                    1.0
+                } else {
+                    // The sagitta of a piece spanning angle a is radius * (1 - cos(a / 2))
+                    let max_angle = 2.0 * transcendental::acos(1.0 - tolerance / radius);
+                    (sweep_angle.abs().to_radians() / max_angle).ceil()
+                }
+            }
+        };
+        (count as usize).max(1)
+    }
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<Point>, indices: Vec<u32>) -> Mesh {
+        Mesh { vertices, indices }
+    }
+
+    /// Iterate over the corner points of every triangle
+    pub fn triangles(&self) -> impl Iterator<Item = [Point; 3]> + '_ {
+        self.indices.chunks(3).map(move |t| {
+            [
+                self.vertices[t[0] as usize],
+                self.vertices[t[1] as usize],
+                self.vertices[t[2] as usize],
+            ]
+        })
+    }
+
+    /// Return the total area of all triangles
+    pub fn area(&self) -> f32 {
+        self.triangles().map(|[a, b, c]| signed_area(a, b, c)).sum()
+    }
+
+    /// Triangle fan around center through the outline points, closed back to the first
+    /// outline point if closed is true
+    fn fan(center: Point, outline: Vec<Point>, closed: bool) -> Mesh {
+        let n = outline.len() as u32;
+        let mut vertices = Vec::with_capacity(outline.len() + 1);
+        vertices.push(center);
+        vertices.extend(outline);
+        let pieces = if closed { n } else { n.saturating_sub(1) };
+        let mut indices = Vec::with_capacity(pieces as usize * 3);
+        for i in 0..pieces {
+            indices.extend_from_slice(&[0, i + 1, (i + 1) % n + 1]);
+        }
+        let mut mesh = Mesh { vertices, indices };
+        mesh.orient_counter_clockwise();
+        mesh
+    }
+
+    pub(crate) fn orient_counter_clockwise(&mut self) {
+        let vertices = &self.vertices;
+        for t in self.indices.chunks_mut(3) {
+            let (a, b, c) = (
+                vertices[t[0] as usize],
+                vertices[t[1] as usize],
+                vertices[t[2] as usize],
+            );
+            if signed_area(a, b, c) < 0.0 {
+                t.swap(1, 2);
+            }
+        }
+    }
+}
+
+impl Polygon {
+    /// Triangulate the polygon by ear clipping. The polygon has to be simple, without self
+    /// intersections or holes, but may be concave and ordered either way. The vertices of
+    /// the mesh are the polygon points.
+    pub fn triangulate(&self) -> Mesh {
+        let mut ring: Vec<usize> = (0..self.points.len()).collect();
+        if self.signed_area() < 0.0 {
+            ring.reverse();
+        }
+        let mut mesh = Mesh::new(self.points.clone(), ear_clip(&self.points, ring));
+        mesh.orient_counter_clockwise();
+        mesh
+    }
+}
+
+/// Triangulate the counter clockwise ring of indices into points by ear clipping and return
+/// the index buffer. The ring may visit a point twice, as bridges to holes do; corners at
+/// the same position as the ear corners do not block the ear.
+pub(crate) fn ear_clip(points: &[Point], mut ring: Vec<usize>) -> Vec<u32> {
+    let mut indices = Vec::with_capacity(ring.len().saturating_sub(2) * 3);
+    while ring.len() > 3 {
+        let n = ring.len();
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = (
+                points[ring[(i + n - 1) % n]],
+                points[ring[i]],
+                points[ring[(i + 1) % n]],
+            );
+            signed_area(a, b, c) > 0.0
+                && ring.iter().all(|&other| {
+                    let q = points[other];
+                    q == a || q == b || q == c || !in_triangle(q, a, b, c)
+                })
+        });
+        // Without an ear the input is degenerate, clip the next corner anyway
+        let i = ear.unwrap_or(0);
+        indices.extend_from_slice(&[
+            ring[(i + n - 1) % n] as u32,
+            ring[i] as u32,
+            ring[(i + 1) % n] as u32,
+        ]);
+        ring.remove(i);
+    }
+    if ring.len() == 3 {
+        indices.extend(ring.iter().map(|&i| i as u32));
+    }
+    indices
+}
+
+impl Circle {
+    /// Return a triangle fan covering the disk, the first vertex is the center
+    pub fn tessellate(&self, resolution: Resolution) -> Mesh {
+        let n = resolution.segments(self.radius, 360.0).max(3);
+        let outline = (0..n)
+            .map(|i| {
+                let angle = (i as f32 / n as f32) * std::f32::consts::PI * 2.0;
+                Point {
+                    x: self.center.x + self.radius * transcendental::cos(angle),
+                    y: self.center.y + self.radius * transcendental::sin(angle),
+                }
+            })
+            .collect();
+        Mesh::fan(self.center, outline, true)
+    }
+}
+
+impl CircularArc {
+    /// Return a triangle fan covering the circular sector between the arc and its center,
+    /// the first vertex is the center
+    pub fn tessellate(&self, resolution: Resolution) -> Mesh {
+        let n = resolution.segments(self.radius, self.sweep_angle);
+        let outline = (0..=n)
+            .map(|i| self.point_at(i as f32 / n as f32))
+            .collect();
+        Mesh::fan(self.center, outline, false)
+    }
+}
+
+impl Ellipse {
+    /// Return a triangle fan covering the ellipse, the first vertex is the center. A
+    /// tolerance is applied to the sharpest bend of the outline.
+    pub fn tessellate(&self, resolution: Resolution) -> Mesh {
+        let radius = if self.semi_minor > 0.0 {
+            self.semi_major * self.semi_major / self.semi_minor
+        } else {
+            self.semi_major
+        };
+        let n = resolution.segments(radius, 360.0).max(3);
+        let outline = (0..n).map(|i| self.point_at(i as f32 / n as f32)).collect();
+        Mesh::fan(self.center, outline, true)
+    }
+}
+
+fn signed_area(a: Point, b: Point, c: Point) -> f32 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)) / 2.0
+}
+
+/// Return true if p lies inside or on the counter clockwise triangle abc
+pub(crate) fn in_triangle(p: Point, a: Point, b: Point, c: Point) -> bool {
+    signed_area(a, b, p) >= 0.0 && signed_area(b, c, p) >= 0.0 && signed_area(c, a, p) >= 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_triangulate_concave_polygon() {
+        // L shape, ordered clockwise
+        let polygon = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(0.0, 2.0),
+            p(1.0, 2.0),
+            p(1.0, 1.0),
+            p(2.0, 1.0),
+            p(2.0, 0.0),
+        ]);
+        let mesh = polygon.triangulate();
+        assert_eq!(12, mesh.indices.len());
+        assert!((mesh.area() - 3.0).abs() < 1e-6);
+        assert!(mesh.triangles().all(|[a, b, c]| signed_area(a, b, c) > 0.0));
+        assert!(mesh
+            .triangles()
+            .all(|[a, b, c]| !in_triangle(p(1.5, 1.5), a, b, c)));
+    }
+
+    #[test]
+    fn test_tessellate_curves() {
+        let circle = Circle::new(p(1.0, 1.0), 2.0);
+        let mesh = circle.tessellate(Resolution::Segments(64));
+        assert_eq!(65, mesh.vertices.len());
+        assert_eq!(64 * 3, mesh.indices.len());
+        assert!((mesh.area() - std::f32::consts::PI * 4.0).abs() < 0.05);
+        let fine = circle.tessellate(Resolution::Tolerance(0.001));
+        assert!(fine.vertices.len() > 64);
+        let arc = CircularArc::new(p(0.0, 0.0), 1.0, 0.0, -90.0);
+        let sector = arc.tessellate(Resolution::Segments(8));
+        assert_eq!(2 * 3, sector.indices.len());
+        assert!(sector.area() > 0.0);
+        let ellipse = Ellipse::new(p(0.0, 0.0), 3.0, 1.0, 20.0);
+        let mesh = ellipse.tessellate(Resolution::Tolerance(0.01));
+        assert!((mesh.area() - std::f32::consts::PI * 3.0).abs() < 0.05);
+    }
+}
@@ -0,0 +1,142 @@
+//! Second moments of area, the section properties used for beam bending and torsion.
+
+use crate::{Area, Centroid, Circle, Line, Point, Polygon, Rect, Triangle};
+use std::f32::consts::PI;
+
+/// Second moments of an area about axes through its centroid parallel to x and y. ixx is
+/// the integral of y² over the area, iyy of x² and ixy of x * y.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SecondMoments {
+    pub ixx: f32,
+    pub iyy: f32,
+    pub ixy: f32,
+}
+
+impl SecondMoments {
+    /// Return the polar moment about the centroid, ixx + iyy
+    pub fn polar(&self) -> f32 {
+        self.ixx + self.iyy
+    }
+}
+
+/// Shapes with second moments of area.
+pub trait SecondMoment: Area + Centroid {
+    /// Return the second moments about the centroidal axes
+    fn second_moment(&self) -> SecondMoments;
+
+    /// Return the moment of inertia of the area about the axis, using the parallel axis
+    /// theorem. A line with a and b both 0 gives NaN.
+    fn moment_of_inertia(&self, axis: &Line) -> f32 {
+        let length = (axis.a * axis.a + axis.b * axis.b).sqrt();
+        let (a, b) = (axis.a / length, axis.b / length);
+        let moments = self.second_moment();
+        let distance = axis.signed_distance(self.centroid());
+        a * a * moments.iyy
+            + 2.0 * a * b * moments.ixy
+            + b * b * moments.ixx
+            + self.area() * distance * distance
+    }
+}
+
+impl SecondMoment for Rect {
+    fn second_moment(&self) -> SecondMoments {
+        let (w, h) = (self.width(), self.height());
+        SecondMoments {
+            ixx: w * h.powi(3) / 12.0,
+            iyy: h * w.powi(3) / 12.0,
+            ixy: 0.0,
+        }
+    }
+}
+
+impl SecondMoment for Circle {
+    fn second_moment(&self) -> SecondMoments {
+        let i = PI * self.radius.powi(4) / 4.0;
+        SecondMoments {
+            ixx: i,
+            iyy: i,
+            ixy: 0.0,
+        }
+    }
+}
+
+impl SecondMoment for Triangle {
+    fn second_moment(&self) -> SecondMoments {
+        ring_moments(
+            &[self.point_a(), self.point_b(), self.point_c()],
+            self.centroid(),
+        )
+    }
+}
+
+impl SecondMoment for Polygon {
+    /// Return the moments of the enclosed area by the standard polygon formula. The
+    /// orientation of the polygon does not matter.
+    fn second_moment(&self) -> SecondMoments {
+        ring_moments(&self.points, self.centroid())
+    }
+}
+
+/// Moments of the area enclosed by the ring about axes through center, made positive for
+/// clockwise rings
+fn ring_moments(points: &[Point], center: Point) -> SecondMoments {
+    let n = points.len();
+    let (mut ixx, mut iyy, mut ixy, mut area) = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        let (x0, y0, x1, y1) = (
+            a.x - center.x,
+            a.y - center.y,
+            b.x - center.x,
+            b.y - center.y,
+        );
+        let cross = x0 * y1 - x1 * y0;
+        area += cross;
+        ixx += cross * (y0 * y0 + y0 * y1 + y1 * y1);
+        iyy += cross * (x0 * x0 + x0 * x1 + x1 * x1);
+        ixy += cross * (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0);
+    }
+    let sign = if area < 0.0 { -1.0 } else { 1.0 };
+    SecondMoments {
+        ixx: sign * ixx / 12.0,
+        iyy: sign * iyy / 12.0,
+        ixy: sign * ixy / 24.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_rectangle_section() {
+        let rect = Rect::new(p(1.0, 1.0), p(3.0, 5.0));
+        let moments = rect.second_moment();
+        assert!(close(128.0 / 12.0, moments.ixx));
+        assert!(close(32.0 / 12.0, moments.iyy));
+        let polygon = Polygon::new(vec![p(1.0, 1.0), p(1.0, 5.0), p(3.0, 5.0), p(3.0, 1.0)]);
+        let from_polygon = polygon.second_moment();
+        assert!(close(moments.ixx, from_polygon.ixx));
+        assert!(close(moments.iyy, from_polygon.iyy));
+        assert!(close(0.0, from_polygon.ixy));
+        // About the base edge y = 1: w h³ / 3
+        let base = Line::new(0.0, 1.0, -1.0);
+        assert!(close(128.0 / 3.0, rect.moment_of_inertia(&base)));
+    }
+
+    #[test]
+    fn test_triangle_and_circle() {
+        let triangle = Triangle::new(p(0.0, 0.0), p(3.0, 0.0), p(0.0, 6.0));
+        let moments = triangle.second_moment();
+        // b h³ / 36 and the product moment -b² h² / 72 of a right triangle
+        assert!(close(3.0 * 216.0 / 36.0, moments.ixx));
+        assert!(close(-9.0 * 36.0 / 72.0, moments.ixy));
+        let circle = Circle::new(p(5.0, 5.0), 2.0);
+        assert!(close(8.0 * PI, circle.second_moment().polar()));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{ArcLength, Point, Polygon};
+
+impl Polygon {
+    /// Return a polygon with count points spread evenly along the boundary, starting at the
+    /// first point. Corners between the samples are cut off.
+    pub fn resample(&self, count: usize) -> Polygon {
+        if self.points.is_empty() || count == 0 {
+            return self.clone();
+        }
+        let mut ring = self.points.clone();
+        ring.push(self.points[0]);
+        let step = ring.arc_length() / count as f32;
+        Polygon::new(
+            (0..count)
+                .map(|i| ring.point_at_distance(i as f32 * step))
+                .collect(),
+        )
+    }
+}
+
+/// Interpolation between two polygons for animation. Both outlines are resampled to the
+/// same number of points, brought to counter clockwise order and the start of the target is
+/// rotated so corresponding points lie close together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Morph {
+    from: Polygon,
+    to: Polygon,
+}
+
+impl Morph {
+    /// Prepare the morph with count points per outline, at least three
+    pub fn new(from: &Polygon, to: &Polygon, count: usize) -> Morph {
+        let count = count.max(3);
+        let mut from = from.resample(count);
+        let mut to = to.resample(count);
+        from.fix_orientation();
+        to.fix_orientation();
+        let cost = |shift: usize| -> f32 {
+            from.points
+                .iter()
+                .enumerate()
+                .map(|(i, a)| {
+                    let b = to.points[(i + shift) % count];
+                    (a.x - b.x).powi(2) + (a.y - b.y).powi(2)
+                })
+                .sum()
+        };
+        let shift = (0..count)
+            .min_by(|a, b| cost(*a).partial_cmp(&cost(*b)).unwrap())
+            .unwrap_or(0);
+        to.points.rotate_left(shift);
+        Morph { from, to }
+    }
+
+    /// Return the intermediate polygon at t, where 0 is the start and 1 the target outline.
+    /// t is clamped to [0, 1].
+    pub fn at(&self, t: f32) -> Polygon {
+        let t = t.clamp(0.0, 1.0);
+        let points = self
+            .from
+            .points
+            .iter()
+            .zip(&self.to.points)
+            .map(|(a, b)| Point {
+                x: a.x + (b.x - a.x) * t,
+                y: a.y + (b.y - a.y) * t,
+            })
+            .collect();
+        Polygon::new(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_resample() {
+        let square = Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+        let resampled = square.resample(8);
+        assert_eq!(8, resampled.points.len());
+        assert_eq!(p(1.0, 0.0), resampled.points[1]);
+        assert_eq!(p(0.0, 1.0), resampled.points[7]);
+    }
+
+    #[test]
+    fn test_morph_between_squares() {
+        let a = Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+        // Same square shifted, listed clockwise from another corner
+        let b = Polygon::new(vec![p(4.0, 2.0), p(4.0, 0.0), p(2.0, 0.0), p(2.0, 2.0)]);
+        let morph = Morph::new(&a, &b, 8);
+        let halfway = morph.at(0.5);
+        assert_eq!(8, halfway.points.len());
+        assert!(halfway.points.iter().all(|q| (1.0..=3.0).contains(&q.x)));
+        assert!((halfway.signed_area() - 4.0).abs() < 1e-4);
+        assert_eq!(morph.at(1.5), morph.at(1.0));
+    }
+}
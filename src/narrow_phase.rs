@@ -0,0 +1,270 @@
+//! Exact overlap tests between convex polygons. Both tests assume convex input, the
+//! orientation of the polygons does not matter. Contacts follow the conventions of the
+//! collision module: the normal points towards the first polygon, which has to be moved by
+//! normal * depth to resolve the overlap.
+
+use crate::{orientation, Contact, Point, Polygon, Vector};
+
+/// Iteration limit of GJK and EPA, only reached for degenerate input.
+const MAX_ITERATIONS: usize = 64;
+/// EPA stops once the polytope can not be expanded by more than this distance.
+const EPA_TOLERANCE: f32 = 1e-5;
+
+/// Separating axis test between two convex polygons. Returns the contact with the smallest
+/// penetration depth, or None if an edge normal of either polygon separates them.
+pub fn sat(a: &Polygon, b: &Polygon) -> Option<Contact> {
+    if a.points.is_empty() || b.points.is_empty() {
+        return None;
+    }
+    let mut best: Option<((f32, f32), f32)> = None;
+    for edge in a.edges().chain(b.edges()) {
+        let (ex, ey) = (
+            edge.point_b.x - edge.point_a.x,
+            edge.point_b.y - edge.point_a.y,
+        );
+        let length = (ex * ex + ey * ey).sqrt();
+        if length == 0.0 {
+            continue;
+        }
+        let axis = (-ey / length, ex / length);
+        let (min_a, max_a) = project(&a.points, axis);
+        let (min_b, max_b) = project(&b.points, axis);
+        let overlap = max_a.min(max_b) - min_a.max(min_b);
+        if overlap <= 0.0 {
+            return None;
+        }
+        let smaller = match best {
+            Some((_, depth)) => overlap < depth,
+            None => true,
+        };
+        if smaller {
+            best = Some((axis, overlap));
+        }
+    }
+    let (mut normal, depth) = best?;
+    let (ca, cb) = (average(&a.points), average(&b.points));
+    if dot((ca.0 - cb.0, ca.1 - cb.1), normal) < 0.0 {
+        normal = (-normal.0, -normal.1);
+    }
+    Some(contact(a, normal, depth))
+}
+
+/// Return true if the convex polygons overlap or touch, using the Gilbert-Johnson-Keerthi
+/// algorithm on their Minkowski difference
+pub fn gjk(a: &Polygon, b: &Polygon) -> bool {
+    gjk_simplex(a, b).is_some()
+}
+
+/// Return the contact between two overlapping convex polygons, found by GJK and refined with
+/// the expanding polytope algorithm (EPA). None if the polygons do not overlap.
+pub fn gjk_epa(a: &Polygon, b: &Polygon) -> Option<Contact> {
+    let mut polytope = gjk_simplex(a, b)?;
+    if polytope.len() < 3 {
+        // The origin lies on an edge or corner of the Minkowski difference: touching contact
+        let (x, y) = if polytope.len() == 2 {
+            (polytope[1].0 - polytope[0].0, polytope[1].1 - polytope[0].1)
+        } else {
+            (0.0, 1.0)
+        };
+        let length = (x * x + y * y).sqrt();
+        return Some(contact(a, (-y / length, x / length), 0.0));
+    }
+    if orientation(polytope[0], polytope[1], polytope[2]) < 0.0 {
+        polytope.swap(1, 2);
+    }
+    let mut closest = ((0.0, 0.0), 0.0);
+    for _ in 0..MAX_ITERATIONS {
+        let n = polytope.len();
+        let mut edge_index = 0;
+        closest = ((0.0, 0.0), f32::MAX);
+        for i in 0..n {
+            let e = sub(polytope[(i + 1) % n], polytope[i]);
+            let length = (e.0 * e.0 + e.1 * e.1).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            // Outward normal of a counter clockwise polytope
+            let normal = (e.1 / length, -e.0 / length);
+            let distance = dot(normal, polytope[i]);
+            if distance < closest.1 {
+                closest = (normal, distance);
+                edge_index = i + 1;
+            }
+        }
+        let support = support(a, b, closest.0);
+        if dot(support, closest.0) - closest.1 < EPA_TOLERANCE {
+            break;
+        }
+        polytope.insert(edge_index, support);
+    }
+    let ((nx, ny), depth) = closest;
+    Some(contact(a, (-nx, -ny), depth))
+}
+
+/// Run GJK and return the final simplex if it encloses the origin
+fn gjk_simplex(a: &Polygon, b: &Polygon) -> Option<Vec<(f32, f32)>> {
+    if a.points.is_empty() || b.points.is_empty() {
+        return None;
+    }
+    let (ca, cb) = (average(&a.points), average(&b.points));
+    let mut direction = sub(ca, cb);
+    if direction == (0.0, 0.0) {
+        direction = (1.0, 0.0);
+    }
+    let mut simplex = vec![support(a, b, direction)];
+    direction = (-simplex[0].0, -simplex[0].1);
+    for _ in 0..MAX_ITERATIONS {
+        if direction == (0.0, 0.0) {
+            return Some(simplex);
+        }
+        let point = support(a, b, direction);
+        if dot(point, direction) < 0.0 {
+            return None;
+        }
+        simplex.push(point);
+        if reduce_simplex(&mut simplex, &mut direction) {
+            return Some(simplex);
+        }
+    }
+    None
+}
+
+/// Drop the simplex points not needed to approach the origin and update the search
+/// direction, returning true if the simplex already contains the origin
+fn reduce_simplex(simplex: &mut Vec<(f32, f32)>, direction: &mut (f32, f32)) -> bool {
+    let last = simplex[simplex.len() - 1];
+    let to_origin = (-last.0, -last.1);
+    if simplex.len() == 2 {
+        let ab = sub(simplex[0], last);
+        if dot(ab, to_origin) > 0.0 {
+            *direction = perpendicular_towards(ab, to_origin);
+            // A zero direction means the origin lies on the segment
+            return *direction == (0.0, 0.0);
+        }
+        *simplex = vec![last];
+        *direction = to_origin;
+        return false;
+    }
+    let (b, c) = (simplex[1], simplex[0]);
+    let (ab, ac) = (sub(b, last), sub(c, last));
+    let ab_normal = perpendicular_towards(ab, (-ac.0, -ac.1));
+    let ac_normal = perpendicular_towards(ac, (-ab.0, -ab.1));
+    if dot(ab_normal, to_origin) > 0.0 {
+        *simplex = vec![b, last];
+        *direction = ab_normal;
+        false
+    } else if dot(ac_normal, to_origin) > 0.0 {
+        *simplex = vec![c, last];
+        *direction = ac_normal;
+        false
+    } else {
+        true
+    }
+}
+
+/// Return the point of the Minkowski difference a - b furthest along direction
+fn support(a: &Polygon, b: &Polygon, direction: (f32, f32)) -> (f32, f32) {
+    let pa = furthest(&a.points, direction);
+    let pb = furthest(&b.points, (-direction.0, -direction.1));
+    (pa.x - pb.x, pa.y - pb.y)
+}
+
+fn furthest(points: &[Point], direction: (f32, f32)) -> Point {
+    *points
+        .iter()
+        .max_by(|p, q| {
+            dot((p.x, p.y), direction)
+                .partial_cmp(&dot((q.x, q.y), direction))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap()
+}
+
+/// Build the contact of polygon a resolved along normal by depth. The contact point is the
+/// deepest point of a moved back onto the surface of b.
+fn contact(a: &Polygon, normal: (f32, f32), depth: f32) -> Contact {
+    let deepest = furthest(&a.points, (-normal.0, -normal.1));
+    let point = Point {
+        x: deepest.x + normal.0 * depth,
+        y: deepest.y + normal.1 * depth,
+    };
+    Contact {
+        point,
+        normal: Vector::new(
+            point,
+            Point {
+                x: point.x + normal.0,
+                y: point.y + normal.1,
+            },
+        ),
+        depth,
+    }
+}
+
+fn project(points: &[Point], axis: (f32, f32)) -> (f32, f32) {
+    points
+        .iter()
+        .map(|p| dot((p.x, p.y), axis))
+        .fold((f32::MAX, f32::MIN), |(min, max), d| {
+            (min.min(d), max.max(d))
+        })
+}
+
+fn average(points: &[Point]) -> (f32, f32) {
+    let n = points.len() as f32;
+    let (x, y) = points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+    (x / n, y / n)
+}
+
+/// Return a vector perpendicular to v pointing to the same side as target
+fn perpendicular_towards(v: (f32, f32), target: (f32, f32)) -> (f32, f32) {
+    let n = (-v.1, v.0);
+    if dot(n, target) < 0.0 {
+        (-n.0, -n.1)
+    } else {
+        n
+    }
+}
+
+fn sub(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+fn dot(a: (f32, f32), b: (f32, f32)) -> f32 {
+    a.0 * b.0 + a.1 * b.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::square;
+
+    #[test]
+    fn test_sat_and_epa_agree() {
+        let a = square(0.0, 0.0, 2.0);
+        let b = square(1.5, 0.5, 2.0);
+        let by_sat = sat(&a, &b).unwrap();
+        let by_epa = gjk_epa(&a, &b).unwrap();
+        assert!((by_sat.depth - 0.5).abs() < 1e-5);
+        assert!((by_epa.depth - 0.5).abs() < 1e-4);
+        assert_eq!((-1.0, 0.0), (by_sat.normal.dx(), by_sat.normal.dy()));
+        assert!((by_epa.normal.dx() + 1.0).abs() < 1e-4);
+        assert!(by_epa.normal.dy().abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_separated_polygons() {
+        let a = square(0.0, 0.0, 1.0);
+        let triangle = Polygon::new(vec![
+            Point { x: 3.0, y: 0.0 },
+            Point { x: 2.0, y: 3.0 },
+            Point { x: 1.5, y: 1.4 },
+        ]);
+        assert!(!gjk(&a, &triangle));
+        assert_eq!(None, sat(&a, &triangle));
+        assert_eq!(None, gjk_epa(&a, &triangle));
+        assert!(gjk(&a, &square(0.5, 0.5, 0.1)));
+    }
+}
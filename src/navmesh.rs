@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::mesh::in_triangle;
+use crate::{orientation, Mesh, Point, Polygon, PolygonWithHoles, Polyline, Segment};
+
+/// Walkable area made of triangles, for finding paths between points. Triangles sharing an
+/// edge are neighbors; the path is searched with A* over the triangles and then pulled
+/// tight around the corners with the funnel algorithm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavMesh {
+    mesh: Mesh,
+    /// Triangle across the edge from corner k to corner k + 1 of each triangle
+    neighbors: Vec<[Option<usize>; 3]>,
+}
+
+impl NavMesh {
+    /// Return the navigation mesh over the triangles. Triangles are neighbors if they share
+    /// the indices of an edge.
+    pub fn new(mut mesh: Mesh) -> NavMesh {
+        mesh.orient_counter_clockwise();
+        let count = mesh.indices.len() / 3;
+        let mut edges: HashMap<(u32, u32), (usize, usize)> = HashMap::new();
+        let mut neighbors = vec![[None; 3]; count];
+        for t in 0..count {
+            for k in 0..3 {
+                let (a, b) = (mesh.indices[3 * t + k], mesh.indices[3 * t + (k + 1) % 3]);
+                match edges.remove(&(b, a)) {
+                    Some((other, j)) => {
+                        neighbors[t][k] = Some(other);
+                        neighbors[other][j] = Some(t);
+                    }
+                    None => {
+                        edges.insert((a, b), (t, k));
+                    }
+                }
+            }
+        }
+        NavMesh { mesh, neighbors }
+    }
+
+    pub fn mesh(&self) -> &Mesh {
+        &self.mesh
+    }
+
+    /// Return the index of a triangle containing the point, None if it is outside
+    pub fn triangle_containing(&self, point: Point) -> Option<usize> {
+        (0..self.neighbors.len()).find(|t| {
+            let [a, b, c] = self.corners(*t);
+            in_triangle(point, a, b, c)
+        })
+    }
+
+    /// Return the shortest path found from start to goal, None if either point is outside
+    /// the mesh or they are not connected. A* picks the triangles by the distances between
+    /// their centers, so the path is short but not always the shortest possible.
+    pub fn find_path(&self, start: Point, goal: Point) -> Option<Polyline> {
+        let first = self.triangle_containing(start)?;
+        let last = self.triangle_containing(goal)?;
+        let corridor = self.corridor(first, last, goal)?;
+        // Portals are the shared edges, as seen walking from start towards goal
+        let mut portals = vec![(start, start)];
+        for pair in corridor.windows(2) {
+            let k = (0..3).find(|k| self.neighbors[pair[0]][*k] == Some(pair[1]))?;
+            let corners = self.corners(pair[0]);
+            portals.push((corners[(k + 1) % 3], corners[k]));
+        }
+        portals.push((goal, goal));
+        Some(Polyline::new(funnel(&portals)))
+    }
+
+    /// A* over the triangles, return the triangles walked from first to last
+    fn corridor(&self, first: usize, last: usize, goal: Point) -> Option<Vec<usize>> {
+        let centers: Vec<Point> = (0..self.neighbors.len())
+            .map(|t| {
+                let [a, b, c] = self.corners(t);
+                Point {
+                    x: (a.x + b.x + c.x) / 3.0,
+                    y: (a.y + b.y + c.y) / 3.0,
+                }
+            })
+            .collect();
+        let distance = |a: Point, b: Point| Segment::new(a, b).length();
+        let mut cost = vec![f32::INFINITY; centers.len()];
+        let mut came_from = vec![None; centers.len()];
+        let mut open = BinaryHeap::new();
+        cost[first] = 0.0;
+        open.push(Open {
+            estimate: distance(centers[first], goal),
+            triangle: first,
+        });
+        while let Some(Open { triangle, .. }) = open.pop() {
+            if triangle == last {
+                let mut corridor = vec![last];
+                while let Some(previous) = came_from[*corridor.last().unwrap()] {
+                    corridor.push(previous);
+                }
+                corridor.reverse();
+                return Some(corridor);
+            }
+            for next in self.neighbors[triangle].iter().flatten() {
+                let step = cost[triangle] + distance(centers[triangle], centers[*next]);
+                if step < cost[*next] {
+                    cost[*next] = step;
+                    came_from[*next] = Some(triangle);
+                    open.push(Open {
+                        estimate: step + distance(centers[*next], goal),
+                        triangle: *next,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    fn corners(&self, triangle: usize) -> [Point; 3] {
+        let i = &self.mesh.indices[3 * triangle..3 * triangle + 3];
+        [
+            self.mesh.vertices[i[0] as usize],
+            self.mesh.vertices[i[1] as usize],
+            self.mesh.vertices[i[2] as usize],
+        ]
+    }
+}
+
+impl From<&Polygon> for NavMesh {
+    fn from(polygon: &Polygon) -> NavMesh {
+        NavMesh::new(polygon.triangulate())
+    }
+}
+
+impl From<&PolygonWithHoles> for NavMesh {
+    fn from(polygon: &PolygonWithHoles) -> NavMesh {
+        NavMesh::new(polygon.triangulate())
+    }
+}
+
+/// Triangle waiting in the A* queue, ordered so the lowest estimate comes first
+#[derive(Copy, Clone)]
+struct Open {
+    estimate: f32,
+    triangle: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Open) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Open) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Open) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+/// Pull the path through the (left, right) portals tight with the simple stupid funnel
+/// algorithm. The first and last portal are the start and goal point.
+fn funnel(portals: &[(Point, Point)]) -> Vec<Point> {
+    let mut apex = portals[0].0;
+    let mut path = vec![apex];
+    let (mut left, mut right) = portals[0];
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+        if orientation(apex, right, next_right) >= 0.0 {
+            if apex == right || orientation(apex, left, next_right) < 0.0 {
+                right = next_right;
+                right_index = i;
+            } else {
+                // The right side crossed over the left, the left point becomes a corner
+                apex = left;
+                path.push(apex);
+                right = apex;
+                right_index = left_index;
+                i = left_index + 1;
+                continue;
+            }
+        }
+        if orientation(apex, left, next_left) <= 0.0 {
+            if apex == left || orientation(apex, right, next_left) > 0.0 {
+                left = next_left;
+                left_index = i;
+            } else {
+                apex = right;
+                path.push(apex);
+                left = apex;
+                left_index = right_index;
+                i = right_index + 1;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    let goal = portals[portals.len() - 1].0;
+    if path.last() != Some(&goal) {
+        path.push(goal);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_path_around_corners() {
+        let u_shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(6.0, 0.0),
+            p(6.0, 4.0),
+            p(4.0, 4.0),
+            p(4.0, 1.0),
+            p(2.0, 1.0),
+            p(2.0, 4.0),
+            p(0.0, 4.0),
+        ]);
+        let navmesh = NavMesh::from(&u_shape);
+        let path = navmesh.find_path(p(1.0, 3.0), p(5.0, 3.0)).unwrap();
+        assert_eq!(
+            vec![p(1.0, 3.0), p(2.0, 1.0), p(4.0, 1.0), p(5.0, 3.0)],
+            path.points
+        );
+        let straight = navmesh.find_path(p(0.5, 0.5), p(5.5, 0.5)).unwrap();
+        assert_eq!(vec![p(0.5, 0.5), p(5.5, 0.5)], straight.points);
+        assert_eq!(None, navmesh.find_path(p(1.0, 3.0), p(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_path_around_hole() {
+        let square = |min: f32, max: f32| {
+            Polygon::new(vec![p(min, min), p(max, min), p(max, max), p(min, max)])
+        };
+        let room = PolygonWithHoles::new(square(0.0, 10.0), vec![square(4.0, 6.0)]);
+        let navmesh = NavMesh::from(&room);
+        let path = navmesh.find_path(p(1.0, 5.0), p(9.0, 5.0)).unwrap();
+        assert_eq!(4, path.points.len());
+        let expected = 2.0 * 3.0f32.hypot(1.0) + 2.0;
+        assert!((path.length() - expected).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Point, Polyline, Tolerance};
+
+/// Side of a directed polyline, seen when walking from its first to its last point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OffsetSide {
+    Left,
+    Right,
+}
+
+/// Longest allowed miter as a multiple of the offset distance, the default of SVG. Sharper
+/// outer corners are beveled.
+const MITER_LIMIT: f32 = 4.0;
+
+impl Polyline {
+    /// Return the parallel polyline at distance on the given side. Outer corners are
+    /// mitered, or beveled when the miter would exceed four times the distance, inner
+    /// corners are cut at the crossing of the neighboring offset edges. Very sharp inner
+    /// corners and distances above the local radius of curvature can produce loops.
+    pub fn offset(&self, distance: f32, side: OffsetSide) -> Polyline {
+        let points = Tolerance::default().dedup(&self.points);
+        if points.len() < 2 {
+            return Polyline::new(points);
+        }
+        let distance = match side {
+            OffsetSide::Left => distance,
+            OffsetSide::Right => -distance,
+        };
+        // Left unit normal and direction of every edge
+        let edges: Vec<((f32, f32), (f32, f32))> = points
+            .windows(2)
+            .map(|w| {
+                let (dx, dy) = (w[1].x - w[0].x, w[1].y - w[0].y);
+                let length = (dx * dx + dy * dy).sqrt();
+                ((-dy / length, dx / length), (dx / length, dy / length))
+            })
+            .collect();
+        let shift = |p: Point, n: (f32, f32)| Point {
+            x: p.x + n.0 * distance,
+            y: p.y + n.1 * distance,
+        };
+
+        let mut result = vec![shift(points[0], edges[0].0)];
+        for (i, corner) in points.iter().enumerate().take(points.len() - 1).skip(1) {
+            let ((n1, d1), (n2, d2)) = (edges[i - 1], edges[i]);
+            let (a, b) = (shift(*corner, n1), shift(*corner, n2));
+            let turn = d1.0 * d2.1 - d1.1 * d2.0;
+            if turn.abs() < 1e-6 {
+                result.push(a);
+                if d1.0 * d2.0 + d1.1 * d2.1 < 0.0 {
+                    // The line doubles back, go around the end
+                    result.push(b);
+                }
+                continue;
+            }
+            // Crossing of the offset edges, a + d1 * s = b + d2 * t
+            let s = ((b.x - a.x) * d2.1 - (b.y - a.y) * d2.0) / turn;
+            let miter = Point {
+                x: a.x + d1.0 * s,
+                y: a.y + d1.1 * s,
+            };
+            let outer = turn * distance < 0.0;
+            let miter_length = ((miter.x - corner.x).powi(2) + (miter.y - corner.y).powi(2)).sqrt();
+            if outer && miter_length > MITER_LIMIT * distance.abs() {
+                result.push(a);
+                result.push(b);
+            } else {
+                result.push(miter);
+            }
+        }
+        let last = points.len() - 1;
+        result.push(shift(points[last], edges[last - 1].0));
+        Polyline::new(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn close(a: Point, b: Point) -> bool {
+        (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_corner_offsets() {
+        let road = Polyline::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(10.0, -10.0)]);
+        let left = road.offset(1.0, OffsetSide::Left);
+        let expected = [p(0.0, 1.0), p(11.0, 1.0), p(11.0, -10.0)];
+        assert!(left
+            .points
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| close(*a, *b)));
+        let right = road.offset(1.0, OffsetSide::Right);
+        let expected = [p(0.0, -1.0), p(9.0, -1.0), p(9.0, -10.0)];
+        assert!(right
+            .points
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| close(*a, *b)));
+    }
+
+    #[test]
+    fn test_sharp_corner_is_beveled() {
+        let hairpin = Polyline::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(0.0, -1.0)]);
+        let outer = hairpin.offset(1.0, OffsetSide::Left);
+        assert_eq!(4, outer.points.len());
+        assert!(close(p(10.0, 1.0), outer.points[1]));
+        let straight = Polyline::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 0.0), p(3.0, 0.0)]);
+        let offset = straight.offset(2.0, OffsetSide::Right);
+        assert!(offset.points.iter().all(|q| (q.y + 2.0).abs() < 1e-6));
+    }
+}
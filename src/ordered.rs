@@ -0,0 +1,99 @@
+use crate::Point;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// Point with a total order, so it can be used as key of a HashMap, HashSet or BTreeMap.
+/// Points are ordered by x and then by y, the order of a left to right sweep line. Negative
+/// zero equals zero, and NaN coordinates sort after all numbers and equal each other.
+#[derive(Debug, Copy, Clone)]
+pub struct OrderedPoint(pub Point);
+
+impl OrderedPoint {
+    /// Coordinates with negative zero and all NaN values collapsed to one representation
+    fn key(&self) -> (f32, f32) {
+        (canonical(self.0.x), canonical(self.0.y))
+    }
+}
+
+fn canonical(value: f32) -> f32 {
+    if value.is_nan() {
+        f32::NAN
+    } else if value == 0.0 {
+        0.0
+    } else {
+        value
+    }
+}
+
+impl From<Point> for OrderedPoint {
+    fn from(point: Point) -> OrderedPoint {
+        OrderedPoint(point)
+    }
+}
+
+impl From<OrderedPoint> for Point {
+    fn from(point: OrderedPoint) -> Point {
+        point.0
+    }
+}
+
+impl PartialEq for OrderedPoint {
+    fn eq(&self, other: &OrderedPoint) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedPoint {}
+
+impl PartialOrd for OrderedPoint {
+    fn partial_cmp(&self, other: &OrderedPoint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedPoint {
+    fn cmp(&self, other: &OrderedPoint) -> Ordering {
+        let ((ax, ay), (bx, by)) = (self.key(), other.key());
+        ax.total_cmp(&bx).then(ay.total_cmp(&by))
+    }
+}
+
+impl Hash for OrderedPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let (x, y) = self.key();
+        x.to_bits().hash(state);
+        y.to_bits().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashSet};
+
+    fn op(x: f32, y: f32) -> OrderedPoint {
+        OrderedPoint(Point { x, y })
+    }
+
+    #[test]
+    fn test_sweep_order() {
+        let set: BTreeSet<OrderedPoint> =
+            vec![op(1.0, 0.0), op(0.0, 2.0), op(0.0, -1.0), op(f32::NAN, 0.0)]
+                .into_iter()
+                .collect();
+        let ordered: Vec<(f32, f32)> = set.iter().map(|p| (p.0.x, p.0.y)).collect();
+        assert_eq!((0.0, -1.0), ordered[0]);
+        assert_eq!((0.0, 2.0), ordered[1]);
+        assert_eq!((1.0, 0.0), ordered[2]);
+        assert!(ordered[3].0.is_nan());
+    }
+
+    #[test]
+    fn test_hash_deduplicates() {
+        let set: HashSet<OrderedPoint> = vec![op(0.0, 1.0), op(-0.0, 1.0), op(1.0, 1.0)]
+            .into_iter()
+            .collect();
+        assert_eq!(2, set.len());
+        assert_eq!(op(f32::NAN, 0.0), op(-f32::NAN, 0.0));
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{convex_hull, transcendental, Covariance, Point};
+
+/// Rectangle around center, rotated by rotation degrees counter clockwise. width is
+/// measured along the rotated x axis, height along the rotated y axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OrientedRect {
+    pub center: Point,
+    pub width: f32,
+    pub height: f32,
+    pub rotation: f32,
+}
+
+impl OrientedRect {
+    pub fn new(center: Point, width: f32, height: f32, rotation: f32) -> OrientedRect {
+        OrientedRect {
+            center,
+            width,
+            height,
+            rotation,
+        }
+    }
+
+    /// Return the corners in counter clockwise order, starting at the lower left corner of
+    /// the unrotated rectangle
+    pub fn corners(&self) -> [Point; 4] {
+        let (w, h) = (self.width / 2.0, self.height / 2.0);
+        [
+            self.world(-w, -h),
+            self.world(w, -h),
+            self.world(w, h),
+            self.world(-w, h),
+        ]
+    }
+
+    /// Return true if the point lies inside or on the rectangle
+    pub fn contains(&self, point: Point) -> bool {
+        let (sin, cos) = transcendental::sin_cos(self.rotation.to_radians());
+        let (dx, dy) = (point.x - self.center.x, point.y - self.center.y);
+        let (x, y) = (cos * dx + sin * dy, -sin * dx + cos * dy);
+        x.abs() <= self.width / 2.0 && y.abs() <= self.height / 2.0
+    }
+
+    pub fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
+    fn world(&self, x: f32, y: f32) -> Point {
+        let (sin, cos) = transcendental::sin_cos(self.rotation.to_radians());
+        Point {
+            x: self.center.x + cos * x - sin * y,
+            y: self.center.y + sin * x + cos * y,
+        }
+    }
+}
+
+/// Return the bounding rectangle of the points aligned with their principal axes, found by
+/// principal component analysis. width lies along the axis of largest variance. This is a
+/// fast estimate of the footprint, not the minimum area rectangle. None if there are no
+/// points.
+pub fn oriented_bbox(points: &[Point]) -> Option<OrientedRect> {
+    let rotation = Covariance::of(points)?.principal_angle();
+    Some(fitted(points, rotation))
+}
+
+/// Return the smallest rectangle of any rotation around the points. One side of it lies on
+/// an edge of the convex hull, so only the hull edge directions are tried. None if there
+/// are no points.
+pub fn min_area_rect(points: &[Point]) -> Option<OrientedRect> {
+    let hull = convex_hull(points);
+    if hull.len() < 3 {
+        let rotation = match hull.as_slice() {
+            [a, b] => transcendental::atan2(b.y - a.y, b.x - a.x).to_degrees(),
+            _ => 0.0,
+        };
+        return hull.first().map(|_| fitted(&hull, rotation));
+    }
+    (0..hull.len())
+        .map(|i| {
+            let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+            fitted(
+                &hull,
+                transcendental::atan2(b.y - a.y, b.x - a.x).to_degrees(),
+            )
+        })
+        .min_by(|r, s| r.area().total_cmp(&s.area()))
+}
+
+/// Return the rectangle rotated by rotation degrees that tightly bounds the points
+fn fitted(points: &[Point], rotation: f32) -> OrientedRect {
+    let (sin, cos) = transcendental::sin_cos(rotation.to_radians());
+    let (mut min_u, mut max_u) = (f32::MAX, f32::MIN);
+    let (mut min_v, mut max_v) = (f32::MAX, f32::MIN);
+    for p in points {
+        let (u, v) = (cos * p.x + sin * p.y, -sin * p.x + cos * p.y);
+        min_u = min_u.min(u);
+        max_u = max_u.max(u);
+        min_v = min_v.min(v);
+        max_v = max_v.max(v);
+    }
+    let (u, v) = ((min_u + max_u) / 2.0, (min_v + max_v) / 2.0);
+    OrientedRect::new(
+        Point {
+            x: cos * u - sin * v,
+            y: sin * u + cos * v,
+        },
+        max_u - min_u,
+        max_v - min_v,
+        rotation,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_oriented_bbox_of_rotated_rectangle() {
+        let truth = OrientedRect::new(p(3.0, -1.0), 6.0, 2.0, 30.0);
+        let mut points = truth.corners().to_vec();
+        points.push(truth.center);
+        let bbox = oriented_bbox(&points).unwrap();
+        assert!((bbox.center.x - 3.0).abs() < 1e-4);
+        assert!((bbox.center.y + 1.0).abs() < 1e-4);
+        assert!((bbox.width - 6.0).abs() < 1e-4);
+        assert!((bbox.height - 2.0).abs() < 1e-4);
+        assert!((bbox.rotation - 30.0).abs() < 1e-3);
+        assert!(bbox.contains(p(5.0, 0.0)));
+        assert_eq!(None, oriented_bbox(&[]));
+    }
+
+    #[test]
+    fn test_contains() {
+        let rect = OrientedRect::new(p(0.0, 0.0), 4.0, 1.0, 90.0);
+        assert!(rect.contains(p(0.0, 1.9)));
+        assert!(!rect.contains(p(1.9, 0.0)));
+        assert!((rect.area() - 4.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_min_area_rect() {
+        let truth = OrientedRect::new(p(1.0, 2.0), 8.0, 1.0, 45.0);
+        let mut points = truth.corners().to_vec();
+        points.push(truth.world(3.0, 0.4));
+        let rect = min_area_rect(&points).unwrap();
+        assert!((rect.area() - 8.0).abs() < 1e-3);
+        assert!((rect.rotation.rem_euclid(90.0) - 45.0).abs() < 1e-3);
+        assert!((rect.center.x - 1.0).abs() < 1e-4);
+        let line = min_area_rect(&[p(0.0, 0.0), p(3.0, 4.0)]).unwrap();
+        assert!((line.width - 5.0).abs() < 1e-5 && line.height.abs() < 1e-5);
+        assert_eq!(None, min_area_rect(&[]));
+    }
+}
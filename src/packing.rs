@@ -0,0 +1,188 @@
+use crate::{Point, Rect};
+
+/// Position of one packed rectangle. If rotated is set the rectangle was turned by 90
+/// degrees, so rect is height wide and width tall.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Placement {
+    pub rect: Rect,
+    pub rotated: bool,
+}
+
+/// Skyline packer filling a bin from its bottom. The upper outline of the placed rectangles
+/// is kept as horizontal pieces, and every rectangle goes where its top ends lowest, ties
+/// broken by the leftmost position. Space below overhangs is given up, which keeps inserts
+/// fast enough for texture atlases built at load time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Skyline {
+    bin: Rect,
+    allow_rotation: bool,
+    /// (x, height, width) of each piece, left to right, covering the bin width
+    pieces: Vec<(f32, f32, f32)>,
+}
+
+impl Skyline {
+    pub fn new(bin: Rect) -> Skyline {
+        Skyline {
+            bin,
+            allow_rotation: true,
+            pieces: vec![(bin.min.x, bin.min.y, bin.width())],
+        }
+    }
+
+    /// Set whether rectangles may be turned by 90 degrees to fit better, true by default
+    pub fn allow_rotation(&mut self, allow: bool) -> &mut Self {
+        self.allow_rotation = allow;
+        self
+    }
+
+    /// Place a rectangle of the size, None if it does not fit anywhere
+    pub fn insert(&mut self, width: f32, height: f32) -> Option<Placement> {
+        let mut best: Option<(usize, f32, f32, f32, bool)> = None;
+        let mut orientations = vec![(width, height, false)];
+        if self.allow_rotation && width != height {
+            orientations.push((height, width, true));
+        }
+        for (w, h, rotated) in orientations {
+            for i in 0..self.pieces.len() {
+                if let Some(y) = self.fit(i, w, h) {
+                    let better = match best {
+                        Some((j, best_y, _, best_h, _)) => {
+                            let (top, best_top) = (y + h, best_y + best_h);
+                            top < best_top
+                                || (top == best_top && self.pieces[i].0 < self.pieces[j].0)
+                        }
+                        None => true,
+                    };
+                    if better {
+                        best = Some((i, y, w, h, rotated));
+                    }
+                }
+            }
+        }
+        let (i, y, w, h, rotated) = best?;
+        let x = self.pieces[i].0;
+        self.raise(i, x, y + h, w);
+        Some(Placement {
+            rect: Rect::new(Point { x, y }, Point { x: x + w, y: y + h }),
+            rotated,
+        })
+    }
+
+    /// Return the bottom of a w by h rectangle whose left edge is at piece i, None if it
+    /// would leave the bin
+    fn fit(&self, i: usize, w: f32, h: f32) -> Option<f32> {
+        let x = self.pieces[i].0;
+        if x + w > self.bin.max.x {
+            return None;
+        }
+        let mut y = self.bin.min.y;
+        for &(start, height, _) in &self.pieces[i..] {
+            if start >= x + w {
+                break;
+            }
+            y = y.max(height);
+        }
+        if y + h > self.bin.max.y {
+            None
+        } else {
+            Some(y)
+        }
+    }
+
+    /// Replace the outline under [x, x + w] by a piece at height top
+    fn raise(&mut self, i: usize, x: f32, top: f32, w: f32) {
+        let end = x + w;
+        self.pieces.insert(i, (x, top, w));
+        let k = i + 1;
+        while k < self.pieces.len() {
+            let (start, height, width) = self.pieces[k];
+            if start >= end {
+                break;
+            }
+            if start + width <= end {
+                self.pieces.remove(k);
+            } else {
+                self.pieces[k] = (end, height, start + width - end);
+                break;
+            }
+        }
+        // Merge neighbors of equal height so later fits see one wide piece
+        let mut k = 0;
+        while k + 1 < self.pieces.len() {
+            if self.pieces[k].1 == self.pieces[k + 1].1 {
+                self.pieces[k].2 += self.pieces[k + 1].2;
+                self.pieces.remove(k + 1);
+            } else {
+                k += 1;
+            }
+        }
+    }
+}
+
+/// Return a placement for every (width, height) in the bin, in the order of sizes, None for
+/// those that did not fit. Larger rectangles are placed first, which packs much tighter
+/// than the input order, and rotation by 90 degrees is allowed.
+pub fn pack(sizes: &[(f32, f32)], bin: &Rect) -> Vec<Option<Placement>> {
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    let longest = |i: &usize| sizes[*i].0.max(sizes[*i].1);
+    order.sort_by(|a, b| longest(b).total_cmp(&longest(a)));
+    let mut skyline = Skyline::new(*bin);
+    let mut placements = vec![None; sizes.len()];
+    for i in order {
+        placements[i] = skyline.insert(sizes[i].0, sizes[i].1);
+    }
+    placements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_pack_without_overlap() {
+        let bin = Rect::new(p(0.0, 0.0), p(10.0, 10.0));
+        let sizes = [
+            (4.0, 4.0),
+            (6.0, 2.0),
+            (2.0, 6.0),
+            (5.0, 5.0),
+            (3.0, 1.0),
+            (20.0, 1.0),
+        ];
+        let placements = pack(&sizes, &bin);
+        assert_eq!(None, placements[5]);
+        let rects: Vec<Rect> = placements.iter().flatten().map(|p| p.rect).collect();
+        assert_eq!(5, rects.len());
+        for (i, a) in rects.iter().enumerate() {
+            assert!(a.min.x >= 0.0 && a.max.x <= 10.0 && a.min.y >= 0.0 && a.max.y <= 10.0);
+            for b in &rects[i + 1..] {
+                assert_eq!(None, a.intersection(b));
+            }
+        }
+        for (placement, size) in placements.iter().zip(&sizes) {
+            if let Some(placement) = placement {
+                let (w, h) = if placement.rotated {
+                    (size.1, size.0)
+                } else {
+                    *size
+                };
+                assert_eq!((w, h), (placement.rect.width(), placement.rect.height()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_skyline_rotation() {
+        let bin = Rect::new(p(0.0, 0.0), p(4.0, 8.0));
+        let mut skyline = Skyline::new(bin);
+        let first = skyline.insert(4.0, 2.0).unwrap();
+        assert_eq!(Rect::new(p(0.0, 0.0), p(4.0, 2.0)), first.rect);
+        // Lying on its side the 6 by 2 piece only fits rotated
+        let second = skyline.insert(6.0, 2.0).unwrap();
+        assert!(second.rotated);
+        assert_eq!(Rect::new(p(0.0, 2.0), p(2.0, 8.0)), second.rect);
+        let mut fixed = Skyline::new(bin);
+        assert_eq!(None, fixed.allow_rotation(false).insert(6.0, 2.0));
+    }
+}
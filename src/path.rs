@@ -0,0 +1,332 @@
+use crate::{
+    transcendental, ArcLength, CircularArc, CubicBezier, Point, QuadraticBezier, Rect, Segment,
+    Vector,
+};
+
+/// Single drawing command of a Path. All drawing commands start at the current point,
+/// which is the end of the previous command, or the origin if nothing has been drawn yet.
+/// Arcs run around center by sweep_angle degrees, counter clockwise if positive.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PathCommand {
+    MoveTo(Point),
+    LineTo(Point),
+    ArcTo {
+        center: Point,
+        sweep_angle: f32,
+    },
+    QuadraticTo {
+        control: Point,
+        end: Point,
+    },
+    CubicTo {
+        control_a: Point,
+        control_b: Point,
+        end: Point,
+    },
+    /// Draw a line back to the point of the last MoveTo
+    Close,
+}
+
+/// Drawn piece of a Path, expressed by the matching curve primitive of this crate.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PathSegment {
+    Line(Segment),
+    Arc(CircularArc),
+    Quadratic(QuadraticBezier),
+    Cubic(CubicBezier),
+}
+
+impl PathSegment {
+    /// Return the point at parameter t in [0, 1] of the underlying primitive
+    pub fn point_at(&self, t: f32) -> Point {
+        match self {
+            PathSegment::Line(s) => Point {
+                x: s.point_a.x + (s.point_b.x - s.point_a.x) * t,
+                y: s.point_a.y + (s.point_b.y - s.point_a.y) * t,
+            },
+            PathSegment::Arc(a) => a.point_at(t),
+            PathSegment::Quadratic(q) => q.point_at(t),
+            PathSegment::Cubic(c) => c.point_at(t),
+        }
+    }
+
+    pub fn start_point(&self) -> Point {
+        self.point_at(0.0)
+    }
+
+    pub fn end_point(&self) -> Point {
+        match self {
+            PathSegment::Line(s) => s.point_b,
+            PathSegment::Arc(a) => a.end_point(),
+            PathSegment::Quadratic(q) => q.end,
+            PathSegment::Cubic(c) => c.end,
+        }
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        match self {
+            PathSegment::Line(s) => s.bounding_box(),
+            PathSegment::Arc(a) => a.bounding_box(),
+            PathSegment::Quadratic(q) => q.bounding_box(),
+            PathSegment::Cubic(c) => c.bounding_box(),
+        }
+    }
+}
+
+impl ArcLength for PathSegment {
+    fn arc_length(&self) -> f32 {
+        match self {
+            PathSegment::Line(s) => s.arc_length(),
+            PathSegment::Arc(a) => a.arc_length(),
+            PathSegment::Quadratic(q) => q.arc_length(),
+            PathSegment::Cubic(c) => c.arc_length(),
+        }
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        match self {
+            PathSegment::Line(l) => l.point_at_distance(s),
+            PathSegment::Arc(a) => a.point_at_distance(s),
+            PathSegment::Quadratic(q) => q.point_at_distance(s),
+            PathSegment::Cubic(c) => c.point_at_distance(s),
+        }
+    }
+}
+
+/// Sequence of drawing commands combining lines, arcs and Bézier curves, possibly split
+/// into several sub paths by MoveTo commands.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+}
+
+impl Path {
+    /// Return an empty path
+    pub fn new() -> Path {
+        Path {
+            commands: Vec::new(),
+        }
+    }
+
+    /// Start a new sub path at point
+    pub fn move_to(&mut self, point: Point) -> &mut Path {
+        self.commands.push(PathCommand::MoveTo(point));
+        self
+    }
+
+    pub fn line_to(&mut self, point: Point) -> &mut Path {
+        self.commands.push(PathCommand::LineTo(point));
+        self
+    }
+
+    /// Draw an arc from the current point around center, sweep_angle is in degrees
+    pub fn arc_to(&mut self, center: Point, sweep_angle: f32) -> &mut Path {
+        self.commands.push(PathCommand::ArcTo {
+            center,
+            sweep_angle,
+        });
+        self
+    }
+
+    pub fn quadratic_to(&mut self, control: Point, end: Point) -> &mut Path {
+        self.commands
+            .push(PathCommand::QuadraticTo { control, end });
+        self
+    }
+
+    pub fn cubic_to(&mut self, control_a: Point, control_b: Point, end: Point) -> &mut Path {
+        self.commands.push(PathCommand::CubicTo {
+            control_a,
+            control_b,
+            end,
+        });
+        self
+    }
+
+    /// Close the current sub path with a line back to its first point
+    pub fn close(&mut self) -> &mut Path {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    /// Return all drawn pieces of the path in order. MoveTo commands produce no segment.
+    pub fn segments(&self) -> Vec<PathSegment> {
+        let origin = Point { x: 0.0, y: 0.0 };
+        let (mut current, mut sub_path_start) = (origin, origin);
+        let mut segments = Vec::new();
+        for command in &self.commands {
+            let segment = match *command {
+                PathCommand::MoveTo(point) => {
+                    current = point;
+                    sub_path_start = point;
+                    continue;
+                }
+                PathCommand::LineTo(point) => PathSegment::Line(Segment::new(current, point)),
+                PathCommand::ArcTo {
+                    center,
+                    sweep_angle,
+                } => {
+                    let start_angle =
+                        transcendental::atan2(current.y - center.y, current.x - center.x)
+                            .to_degrees();
+                    let radius = Vector::new(center, current).length();
+                    PathSegment::Arc(CircularArc::new(center, radius, start_angle, sweep_angle))
+                }
+                PathCommand::QuadraticTo { control, end } => {
+                    PathSegment::Quadratic(QuadraticBezier::new(current, control, end))
+                }
+                PathCommand::CubicTo {
+                    control_a,
+                    control_b,
+                    end,
+                } => PathSegment::Cubic(CubicBezier::new(current, control_a, control_b, end)),
+                PathCommand::Close => PathSegment::Line(Segment::new(current, sub_path_start)),
+            };
+            current = segment.end_point();
+            segments.push(segment);
+        }
+        segments
+    }
+
+    /// Return the total drawn length. Jumps between sub paths are not counted.
+    pub fn length(&self) -> f32 {
+        self.segments().iter().map(|s| s.arc_length()).sum()
+    }
+
+    /// Return the point at distance s along the drawn path, None if nothing is drawn.
+    /// s is clamped to [0, length].
+    pub fn point_at_length(&self, s: f32) -> Option<Point> {
+        let segments = self.segments();
+        let mut remaining = s.max(0.0);
+        for segment in &segments {
+            let length = segment.arc_length();
+            if remaining <= length {
+                return Some(segment.point_at_distance(remaining));
+            }
+            remaining -= length;
+        }
+        segments.last().map(|s| s.end_point())
+    }
+
+    /// Return the bounding box of all drawn segments, None if nothing is drawn
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.segments().iter().map(|s| s.bounding_box()).fold(
+            None,
+            |acc: Option<Rect>, r| match acc {
+                None => Some(r),
+                Some(acc) => Some(acc.expand(r.min).expand(r.max)),
+            },
+        )
+    }
+
+    /// Return the path as SVG path data, usable as the d attribute of an SVG path element.
+    /// Arcs are split into pieces of at most 180 degrees, as SVG arcs can not describe full
+    /// circles. A path that starts drawing without a move starts at the origin, which SVG
+    /// needs spelled out as a leading move.
+    pub fn to_svg_path_data(&self) -> String {
+        let mut parts = Vec::new();
+        match self.commands.first() {
+            None | Some(PathCommand::MoveTo(_)) => {}
+            Some(_) => parts.push("M 0 0".to_string()),
+        }
+        let mut current = Point { x: 0.0, y: 0.0 };
+        let mut sub_path_start = current;
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(p) => {
+                    parts.push(format!("M {} {}", p.x, p.y));
+                    sub_path_start = p;
+                    current = p;
+                }
+                PathCommand::LineTo(p) => {
+                    parts.push(format!("L {} {}", p.x, p.y));
+                    current = p;
+                }
+                PathCommand::ArcTo {
+                    center,
+                    sweep_angle,
+                } => {
+                    let start_angle =
+                        transcendental::atan2(current.y - center.y, current.x - center.x)
+                            .to_degrees();
+                    let radius = Vector::new(center, current).length();
+                    let pieces = (sweep_angle.abs() / 180.0).ceil().max(1.0);
+                    let sweep_flag = if sweep_angle >= 0.0 { 1 } else { 0 };
+                    let arc = CircularArc::new(center, radius, start_angle, sweep_angle);
+                    for i in 1..=pieces as u32 {
+                        let p = arc.point_at(i as f32 / pieces);
+                        parts.push(format!(
+                            "A {} {} 0 0 {} {} {}",
+                            radius, radius, sweep_flag, p.x, p.y
+                        ));
+                        current = p;
+                    }
+                }
+                PathCommand::QuadraticTo { control, end } => {
+                    parts.push(format!("Q {} {} {} {}", control.x, control.y, end.x, end.y));
+                    current = end;
+                }
+                PathCommand::CubicTo {
+                    control_a,
+                    control_b,
+                    end,
+                } => {
+                    parts.push(format!(
+                        "C {} {} {} {} {} {}",
+                        control_a.x, control_a.y, control_b.x, control_b.y, end.x, end.y
+                    ));
+                    current = end;
+                }
+                PathCommand::Close => {
+                    parts.push(String::from("Z"));
+                    current = sub_path_start;
+                }
+            }
+        }
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_closed_path_length_and_point() {
+        let mut path = Path::new();
+        path.move_to(p(0.0, 0.0))
+            .line_to(p(4.0, 0.0))
+            .line_to(p(4.0, 3.0))
+            .close();
+        assert_eq!(12.0, path.length());
+        assert_eq!(Some(p(4.0, 1.0)), path.point_at_length(5.0));
+        assert_eq!(Some(p(0.0, 0.0)), path.point_at_length(50.0));
+        assert_eq!("M 0 0 L 4 0 L 4 3 Z", path.to_svg_path_data());
+    }
+
+    #[test]
+    fn test_arc_path_bounding_box() {
+        let mut path = Path::new();
+        path.move_to(p(1.0, 0.0)).arc_to(p(0.0, 0.0), 360.0);
+        assert!((path.length() - 2.0 * std::f32::consts::PI).abs() < 1e-5);
+        let bbox = path.bounding_box().unwrap();
+        assert!((bbox.min.x + 1.0).abs() < 1e-5);
+        assert!((bbox.min.y + 1.0).abs() < 1e-5);
+        assert!((bbox.max.y - 1.0).abs() < 1e-5);
+        assert_eq!(2, path.to_svg_path_data().matches('A').count());
+        assert_eq!(None, Path::new().bounding_box());
+    }
+
+    #[test]
+    fn test_svg_path_data_starts_with_move() {
+        let mut path = Path::new();
+        path.line_to(p(4.0, 0.0)).line_to(p(4.0, 3.0));
+        assert_eq!("M 0 0 L 4 0 L 4 3", path.to_svg_path_data());
+        assert_eq!("", Path::new().to_svg_path_data());
+    }
+}
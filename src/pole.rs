@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{Centroid, Circle, Point, Polygon, Rect, SignedDistance};
+
+impl Polygon {
+    /// Return the largest circle inside the polygon, centered on the pole of inaccessibility,
+    /// the interior point farthest from the boundary. The bounding box is refined into ever
+    /// smaller cells until no cell can improve the radius by more than precision, as in the
+    /// polylabel algorithm. None if the polygon has fewer than three points.
+    pub fn pole_of_inaccessibility(&self, precision: f32) -> Option<Circle> {
+        if self.points.len() < 3 {
+            return None;
+        }
+        let bounds = Rect::from_points(&self.points)?;
+        let size = bounds.width().min(bounds.height());
+        if size == 0.0 {
+            return Some(Circle::new(self.points[0], 0.0));
+        }
+        let precision = precision.max(size * 1e-6);
+
+        let mut cells = BinaryHeap::new();
+        let half = size / 2.0;
+        let mut x = bounds.min.x;
+        while x < bounds.max.x {
+            let mut y = bounds.min.y;
+            while y < bounds.max.y {
+                cells.push(Cell::new(
+                    self,
+                    Point {
+                        x: x + half,
+                        y: y + half,
+                    },
+                    half,
+                ));
+                y += size;
+            }
+            x += size;
+        }
+        let mut best = Cell::new(self, self.centroid(), 0.0);
+        let center = Cell::new(
+            self,
+            Point {
+                x: (bounds.min.x + bounds.max.x) / 2.0,
+                y: (bounds.min.y + bounds.max.y) / 2.0,
+            },
+            0.0,
+        );
+        if center.distance > best.distance {
+            best = center;
+        }
+
+        while let Some(cell) = cells.pop() {
+            if cell.distance > best.distance {
+                best = cell;
+            }
+            if cell.potential - best.distance <= precision {
+                continue;
+            }
+            let half = cell.half / 2.0;
+            for (dx, dy) in &[(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+                let center = Point {
+                    x: cell.center.x + dx * half,
+                    y: cell.center.y + dy * half,
+                };
+                cells.push(Cell::new(self, center, half));
+            }
+        }
+        Some(Circle::new(best.center, best.distance.max(0.0)))
+    }
+}
+
+/// Square search cell, ordered by the best distance any point inside could reach
+#[derive(Copy, Clone)]
+struct Cell {
+    center: Point,
+    half: f32,
+    distance: f32,
+    potential: f32,
+}
+
+impl Cell {
+    fn new(polygon: &Polygon, center: Point, half: f32) -> Cell {
+        // Distance to the boundary, positive inside
+        let distance = -polygon.sdf(center);
+        Cell {
+            center,
+            half,
+            distance,
+            potential: distance + half * std::f32::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Cell) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Cell) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Cell) -> Ordering {
+        self.potential.total_cmp(&other.potential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_rectangle() {
+        let rect = Polygon::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(10.0, 4.0), p(0.0, 4.0)]);
+        let circle = rect.pole_of_inaccessibility(0.01).unwrap();
+        assert!((circle.radius - 2.0).abs() < 0.01);
+        assert!((circle.center.y - 2.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_l_shape_avoids_centroid() {
+        let shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(10.0, 0.0),
+            p(10.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 10.0),
+            p(0.0, 10.0),
+        ]);
+        let circle = shape.pole_of_inaccessibility(0.001).unwrap();
+        assert!(shape.contains(circle.center));
+        assert!(circle.radius > 0.5 && circle.radius < 0.75);
+        assert_eq!(
+            None,
+            Polygon::new(vec![p(0.0, 0.0)]).pole_of_inaccessibility(0.1)
+        );
+    }
+}
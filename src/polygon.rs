@@ -0,0 +1,34 @@
+use crate::{Point, Segment};
+
+/// Closed polygon through all points in order, the last point connects back to the first.
+/// The points may be ordered clockwise or counter clockwise.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polygon {
+    pub points: Vec<Point>,
+}
+
+impl Polygon {
+    pub fn new(points: Vec<Point>) -> Polygon {
+        Polygon { points }
+    }
+
+    /// Return true if the point lies inside the polygon, using the even-odd rule
+    pub fn contains(&self, point: Point) -> bool {
+        let mut inside = false;
+        for edge in self.edges() {
+            let (a, b) = (edge.point_a, edge.point_b);
+            if (a.y > point.y) != (b.y > point.y)
+                && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+            {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// Iterate over all edges including the closing edge from the last to the first point
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        let n = self.points.len();
+        (0..n).map(move |i| Segment::new(self.points[i], self.points[(i + 1) % n]))
+    }
+}
@@ -0,0 +1,203 @@
+use crate::mesh::ear_clip;
+use crate::{
+    Area, Centroid, Contains, Mesh, Perimeter, Point, Polygon, Segment, SegmentContact, Tolerance,
+};
+
+/// Polygon with interior rings, like a land parcel around a courtyard. Points inside a hole
+/// are outside the polygon. The holes are expected inside the exterior and apart from each
+/// other; the orientation of all rings is free.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PolygonWithHoles {
+    pub exterior: Polygon,
+    pub holes: Vec<Polygon>,
+}
+
+impl PolygonWithHoles {
+    pub fn new(exterior: Polygon, holes: Vec<Polygon>) -> PolygonWithHoles {
+        PolygonWithHoles { exterior, holes }
+    }
+
+    /// Iterate over the edges of the exterior and then of all holes
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.exterior
+            .edges()
+            .chain(self.holes.iter().flat_map(|h| h.edges()))
+    }
+
+    /// Triangulate by connecting every hole to the exterior with a bridge edge and ear
+    /// clipping the resulting ring. The vertices of the mesh are the exterior points
+    /// followed by the points of each hole.
+    pub fn triangulate(&self) -> Mesh {
+        self.triangulate_with(Tolerance::default())
+    }
+
+    /// Triangulate like triangulate, with bridges that may pass ring vertices within the
+    /// tolerance only where they end
+    pub fn triangulate_with(&self, tolerance: Tolerance) -> Mesh {
+        let mut vertices = self.exterior.points.clone();
+        let mut ring: Vec<usize> = (0..vertices.len()).collect();
+        if self.exterior.signed_area() < 0.0 {
+            ring.reverse();
+        }
+        let mut holes: Vec<Vec<usize>> = Vec::with_capacity(self.holes.len());
+        for hole in &self.holes {
+            let offset = vertices.len();
+            let mut indices: Vec<usize> = (offset..offset + hole.points.len()).collect();
+            // Holes run clockwise, against the exterior
+            if hole.signed_area() > 0.0 {
+                indices.reverse();
+            }
+            vertices.extend_from_slice(&hole.points);
+            holes.push(indices);
+        }
+        // Bridge the hole reaching furthest right first, so later bridges can not cross it
+        let max_x =
+            |hole: &Vec<usize>| hole.iter().map(|i| vertices[*i].x).fold(f32::MIN, f32::max);
+        holes.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+        for (h, hole) in holes.iter().enumerate() {
+            if hole.len() < 3 {
+                continue;
+            }
+            let m = (0..hole.len())
+                .max_by(|a, b| vertices[hole[*a]].x.total_cmp(&vertices[hole[*b]].x))
+                .unwrap();
+            let bridge_end = vertices[hole[m]];
+            let blocking: Vec<Segment> = ring_edges(&vertices, &ring)
+                .chain(holes[h..].iter().flat_map(|r| ring_edges(&vertices, r)))
+                .collect();
+            let distance = |k: &usize| {
+                let v = vertices[ring[*k]];
+                (v.x - bridge_end.x).powi(2) + (v.y - bridge_end.y).powi(2)
+            };
+            let visible = (0..ring.len())
+                .filter(|k| {
+                    let bridge = Segment::new(vertices[ring[*k]], bridge_end);
+                    blocking
+                        .iter()
+                        .all(|edge| !crosses(&bridge, edge, tolerance))
+                })
+                .min_by(|a, b| distance(a).total_cmp(&distance(b)));
+            // Without a visible vertex the input is invalid, take the closest one anyway
+            let k = visible
+                .or_else(|| (0..ring.len()).min_by(|a, b| distance(a).total_cmp(&distance(b))))
+                .unwrap();
+            let mut spliced = ring[..=k].to_vec();
+            spliced.extend((0..=hole.len()).map(|i| hole[(m + i) % hole.len()]));
+            spliced.push(ring[k]);
+            spliced.extend_from_slice(&ring[k + 1..]);
+            ring = spliced;
+        }
+        let indices = ear_clip(&vertices, ring);
+        let mut mesh = Mesh::new(vertices, indices);
+        mesh.orient_counter_clockwise();
+        mesh
+    }
+}
+
+fn ring_edges<'a>(vertices: &'a [Point], ring: &'a [usize]) -> impl Iterator<Item = Segment> + 'a {
+    let n = ring.len();
+    (0..n).map(move |i| Segment::new(vertices[ring[i]], vertices[ring[(i + 1) % n]]))
+}
+
+/// Return true if the segments meet anywhere but at a shared end point
+fn crosses(a: &Segment, b: &Segment, tolerance: Tolerance) -> bool {
+    match a.contact(b, tolerance) {
+        SegmentContact::Disjoint => false,
+        SegmentContact::Touching(point) => ![a.point_a, a.point_b].iter().any(|end| {
+            tolerance.coincident(*end, point)
+                && (tolerance.coincident(*end, b.point_a) || tolerance.coincident(*end, b.point_b))
+        }),
+        SegmentContact::Crossing(_) | SegmentContact::Overlapping(_) => true,
+    }
+}
+
+impl From<Polygon> for PolygonWithHoles {
+    fn from(exterior: Polygon) -> PolygonWithHoles {
+        PolygonWithHoles::new(exterior, Vec::new())
+    }
+}
+
+impl Area for PolygonWithHoles {
+    fn area(&self) -> f32 {
+        self.exterior.area() - self.holes.iter().map(|h| h.area()).sum::<f32>()
+    }
+}
+
+impl Perimeter for PolygonWithHoles {
+    /// Return the length of all rings, exterior and holes
+    fn perimeter(&self) -> f32 {
+        self.exterior.perimeter() + self.holes.iter().map(|h| h.perimeter()).sum::<f32>()
+    }
+}
+
+impl Centroid for PolygonWithHoles {
+    fn centroid(&self) -> Point {
+        let exterior_area = self.exterior.area();
+        let outer = Centroid::centroid(&self.exterior);
+        let (mut x, mut y) = (outer.x * exterior_area, outer.y * exterior_area);
+        for hole in &self.holes {
+            let (area, center) = (hole.area(), Centroid::centroid(hole));
+            x -= center.x * area;
+            y -= center.y * area;
+        }
+        let area = self.area();
+        if area == 0.0 {
+            return outer;
+        }
+        Point {
+            x: x / area,
+            y: y / area,
+        }
+    }
+}
+
+impl Contains<Point> for PolygonWithHoles {
+    /// Return true if the point lies inside the exterior and outside all holes
+    fn contains(&self, point: Point) -> bool {
+        self.exterior.contains(point) && !self.holes.iter().any(|h| h.contains(point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{p, square};
+
+    #[test]
+    fn test_area_centroid_and_containment() {
+        let parcel = PolygonWithHoles::new(square(0.0, 0.0, 4.0), vec![square(2.0, 1.0, 1.0)]);
+        assert!((parcel.area() - 15.0).abs() < 1e-6);
+        assert!((parcel.perimeter() - 20.0).abs() < 1e-6);
+        assert!(parcel.contains(p(1.0, 1.0)));
+        assert!(!parcel.contains(p(2.5, 1.5)));
+        let centroid = parcel.centroid();
+        assert!((centroid.x - (2.0 * 16.0 - 2.5) / 15.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_bridges_may_only_share_end_points() {
+        let tolerance = Tolerance::default();
+        let bridge = Segment::new(p(0.0, 0.0), p(2.0, 0.0));
+        let shared_end = Segment::new(p(2.0, 0.0), p(3.0, 1.0));
+        assert!(!crosses(&bridge, &shared_end, tolerance));
+        let t_junction = Segment::new(p(1.0, 0.0), p(1.0, 1.0));
+        assert!(crosses(&bridge, &t_junction, tolerance));
+        let along = Segment::new(p(1.0, 0.0), p(3.0, 0.0));
+        assert!(crosses(&bridge, &along, tolerance));
+    }
+
+    #[test]
+    fn test_triangulate_with_holes() {
+        let parcel = PolygonWithHoles::new(
+            square(0.0, 0.0, 10.0),
+            vec![square(2.0, 2.0, 2.0), square(6.0, 5.0, 3.0)],
+        );
+        let mesh = parcel.triangulate();
+        assert_eq!(12, mesh.vertices.len());
+        assert!((mesh.area() - (100.0 - 4.0 - 9.0)).abs() < 1e-3);
+        for [a, b, c] in mesh.triangles() {
+            let center = p((a.x + b.x + c.x) / 3.0, (a.y + b.y + c.y) / 3.0);
+            assert!(parcel.contains(center));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{polyline_curvature, ArcLength, Point, Segment, Vector};
+
+/// Open chain of straight segments through all points in order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<Point>) -> Polyline {
+        Polyline { points }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.points.arc_length()
+    }
+
+    /// Iterate over the segments between consecutive points
+    pub fn segments(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.points.windows(2).map(|w| Segment::new(w[0], w[1]))
+    }
+
+    /// Return the point at fraction t of the total length, where t = 0 is the first and
+    /// t = 1 the last point. None if the polyline has no points.
+    pub fn point_at_fraction(&self, t: f32) -> Option<Point> {
+        if self.points.is_empty() {
+            return None;
+        }
+        Some(self.points.point_at_distance(t * self.length()))
+    }
+
+    /// Return a new polyline with points spaced evenly by spacing along this polyline.
+    /// The first and last point are kept, so the final step may be shorter than spacing.
+    pub fn resample(&self, spacing: f32) -> Polyline {
+        let length = self.length();
+        if self.points.len() < 2 || spacing <= 0.0 || length == 0.0 {
+            return self.clone();
+        }
+        let steps = (length / spacing).ceil() as usize;
+        let mut points: Vec<Point> = (0..steps)
+            .map(|i| self.points.point_at_distance(i as f32 * spacing))
+            .collect();
+        points.push(*self.points.last().unwrap());
+        Polyline { points }
+    }
+
+    /// Return the point on the polyline closest to the passed point, None if the polyline
+    /// has no points
+    pub fn closest_point(&self, point: Point) -> Option<Point> {
+        if self.points.len() == 1 {
+            return Some(self.points[0]);
+        }
+        self.segments()
+            .map(|s| s.closest_point(point))
+            .map(|p| (p, Vector::new(p, point).length()))
+            .fold(None, |best: Option<(Point, f32)>, candidate| match best {
+                Some(b) if b.1 <= candidate.1 => Some(b),
+                _ => Some(candidate),
+            })
+            .map(|(p, _)| p)
+    }
+
+    /// Return the shortest distance from the passed point to the polyline, None if the
+    /// polyline has no points
+    pub fn distance_to(&self, point: Point) -> Option<f32> {
+        self.closest_point(point)
+            .map(|p| Vector::new(p, point).length())
+    }
+
+    /// Return the discrete curvature at every point, see polyline_curvature
+    pub fn curvature(&self) -> Vec<f32> {
+        polyline_curvature(&self.points)
+    }
+}
+
+impl ArcLength for Polyline {
+    fn arc_length(&self) -> f32 {
+        self.length()
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        self.points.point_at_distance(s)
+    }
+}
+
+impl From<Vec<Point>> for Polyline {
+    fn from(points: Vec<Point>) -> Polyline {
+        Polyline { points }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_resample_and_fraction() {
+        let line = Polyline::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 3.0)]);
+        assert_eq!(5.0, line.length());
+        assert_eq!(2, line.segments().count());
+        assert_eq!(Some(p(2.0, 0.5)), line.point_at_fraction(0.5));
+        let resampled = line.resample(2.0);
+        assert_eq!(
+            vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(2.0, 3.0)],
+            resampled.points
+        );
+        assert_eq!(None, Polyline::default().point_at_fraction(0.5));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let line = Polyline::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 3.0)]);
+        assert_eq!(Some(p(1.0, 0.0)), line.closest_point(p(1.0, -1.0)));
+        assert_eq!(Some(p(2.0, 2.0)), line.closest_point(p(5.0, 2.0)));
+        assert_eq!(Some(3.0), line.distance_to(p(5.0, 2.0)));
+        assert_eq!(None, Polyline::default().closest_point(p(0.0, 0.0)));
+    }
+}
@@ -0,0 +1,110 @@
+use crate::transcendental;
+use crate::{Angle, Point};
+
+/// Position and heading of a body in the plane, which is at the same time the rigid
+/// transform from the body frame into the world frame. The heading is measured counter
+/// clockwise from the positive x axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pose2 {
+    pub position: Point,
+    pub heading: Angle,
+}
+
+impl Pose2 {
+    pub fn new(position: Point, heading: Angle) -> Pose2 {
+        Pose2 { position, heading }
+    }
+
+    /// Return the pose at the origin facing along the positive x axis, which transforms
+    /// nothing
+    pub fn identity() -> Pose2 {
+        Pose2::new(Point { x: 0.0, y: 0.0 }, Angle::from_degrees(0.0))
+    }
+
+    /// Map a point given in the body frame of this pose into the world frame
+    pub fn transform_point(&self, point: Point) -> Point {
+        let (sin, cos) = transcendental::sin_cos(self.heading.radians());
+        Point {
+            x: self.position.x + cos * point.x - sin * point.y,
+            y: self.position.y + sin * point.x + cos * point.y,
+        }
+    }
+
+    /// Map a point given in the world frame into the body frame of this pose
+    pub fn inverse_transform_point(&self, point: Point) -> Point {
+        let (sin, cos) = transcendental::sin_cos(self.heading.radians());
+        let (dx, dy) = (point.x - self.position.x, point.y - self.position.y);
+        Point {
+            x: cos * dx + sin * dy,
+            y: -sin * dx + cos * dy,
+        }
+    }
+
+    /// Chain two transforms: the result maps points of other's body frame into the world,
+    /// when other is given relative to this pose
+    pub fn compose(&self, other: &Pose2) -> Pose2 {
+        Pose2 {
+            position: self.transform_point(other.position),
+            heading: Angle::from_degrees(self.heading.degrees() + other.heading.degrees())
+                .normalized(),
+        }
+    }
+
+    /// Return the pose undoing this one, so that pose.compose(&pose.inverse()) is the
+    /// identity
+    pub fn inverse(&self) -> Pose2 {
+        let origin = self.inverse_transform_point(Point { x: 0.0, y: 0.0 });
+        Pose2 {
+            position: origin,
+            heading: Angle::from_degrees(-self.heading.degrees()).normalized(),
+        }
+    }
+
+    /// Return other expressed relative to this pose, the inverse of compose:
+    /// self.compose(&self.between(other)) equals other
+    pub fn between(&self, other: &Pose2) -> Pose2 {
+        self.inverse().compose(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn assert_close(expected: Point, result: Point) {
+        assert!(
+            (expected.x - result.x).abs() < 1e-5,
+            "{:?} {:?}",
+            expected,
+            result
+        );
+        assert!(
+            (expected.y - result.y).abs() < 1e-5,
+            "{:?} {:?}",
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn test_transform_point() {
+        let pose = Pose2::new(p(1.0, 2.0), Angle::from_degrees(90.0));
+        assert_close(p(1.0, 3.0), pose.transform_point(p(1.0, 0.0)));
+        assert_close(p(1.0, 0.0), pose.inverse_transform_point(p(1.0, 3.0)));
+    }
+
+    #[test]
+    fn test_compose_inverse_between() {
+        let a = Pose2::new(p(1.0, 2.0), Angle::from_degrees(90.0));
+        let b = Pose2::new(p(-3.0, 0.5), Angle::from_degrees(135.0));
+        let identity = a.compose(&a.inverse());
+        assert_close(p(0.0, 0.0), identity.position);
+        assert!(identity.heading.degrees().abs() < 1e-5);
+        let relative = a.between(&b);
+        let back = a.compose(&relative);
+        assert_close(b.position, back.position);
+        assert!((back.heading.degrees() - 135.0).abs() < 1e-4);
+        assert!((relative.heading.degrees() - 45.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,125 @@
+//! Solvers for the classic applied trigonometry problems of the classroom: heights from
+//! angles of elevation, distances from angles of depression, triangulation from two
+//! bearings and ladders against walls. Angles are in degrees and measured from the
+//! horizontal unless stated otherwise; lengths may use any unit.
+
+use crate::degrees::{asin_deg, atan_deg, cos_deg, sin_deg, tan_deg};
+use crate::Angle;
+
+/// Return the height of an object seen at the angle of elevation from the given horizontal
+/// distance, measured from the eye of the observer
+pub fn height_from_angle_and_distance(elevation: f32, distance: f32) -> f32 {
+    distance * tan_deg(elevation)
+}
+
+/// Return the horizontal distance to an object seen at the angle of depression from the
+/// given height above it, like a boat seen from a cliff
+pub fn distance_from_depression(depression: f32, height: f32) -> f32 {
+    height / tan_deg(depression)
+}
+
+/// Return the angle of elevation to the top of an object of the height at the horizontal
+/// distance
+pub fn angle_of_elevation(height: f32, distance: f32) -> f32 {
+    atan_deg(height / distance)
+}
+
+/// Return the length of the shadow an object of the height casts with the sun at the
+/// elevation
+pub fn shadow_length(height: f32, sun_elevation: f32) -> f32 {
+    height / tan_deg(sun_elevation)
+}
+
+/// Return the height of an object whose top is seen at the near elevation, and at the
+/// smaller far elevation after stepping back by separation along the same line. None if
+/// the far angle is not smaller.
+pub fn height_from_two_elevations(near: f32, far: f32, separation: f32) -> Option<f32> {
+    if far >= near || far <= 0.0 {
+        return None;
+    }
+    let (near, far) = (tan_deg(near), tan_deg(far));
+    Some(separation * near * far / (near - far))
+}
+
+/// Return the distances of an object from two observers A and B, each measuring the
+/// compass bearing to it, where B lies baseline away from A at baseline_bearing. Solved
+/// with the law of sines. None if the sight lines do not meet on one side of the baseline.
+pub fn distance_from_two_bearings(
+    baseline: f32,
+    baseline_bearing: f32,
+    bearing_from_a: f32,
+    bearing_from_b: f32,
+) -> Option<(f32, f32)> {
+    // Turns from the baseline to the sight lines, on opposite sides if the lines meet
+    let at_a = Angle::from_degrees(bearing_from_a - baseline_bearing)
+        .normalized()
+        .degrees();
+    let at_b = Angle::from_degrees(bearing_from_b - baseline_bearing - 180.0)
+        .normalized()
+        .degrees();
+    if at_a * at_b >= 0.0 {
+        return None;
+    }
+    let (at_a, at_b) = (at_a.abs(), at_b.abs());
+    let at_object = 180.0 - at_a - at_b;
+    if at_object <= 0.0 {
+        return None;
+    }
+    let scale = baseline / sin_deg(at_object);
+    Some((scale * sin_deg(at_b), scale * sin_deg(at_a)))
+}
+
+/// Return how high a ladder of the length reaches up a wall and how far its foot stands
+/// from the wall, leaning at the angle with the ground
+pub fn ladder_against_wall(length: f32, angle_with_ground: f32) -> (f32, f32) {
+    (
+        length * sin_deg(angle_with_ground),
+        length * cos_deg(angle_with_ground),
+    )
+}
+
+/// Return the angle with the ground of a ladder of the length reaching the height, None if
+/// the ladder is too short
+pub fn ladder_angle(length: f32, height: f32) -> Option<f32> {
+    if height > length || length <= 0.0 {
+        return None;
+    }
+    Some(asin_deg(height / length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_elevation_problems() {
+        assert!(close(50.0, height_from_angle_and_distance(45.0, 50.0)));
+        assert!(close(
+            100.0,
+            distance_from_depression(30.0, 100.0 / 3f32.sqrt())
+        ));
+        assert!(close(45.0, angle_of_elevation(10.0, 10.0)));
+        assert!(close(3f32.sqrt(), shadow_length(1.0, 30.0)));
+        // Tower seen at 60 degrees, then at 30 degrees 20 m further away
+        let height = height_from_two_elevations(60.0, 30.0, 20.0).unwrap();
+        assert!(close(10.0 * 3f32.sqrt(), height));
+        assert_eq!(None, height_from_two_elevations(30.0, 60.0, 20.0));
+    }
+
+    #[test]
+    fn test_bearings_and_ladders() {
+        // A and B 10 apart on an east west baseline, the object due north of their middle
+        let bearing = 45.0;
+        let (from_a, from_b) = distance_from_two_bearings(10.0, 90.0, bearing, -bearing).unwrap();
+        assert!(close(50f32.sqrt(), from_a) && close(50f32.sqrt(), from_b));
+        assert_eq!(None, distance_from_two_bearings(10.0, 90.0, 45.0, 135.0));
+        let (height, foot) = ladder_against_wall(5.0, 60.0);
+        assert!(close(2.5 * 3f32.sqrt(), height) && close(2.5, foot));
+        assert!(close(30.0, ladder_angle(4.0, 2.0).unwrap()));
+        assert_eq!(None, ladder_angle(2.0, 3.0));
+    }
+}
@@ -0,0 +1,322 @@
+//! Random geometry for simulation seeding and fuzz style tests, enabled by the rand feature.
+//! All functions take any rand::Rng, so seeded generators give reproducible geometry.
+
+use crate::{
+    transcendental, Area, Circle, Contains, Point, Polygon, PolygonWithHoles, Rect, Triangle,
+    Vector,
+};
+use rand::Rng;
+
+/// Smallest angle in degrees of the triangles returned by triangle.
+const MIN_TRIANGLE_ANGLE: f32 = 1.0;
+
+/// Candidates tried around each active point by poisson_disk before it is retired.
+const POISSON_ATTEMPTS: usize = 30;
+
+/// Return a point uniformly distributed in the rectangle
+pub fn point_in_rect<R: Rng + ?Sized>(rng: &mut R, rect: &Rect) -> Point {
+    Point {
+        x: rect.min.x + rng.gen::<f32>() * rect.width(),
+        y: rect.min.y + rng.gen::<f32>() * rect.height(),
+    }
+}
+
+/// Return a point uniformly distributed in the disk of the circle
+pub fn point_in_circle<R: Rng + ?Sized>(rng: &mut R, circle: &Circle) -> Point {
+    // The square root compensates for the larger area of outer rings
+    let radius = circle.radius * rng.gen::<f32>().sqrt();
+    let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+    Point {
+        x: circle.center.x + radius * transcendental::cos(angle),
+        y: circle.center.y + radius * transcendental::sin(angle),
+    }
+}
+
+/// Return a point uniformly distributed in the triangle
+pub fn point_in_triangle<R: Rng + ?Sized>(rng: &mut R, triangle: &Triangle) -> Point {
+    uniform_in_triangle(
+        rng,
+        [triangle.point_a(), triangle.point_b(), triangle.point_c()],
+    )
+}
+
+/// Return a point uniformly distributed in the simple polygon, None if it has no area
+pub fn point_in_polygon<R: Rng + ?Sized>(rng: &mut R, polygon: &Polygon) -> Option<Point> {
+    polygon.sample_uniform(rng, 1).pop()
+}
+
+impl Polygon {
+    /// Return n points uniformly distributed in the simple polygon, none if it has no area.
+    /// The polygon is triangulated once, then each point picks a triangle with probability
+    /// proportional to its area and a uniform position inside it.
+    pub fn sample_uniform<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<Point> {
+        let mesh = self.triangulate();
+        let mut triangles = Vec::new();
+        // Running total of the areas, for picking a triangle by binary search
+        let mut cumulative = Vec::new();
+        let mut total = 0.0;
+        for corners in mesh.triangles() {
+            let area = Triangle::new(corners[0], corners[1], corners[2]).area();
+            if area > 0.0 {
+                total += area;
+                triangles.push(corners);
+                cumulative.push(total);
+            }
+        }
+        if triangles.is_empty() {
+            return Vec::new();
+        }
+        (0..n)
+            .map(|_| {
+                let target = rng.gen::<f32>() * total;
+                let index = cumulative
+                    .partition_point(|c| *c <= target)
+                    .min(triangles.len() - 1);
+                uniform_in_triangle(rng, triangles[index])
+            })
+            .collect()
+    }
+}
+
+/// Return a unit vector starting at origin pointing in a uniformly distributed direction
+pub fn direction<R: Rng + ?Sized>(rng: &mut R, origin: Point) -> Vector {
+    let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+    Vector::new(
+        origin,
+        Point {
+            x: origin.x + transcendental::cos(angle),
+            y: origin.y + transcendental::sin(angle),
+        },
+    )
+}
+
+/// Return a random triangle with corners inside bounds and no angle below one degree, so it
+/// is safe to use with the angle and side calculations of Triangle. Panics if bounds has no
+/// area.
+pub fn triangle<R: Rng + ?Sized>(rng: &mut R, bounds: &Rect) -> Triangle {
+    assert!(
+        bounds.width() > 0.0 && bounds.height() > 0.0,
+        "bounds of a random triangle need an area"
+    );
+    loop {
+        let mut triangle = Triangle::new(
+            point_in_rect(rng, bounds),
+            point_in_rect(rng, bounds),
+            point_in_rect(rng, bounds),
+        );
+        let smallest = triangle.try_angles().map(|[a, b, c]| a.min(b).min(c));
+        if smallest.is_ok_and(|angle| angle >= MIN_TRIANGLE_ANGLE) {
+            return triangle;
+        }
+    }
+}
+
+/// Shape that poisson_disk can fill: a containment test and a box to draw candidates from.
+pub trait SampleRegion: Contains<Point> {
+    fn sample_bounds(&self) -> Rect;
+}
+
+impl SampleRegion for Rect {
+    fn sample_bounds(&self) -> Rect {
+        *self
+    }
+}
+
+impl SampleRegion for Circle {
+    fn sample_bounds(&self) -> Rect {
+        let corner = |sign: f32| Point {
+            x: self.center.x + sign * self.radius,
+            y: self.center.y + sign * self.radius,
+        };
+        Rect::new(corner(-1.0), corner(1.0))
+    }
+}
+
+impl SampleRegion for Polygon {
+    fn sample_bounds(&self) -> Rect {
+        let origin = Point { x: 0.0, y: 0.0 };
+        Rect::from_points(&self.points).unwrap_or(Rect::new(origin, origin))
+    }
+}
+
+impl SampleRegion for PolygonWithHoles {
+    fn sample_bounds(&self) -> Rect {
+        self.exterior.sample_bounds()
+    }
+}
+
+/// Return points in the region no closer than spacing to each other, spread evenly without
+/// the regularity of a grid, by Bridson's algorithm. Each new point is drawn from the ring
+/// between spacing and twice spacing around a random active point; a background grid with
+/// cells of spacing / sqrt(2) holds at most one point per cell, so the distance check only
+/// looks at nearby cells. Empty if spacing is not positive or no point of the region was
+/// found.
+pub fn poisson_disk<R: Rng + ?Sized, S: SampleRegion + ?Sized>(
+    rng: &mut R,
+    region: &S,
+    spacing: f32,
+) -> Vec<Point> {
+    let bounds = region.sample_bounds();
+    if spacing.is_nan() || spacing <= 0.0 {
+        return Vec::new();
+    }
+    let cell = spacing / std::f32::consts::SQRT_2;
+    let columns = (bounds.width() / cell) as usize + 1;
+    let rows = (bounds.height() / cell) as usize + 1;
+    let mut grid: Vec<Option<usize>> = vec![None; columns * rows];
+    let index = |p: Point| {
+        let column = (((p.x - bounds.min.x) / cell) as usize).min(columns - 1);
+        let row = (((p.y - bounds.min.y) / cell) as usize).min(rows - 1);
+        (column, row)
+    };
+
+    let first = (0..POISSON_ATTEMPTS * 10)
+        .map(|_| point_in_rect(rng, &bounds))
+        .find(|p| region.contains(*p));
+    let mut points: Vec<Point> = first.into_iter().collect();
+    let mut active: Vec<usize> = Vec::new();
+    if let Some(first) = first {
+        let (column, row) = index(first);
+        grid[row * columns + column] = Some(0);
+        active.push(0);
+    }
+    while !active.is_empty() {
+        let slot = ((rng.gen::<f32>() * active.len() as f32) as usize).min(active.len() - 1);
+        let center = points[active[slot]];
+        let mut found = false;
+        for _ in 0..POISSON_ATTEMPTS {
+            // Uniform in the ring by area
+            let radius = spacing * (1.0 + 3.0 * rng.gen::<f32>()).sqrt();
+            let angle = rng.gen::<f32>() * std::f32::consts::PI * 2.0;
+            let candidate = Point {
+                x: center.x + radius * transcendental::cos(angle),
+                y: center.y + radius * transcendental::sin(angle),
+            };
+            if candidate.x < bounds.min.x
+                || candidate.x > bounds.max.x
+                || candidate.y < bounds.min.y
+                || candidate.y > bounds.max.y
+                || !region.contains(candidate)
+            {
+                continue;
+            }
+            let (column, row) = index(candidate);
+            let crowded = (row.saturating_sub(2)..(row + 3).min(rows)).any(|r| {
+                (column.saturating_sub(2)..(column + 3).min(columns)).any(|c| {
+                    grid[r * columns + c].is_some_and(|i| {
+                        let other = points[i];
+                        (other.x - candidate.x).powi(2) + (other.y - candidate.y).powi(2)
+                            < spacing * spacing
+                    })
+                })
+            });
+            if !crowded {
+                grid[row * columns + column] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            active.swap_remove(slot);
+        }
+    }
+    points
+}
+
+fn uniform_in_triangle<R: Rng + ?Sized>(rng: &mut R, [a, b, c]: [Point; 3]) -> Point {
+    let (mut u, mut v) = (rng.gen::<f32>(), rng.gen::<f32>());
+    // Mirror points of the far half of the parallelogram back into the triangle
+    if u + v > 1.0 {
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+    Point {
+        x: a.x + u * (b.x - a.x) + v * (c.x - a.x),
+        y: a.y + u * (b.y - a.y) + v * (c.y - a.y),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Contains;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_points_stay_inside() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let rect = Rect::new(p(-1.0, 2.0), p(3.0, 5.0));
+        let circle = Circle::new(p(1.0, 1.0), 2.0);
+        let triangle = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 3.0));
+        let l_shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(2.0, 1.0),
+            p(1.0, 1.0),
+            p(1.0, 2.0),
+            p(0.0, 2.0),
+        ]);
+        for _ in 0..200 {
+            assert!(rect.contains(point_in_rect(&mut rng, &rect)));
+            assert!(circle.contains(point_in_circle(&mut rng, &circle)));
+            assert!(triangle.contains(point_in_triangle(&mut rng, &triangle)));
+            let q = point_in_polygon(&mut rng, &l_shape).unwrap();
+            assert!(!(q.x > 1.0 && q.y > 1.0));
+        }
+    }
+
+    #[test]
+    fn test_sample_uniform_is_area_weighted() {
+        let mut rng = StdRng::seed_from_u64(13);
+        // Three quarters of the area lie right of x = 1
+        let shape = Polygon::new(vec![p(0.0, 0.0), p(4.0, 0.0), p(4.0, 1.0), p(0.0, 1.0)]);
+        let samples = shape.sample_uniform(&mut rng, 2000);
+        assert_eq!(2000, samples.len());
+        assert!(samples.iter().all(|q| shape.contains(*q)));
+        let right = samples.iter().filter(|q| q.x > 1.0).count();
+        assert!(right > 1400 && right < 1600);
+        assert!(Polygon::new(vec![p(0.0, 0.0), p(1.0, 1.0)])
+            .sample_uniform(&mut rng, 5)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_poisson_disk_spacing() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let circle = Circle::new(p(0.0, 0.0), 5.0);
+        let triangle = Polygon::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(0.0, 10.0)]);
+        for points in [
+            poisson_disk(&mut rng, &circle, 1.0),
+            poisson_disk(&mut rng, &triangle, 1.0),
+        ] {
+            // Dense packing fills at least a third of the area with disks of radius 1/2
+            assert!(points.len() > 20);
+            for (i, a) in points.iter().enumerate() {
+                for b in &points[i + 1..] {
+                    assert!((a.x - b.x).hypot(a.y - b.y) >= 1.0 - 1e-5);
+                }
+            }
+        }
+        assert!(poisson_disk(&mut rng, &circle, 1.0)
+            .iter()
+            .all(|q| circle.contains(*q)));
+        assert!(poisson_disk(&mut rng, &circle, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_directions_and_triangles() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let bounds = Rect::new(p(0.0, 0.0), p(1.0, 1.0));
+        for _ in 0..50 {
+            let mut unit = direction(&mut rng, p(2.0, 2.0));
+            assert!((unit.length() - 1.0).abs() < 1e-5);
+            let mut t = triangle(&mut rng, &bounds);
+            let [alpha, beta, _] = t.try_angles().unwrap();
+            assert!(alpha >= MIN_TRIANGLE_ANGLE && beta >= MIN_TRIANGLE_ANGLE);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{Circle, Contains, Point, Polygon, Rect, Triangle};
+
+/// Placement of a row major grid of square cells in the plane. Cell (i, j) is stored at
+/// index j * width + i and covers [origin.x + i * cell_size, origin.x + (i + 1) * cell_size]
+/// horizontally, and likewise vertically.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GridLayout {
+    pub origin: Point,
+    pub cell_size: f32,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl GridLayout {
+    pub fn new(origin: Point, cell_size: f32, width: usize, height: usize) -> GridLayout {
+        GridLayout {
+            origin,
+            cell_size,
+            width,
+            height,
+        }
+    }
+
+    /// Return the center of cell (i, j)
+    pub fn cell_center(&self, i: usize, j: usize) -> Point {
+        Point {
+            x: self.origin.x + (i as f32 + 0.5) * self.cell_size,
+            y: self.origin.y + (j as f32 + 0.5) * self.cell_size,
+        }
+    }
+
+    /// Return the range of cells overlapping the rectangle as (i0, j0, i1, j1) with
+    /// exclusive ends, None if it misses the grid
+    fn cells_within(&self, rect: &Rect) -> Option<(usize, usize, usize, usize)> {
+        let clamp = |value: f32, max: usize| value.max(0.0).min(max as f32) as usize;
+        let (x0, x1) = (
+            (rect.min.x - self.origin.x) / self.cell_size,
+            (rect.max.x - self.origin.x) / self.cell_size,
+        );
+        let (y0, y1) = (
+            (rect.min.y - self.origin.y) / self.cell_size,
+            (rect.max.y - self.origin.y) / self.cell_size,
+        );
+        let (i0, i1) = (
+            clamp(x0.floor(), self.width),
+            clamp(x1.floor() + 1.0, self.width),
+        );
+        let (j0, j1) = (
+            clamp(y0.floor(), self.height),
+            clamp(y1.floor() + 1.0, self.height),
+        );
+        if i0 >= i1 || j0 >= j1 {
+            return None;
+        }
+        Some((i0, j0, i1, j1))
+    }
+}
+
+/// Shapes that can be drawn into a grid, for example to build occupancy maps. Drawing only
+/// ever raises cells, so several shapes can be drawn into the same grid.
+pub trait Rasterize: Contains<Point> {
+    /// Axis aligned box containing the whole shape
+    fn bounds(&self) -> Rect;
+
+    /// Set every cell whose center lies inside the shape to true. Panics if the grid is
+    /// smaller than the layout.
+    fn rasterize(&self, grid: &mut [bool], layout: &GridLayout) {
+        assert!(
+            grid.len() >= layout.width * layout.height,
+            "grid smaller than layout"
+        );
+        if let Some((i0, j0, i1, j1)) = layout.cells_within(&self.bounds()) {
+            for j in j0..j1 {
+                for i in i0..i1 {
+                    if self.contains(layout.cell_center(i, j)) {
+                        grid[j * layout.width + i] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Raise every cell to the fraction of it covered by the shape, estimated with samples
+    /// by samples points per cell, for anti-aliased output. Panics if the grid is smaller
+    /// than the layout.
+    fn rasterize_coverage(&self, grid: &mut [f32], layout: &GridLayout, samples: usize) {
+        assert!(
+            grid.len() >= layout.width * layout.height,
+            "grid smaller than layout"
+        );
+        let samples = samples.max(1);
+        let step = layout.cell_size / samples as f32;
+        if let Some((i0, j0, i1, j1)) = layout.cells_within(&self.bounds()) {
+            for j in j0..j1 {
+                for i in i0..i1 {
+                    let corner = Point {
+                        x: layout.origin.x + i as f32 * layout.cell_size,
+                        y: layout.origin.y + j as f32 * layout.cell_size,
+                    };
+                    let inside = (0..samples * samples)
+                        .filter(|k| {
+                            self.contains(Point {
+                                x: corner.x + ((k % samples) as f32 + 0.5) * step,
+                                y: corner.y + ((k / samples) as f32 + 0.5) * step,
+                            })
+                        })
+                        .count();
+                    let coverage = inside as f32 / (samples * samples) as f32;
+                    let cell = &mut grid[j * layout.width + i];
+                    *cell = cell.max(coverage);
+                }
+            }
+        }
+    }
+}
+
+impl Rasterize for Circle {
+    fn bounds(&self) -> Rect {
+        let r = self.radius.abs();
+        Rect::new(
+            Point {
+                x: self.center.x - r,
+                y: self.center.y - r,
+            },
+            Point {
+                x: self.center.x + r,
+                y: self.center.y + r,
+            },
+        )
+    }
+}
+
+impl Rasterize for Triangle {
+    fn bounds(&self) -> Rect {
+        Rect::new(self.point_a(), self.point_b()).expand(self.point_c())
+    }
+}
+
+impl Rasterize for Polygon {
+    /// Polygons without points have an empty box at the origin
+    fn bounds(&self) -> Rect {
+        let origin = Point { x: 0.0, y: 0.0 };
+        Rect::from_points(&self.points).unwrap_or(Rect::new(origin, origin))
+    }
+}
+
+impl Rasterize for Rect {
+    fn bounds(&self) -> Rect {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_occupancy() {
+        let layout = GridLayout::new(p(0.0, 0.0), 1.0, 4, 3);
+        let mut grid = vec![false; 12];
+        Rect::new(p(0.0, 0.0), p(2.0, 1.0)).rasterize(&mut grid, &layout);
+        Triangle::new(p(2.0, 1.0), p(10.0, 1.0), p(10.0, 9.0)).rasterize(&mut grid, &layout);
+        let expected = [
+            true, true, false, false, //
+            false, false, true, true, //
+            false, false, false, true,
+        ];
+        assert_eq!(&expected[..], &grid[..]);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let layout = GridLayout::new(p(-2.0, -2.0), 1.0, 4, 4);
+        let mut grid = vec![0.0; 16];
+        Circle::new(p(0.0, 0.0), 2.0).rasterize_coverage(&mut grid, &layout, 16);
+        let total: f32 = grid.iter().sum();
+        assert!((total - 4.0 * std::f32::consts::PI).abs() < 0.1);
+        assert!(grid[5] > 0.99 && grid[0] < 0.5);
+        let square = Polygon::new(vec![
+            p(-2.0, -2.0),
+            p(-1.5, -2.0),
+            p(-1.5, -1.0),
+            p(-2.0, -1.0),
+        ]);
+        square.rasterize_coverage(&mut grid, &layout, 4);
+        assert_eq!(0.5, grid[0]);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    transcendental, Circle, Ellipse, Point, Polygon, PolygonWithHoles, Rect, Segment, Shape,
+    Triangle, Vector,
+};
+
+/// Half line starting at origin. The direction is stored with unit length, so distances
+/// along the ray are real distances. A ray with a zero length direction hits nothing.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    dx: f32,
+    dy: f32,
+}
+
+/// Result of a ray query. The normal has unit length, starts at the hit point and faces
+/// the side the ray came from, so it can be used directly for reflections. index is the
+/// position of the hit shape in the queried collection.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayHit {
+    pub point: Point,
+    pub distance: f32,
+    pub normal: Vector,
+    pub index: usize,
+}
+
+impl Ray {
+    /// Return a ray from origin in the direction of the passed vector. Only the direction
+    /// of the vector matters, not its length or where it is placed.
+    pub fn new(origin: Point, direction: Vector) -> Ray {
+        let (dx, dy) = (direction.dx(), direction.dy());
+        let length = (dx * dx + dy * dy).sqrt();
+        if length == 0.0 {
+            return Ray {
+                origin,
+                dx: 0.0,
+                dy: 0.0,
+            };
+        }
+        Ray {
+            origin,
+            dx: dx / length,
+            dy: dy / length,
+        }
+    }
+
+    /// Return a ray from origin with a direction given in degrees counter clockwise from the
+    /// positive x axis
+    pub fn from_angle(origin: Point, angle: f32) -> Ray {
+        let radians = angle.to_radians();
+        Ray {
+            origin,
+            dx: transcendental::cos(radians),
+            dy: transcendental::sin(radians),
+        }
+    }
+
+    /// Return the unit direction vector placed at the origin of the ray
+    pub fn direction(&self) -> Vector {
+        Vector::new(self.origin, self.point_at(1.0))
+    }
+
+    /// Return the point at distance along the ray
+    pub fn point_at(&self, distance: f32) -> Point {
+        Point {
+            x: self.origin.x + self.dx * distance,
+            y: self.origin.y + self.dy * distance,
+        }
+    }
+
+    /// Build the hit at distance with a normal (nx, ny), turning the normal towards the ray
+    fn hit(&self, distance: f32, nx: f32, ny: f32) -> RayHit {
+        let length = (nx * nx + ny * ny).sqrt();
+        let flip = if nx * self.dx + ny * self.dy > 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+        let point = self.point_at(distance);
+        RayHit {
+            point,
+            distance,
+            normal: Vector::new(
+                point,
+                Point {
+                    x: point.x + flip * nx / length,
+                    y: point.y + flip * ny / length,
+                },
+            ),
+            index: 0,
+        }
+    }
+}
+
+/// Return the closest hit of the ray with any of the shapes, None if it misses all of them
+pub fn raycast(ray: &Ray, shapes: &[impl Shape]) -> Option<RayHit> {
+    shapes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, shape)| {
+            shape
+                .ray_intersection(ray)
+                .map(|hit| RayHit { index, ..hit })
+        })
+        .fold(None, closer)
+}
+
+fn closer(best: Option<RayHit>, hit: RayHit) -> Option<RayHit> {
+    match best {
+        Some(b) if b.distance <= hit.distance => Some(b),
+        _ => Some(hit),
+    }
+}
+
+fn closest_edge_hit(ray: &Ray, edges: impl Iterator<Item = Segment>) -> Option<RayHit> {
+    edges
+        .filter_map(|edge| edge.ray_intersection(ray))
+        .fold(None, closer)
+}
+
+impl Shape for Segment {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        let (ex, ey) = (
+            self.point_b.x - self.point_a.x,
+            self.point_b.y - self.point_a.y,
+        );
+        let denominator = ray.dx * ey - ray.dy * ex;
+        if denominator == 0.0 {
+            return None;
+        }
+        let (wx, wy) = (self.point_a.x - ray.origin.x, self.point_a.y - ray.origin.y);
+        let t = (wx * ey - wy * ex) / denominator;
+        let u = (wx * ray.dy - wy * ray.dx) / denominator;
+        if t < 0.0 || !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        Some(ray.hit(t, -ey, ex))
+    }
+}
+
+impl Shape for Circle {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        if ray.dx == 0.0 && ray.dy == 0.0 {
+            return None;
+        }
+        let (fx, fy) = (ray.origin.x - self.center.x, ray.origin.y - self.center.y);
+        let b = fx * ray.dx + fy * ray.dy;
+        let c = fx * fx + fy * fy - self.radius * self.radius;
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t = if -b - root >= 0.0 {
+            -b - root
+        } else if -b + root >= 0.0 {
+            -b + root
+        } else {
+            return None;
+        };
+        let point = ray.point_at(t);
+        Some(ray.hit(t, point.x - self.center.x, point.y - self.center.y))
+    }
+}
+
+impl Shape for Ellipse {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        if self.semi_major == 0.0 || self.semi_minor == 0.0 {
+            return None;
+        }
+        // Solve in the frame of the ellipse, scaled so the ellipse becomes the unit circle
+        let (sin, cos) = transcendental::sin_cos(self.rotation.to_radians());
+        let (ox, oy) = (ray.origin.x - self.center.x, ray.origin.y - self.center.y);
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let (fx, fy) = ((cos * ox + sin * oy) / a, (-sin * ox + cos * oy) / b);
+        let (dx, dy) = (
+            (cos * ray.dx + sin * ray.dy) / a,
+            (-sin * ray.dx + cos * ray.dy) / b,
+        );
+        let qa = dx * dx + dy * dy;
+        if qa == 0.0 {
+            return None;
+        }
+        let qb = fx * dx + fy * dy;
+        let qc = fx * fx + fy * fy - 1.0;
+        let discriminant = qb * qb - qa * qc;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t = if (-qb - root) / qa >= 0.0 {
+            (-qb - root) / qa
+        } else if (-qb + root) / qa >= 0.0 {
+            (-qb + root) / qa
+        } else {
+            return None;
+        };
+        // The normal is the gradient of (x / a)^2 + (y / b)^2, rotated back
+        let (lx, ly) = ((fx + dx * t) / a, (fy + dy * t) / b);
+        Some(ray.hit(t, cos * lx - sin * ly, sin * lx + cos * ly))
+    }
+}
+
+impl Shape for Polygon {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        closest_edge_hit(ray, self.edges())
+    }
+}
+
+impl Shape for PolygonWithHoles {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        closest_edge_hit(ray, self.edges())
+    }
+}
+
+impl Shape for Triangle {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        closest_edge_hit(ray, self.edges())
+    }
+}
+
+impl Shape for Rect {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        let corners = [
+            self.min,
+            Point {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Point {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ];
+        closest_edge_hit(
+            ray,
+            (0..4).map(|i| Segment::new(corners[i], corners[(i + 1) % 4])),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_raycast_closest_shape() {
+        let ray = Ray::from_angle(p(0.0, 0.0), 0.0);
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Circle::new(p(10.0, 0.0), 2.0)),
+            Box::new(Rect::new(p(5.0, -1.0), p(6.0, 1.0))),
+            Box::new(Segment::new(p(-3.0, -1.0), p(-3.0, 1.0))),
+        ];
+        let hit = raycast(&ray, &shapes).unwrap();
+        assert_eq!(1, hit.index);
+        assert_eq!(5.0, hit.distance);
+        assert_eq!(p(5.0, 0.0), hit.point);
+        assert_eq!((-1.0, 0.0), (hit.normal.dx(), hit.normal.dy()));
+        assert_eq!(None, raycast(&Ray::from_angle(p(0.0, 5.0), 0.0), &shapes));
+    }
+
+    #[test]
+    fn test_ray_circle_from_inside() {
+        let circle = Circle::new(p(0.0, 0.0), 2.0);
+        let ray = Ray::new(p(0.0, 0.0), Vector::new(p(1.0, 1.0), p(1.0, 3.0)));
+        let hit = circle.ray_intersection(&ray).unwrap();
+        assert_eq!(2.0, hit.distance);
+        assert_eq!(p(0.0, 2.0), hit.point);
+        // Facing back towards the origin of the ray
+        assert_eq!((0.0, -1.0), (hit.normal.dx(), hit.normal.dy()));
+    }
+
+    #[test]
+    fn test_ray_ellipse() {
+        let ellipse = Ellipse::new(p(0.0, 0.0), 4.0, 1.0, 90.0);
+        let hit = ellipse
+            .ray_intersection(&Ray::from_angle(p(0.0, -10.0), 90.0))
+            .unwrap();
+        assert!((hit.distance - 6.0).abs() < 1e-4);
+        assert!(hit.normal.dx().abs() < 1e-4 && (hit.normal.dy() + 1.0).abs() < 1e-4);
+        let hit = ellipse
+            .ray_intersection(&Ray::from_angle(p(-5.0, 0.0), 0.0))
+            .unwrap();
+        assert!((hit.point.x + 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_ray_triangle_and_polygon() {
+        let triangle = Triangle::new(p(2.0, -1.0), p(4.0, 0.0), p(2.0, 1.0));
+        let polygon = Polygon::new(vec![p(2.0, -1.0), p(4.0, 0.0), p(2.0, 1.0)]);
+        let ray = Ray::from_angle(p(0.0, 0.0), 0.0);
+        assert_eq!(
+            triangle.ray_intersection(&ray),
+            polygon.ray_intersection(&ray)
+        );
+        assert_eq!(2.0, polygon.ray_intersection(&ray).unwrap().distance);
+    }
+}
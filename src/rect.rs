@@ -0,0 +1,64 @@
+use crate::Point;
+
+/// Axis aligned rectangle described by its lower left (min) and upper right (max) corner.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Return the rectangle spanned by two opposite corners, in any order
+    pub fn new(corner_a: Point, corner_b: Point) -> Rect {
+        Rect {
+            min: Point {
+                x: corner_a.x.min(corner_b.x),
+                y: corner_a.y.min(corner_b.y),
+            },
+            max: Point {
+                x: corner_a.x.max(corner_b.x),
+                y: corner_a.y.max(corner_b.y),
+            },
+        }
+    }
+
+    /// Return the smallest rectangle containing all points, None if there are no points
+    pub fn from_points(points: &[Point]) -> Option<Rect> {
+        let first = *points.first()?;
+        Some(
+            points
+                .iter()
+                .fold(Rect::new(first, first), |r, p| r.expand(*p)),
+        )
+    }
+
+    /// Return the smallest rectangle containing this rectangle and the passed point
+    pub fn expand(&self, point: Point) -> Rect {
+        Rect {
+            min: Point {
+                x: self.min.x.min(point.x),
+                y: self.min.y.min(point.y),
+            },
+            max: Point {
+                x: self.max.x.max(point.x),
+                y: self.max.y.max(point.y),
+            },
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    /// Return true if the rectangles overlap or touch
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+}
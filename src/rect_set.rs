@@ -0,0 +1,258 @@
+use crate::{Point, Rect, Tolerance};
+
+impl Rect {
+    /// Return the overlap of the rectangles, None if they only touch or miss each other
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point {
+            x: self.min.x.max(other.min.x),
+            y: self.min.y.max(other.min.y),
+        };
+        let max = Point {
+            x: self.max.x.min(other.max.x),
+            y: self.max.y.min(other.max.y),
+        };
+        if min.x < max.x && min.y < max.y {
+            Some(Rect { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Return the parts of this rectangle outside the other as at most four disjoint
+    /// rectangles: full width bands below and above the overlap, then the pieces left and
+    /// right of it
+    pub fn difference(&self, other: &Rect) -> Vec<Rect> {
+        let overlap = match self.intersection(other) {
+            Some(overlap) => overlap,
+            None => return vec![*self],
+        };
+        let corner = |x, y| Point { x, y };
+        let candidates = [
+            Rect::new(self.min, corner(self.max.x, overlap.min.y)),
+            Rect::new(corner(self.min.x, overlap.max.y), self.max),
+            Rect::new(
+                corner(self.min.x, overlap.min.y),
+                corner(overlap.min.x, overlap.max.y),
+            ),
+            Rect::new(
+                corner(overlap.max.x, overlap.min.y),
+                corner(self.max.x, overlap.max.y),
+            ),
+        ];
+        candidates
+            .iter()
+            .filter(|r| r.width() > 0.0 && r.height() > 0.0)
+            .copied()
+            .collect()
+    }
+}
+
+/// Return the area covered by at least one of the rectangles. The x coordinates of all
+/// edges cut the plane into slabs, and in each slab the covered y intervals are merged, so
+/// overlaps are counted once.
+pub fn union_area(rects: &[Rect]) -> f32 {
+    let mut xs: Vec<f32> = rects.iter().flat_map(|r| [r.min.x, r.max.x]).collect();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+    let mut area = 0.0;
+    for slab in xs.windows(2) {
+        let mut spans: Vec<(f32, f32)> = rects
+            .iter()
+            .filter(|r| r.min.x <= slab[0] && r.max.x >= slab[1])
+            .map(|r| (r.min.y, r.max.y))
+            .collect();
+        spans.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut covered = 0.0;
+        let mut current: Option<(f32, f32)> = None;
+        for (low, high) in spans {
+            current = match current {
+                Some((start, end)) if low <= end => Some((start, end.max(high))),
+                Some((start, end)) => {
+                    covered += end - start;
+                    Some((low, high))
+                }
+                None => Some((low, high)),
+            };
+        }
+        if let Some((start, end)) = current {
+            covered += end - start;
+        }
+        area += covered * (slab[1] - slab[0]);
+    }
+    area
+}
+
+/// Region made of disjoint axis aligned rectangles, for screen coverage and dirty regions.
+/// Boolean operations keep the pieces disjoint, so the area is the sum of their areas, and
+/// drop slivers no wider than the tolerance that rounding leaves along shared edges.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RectSet {
+    rects: Vec<Rect>,
+    tolerance: Tolerance,
+}
+
+impl RectSet {
+    pub fn new() -> RectSet {
+        RectSet::default()
+    }
+
+    /// Return an empty region dropping pieces up to the tolerance wide
+    pub fn with_tolerance(tolerance: Tolerance) -> RectSet {
+        RectSet {
+            rects: Vec::new(),
+            tolerance,
+        }
+    }
+
+    /// Return the region covered by any of the rectangles
+    pub fn from_rects(rects: &[Rect]) -> RectSet {
+        let mut set = RectSet::new();
+        for rect in rects {
+            set.add(*rect);
+        }
+        set
+    }
+
+    /// Return the disjoint pieces of the region
+    pub fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
+    /// Add the rectangle to the region, storing only the parts not covered yet
+    pub fn add(&mut self, rect: Rect) -> &mut Self {
+        let mut pieces = vec![rect];
+        for existing in &self.rects {
+            pieces = pieces.iter().flat_map(|p| p.difference(existing)).collect();
+        }
+        let tolerance = self.tolerance;
+        self.rects
+            .extend(pieces.into_iter().filter(|r| is_solid(r, tolerance)));
+        self
+    }
+
+    /// Remove the rectangle from the region
+    pub fn subtract(&mut self, rect: &Rect) -> &mut Self {
+        let tolerance = self.tolerance;
+        self.rects = self
+            .rects
+            .iter()
+            .flat_map(|r| r.difference(rect))
+            .filter(|r| is_solid(r, tolerance))
+            .collect();
+        self
+    }
+
+    pub fn area(&self) -> f32 {
+        self.rects.iter().map(|r| r.width() * r.height()).sum()
+    }
+
+    /// Return true if the point lies in or on one of the pieces
+    pub fn contains(&self, point: Point) -> bool {
+        self.rects.iter().any(|r| {
+            (r.min.x..=r.max.x).contains(&point.x) && (r.min.y..=r.max.y).contains(&point.y)
+        })
+    }
+
+    /// Return the region covered by this or the other region
+    pub fn union(&self, other: &RectSet) -> RectSet {
+        let mut result = self.clone();
+        for rect in &other.rects {
+            result.add(*rect);
+        }
+        result
+    }
+
+    /// Return the region covered by both regions, with the tolerance of this one
+    pub fn intersection(&self, other: &RectSet) -> RectSet {
+        let tolerance = self.tolerance;
+        RectSet {
+            rects: self
+                .rects
+                .iter()
+                .flat_map(|a| other.rects.iter().filter_map(move |b| a.intersection(b)))
+                .filter(|r| is_solid(r, tolerance))
+                .collect(),
+            tolerance,
+        }
+    }
+
+    /// Return the region covered by this region but not by the other
+    pub fn difference(&self, other: &RectSet) -> RectSet {
+        let mut result = self.clone();
+        for rect in &other.rects {
+            result.subtract(rect);
+        }
+        result
+    }
+}
+
+/// Return true if the rectangle is wider and higher than the tolerance
+fn is_solid(rect: &Rect, tolerance: Tolerance) -> bool {
+    rect.width() > tolerance.distance && rect.height() > tolerance.distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn r(x0: f32, y0: f32, x1: f32, y1: f32) -> Rect {
+        Rect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 })
+    }
+
+    #[test]
+    fn test_union_area() {
+        let rects = [
+            r(0.0, 0.0, 2.0, 2.0),
+            r(1.0, 1.0, 3.0, 3.0),
+            r(5.0, 0.0, 6.0, 1.0),
+        ];
+        assert_eq!(8.0, union_area(&rects));
+        assert_eq!(
+            4.0,
+            union_area(&[r(0.0, 0.0, 2.0, 2.0), r(0.5, 0.5, 1.0, 1.0)])
+        );
+        assert_eq!(0.0, union_area(&[]));
+    }
+
+    #[test]
+    fn test_rect_boolean_operations() {
+        let a = r(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(
+            Some(r(2.0, 2.0, 4.0, 4.0)),
+            a.intersection(&r(2.0, 2.0, 6.0, 6.0))
+        );
+        assert_eq!(None, a.intersection(&r(4.0, 0.0, 5.0, 1.0)));
+        let ring = a.difference(&r(1.0, 1.0, 3.0, 3.0));
+        assert_eq!(4, ring.len());
+        assert_eq!(
+            12.0,
+            ring.iter().map(|p| p.width() * p.height()).sum::<f32>()
+        );
+
+        let first = RectSet::from_rects(&[r(0.0, 0.0, 2.0, 2.0), r(1.0, 1.0, 3.0, 3.0)]);
+        let second = RectSet::from_rects(&[r(1.0, 0.0, 4.0, 1.0)]);
+        assert_eq!(7.0, first.area());
+        assert_eq!(9.0, first.union(&second).area());
+        assert_eq!(1.0, first.intersection(&second).area());
+        let rest = first.difference(&second);
+        assert_eq!(6.0, rest.area());
+        assert!(!rest.contains(Point { x: 1.5, y: 0.5 }));
+        assert!(rest.contains(Point { x: 0.5, y: 0.5 }));
+    }
+
+    #[test]
+    fn test_slivers_within_tolerance_are_dropped() {
+        let mut exact = RectSet::new();
+        exact
+            .add(r(0.0, 0.0, 1.0, 1.0))
+            .add(r(0.0, 0.0, 1.001, 1.0));
+        assert_eq!(2, exact.rects().len());
+        let mut welded = RectSet::with_tolerance(Tolerance::new(0.01));
+        welded
+            .add(r(0.0, 0.0, 1.0, 1.0))
+            .add(r(0.0, 0.0, 1.001, 1.0));
+        assert_eq!(1, welded.rects().len());
+        welded.subtract(&r(0.0, 0.005, 1.0, 1.0));
+        assert!(welded.rects().is_empty());
+    }
+}
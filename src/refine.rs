@@ -0,0 +1,200 @@
+use crate::delaunay::circumcenter;
+use crate::{delaunay, transcendental, Mesh, Point, Polygon};
+
+/// Targets for quality triangulation.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MeshQuality {
+    /// Smallest allowed angle in degrees. Refinement only terminates reliably up to about
+    /// 20.7 degrees, larger values are clamped to 30.
+    pub min_angle: f32,
+    /// Largest allowed triangle area
+    pub max_area: f32,
+}
+
+impl Default for MeshQuality {
+    fn default() -> MeshQuality {
+        MeshQuality {
+            min_angle: 20.0,
+            max_area: f32::INFINITY,
+        }
+    }
+}
+
+/// Refinement stops after this many added points even if some triangles are still bad, as
+/// sharp input corners can make it run forever.
+const MAX_STEINER_POINTS: usize = 20_000;
+
+impl Polygon {
+    /// Triangulate the polygon with Ruppert's Delaunay refinement. Boundary edges are split
+    /// while points encroach on them, and circumcenters of triangles that violate the
+    /// quality targets are added as Steiner points. The polygon has to be simple; the mesh
+    /// vertices start with the polygon points, followed by the added points.
+    pub fn refined_triangulation(&self, quality: &MeshQuality) -> Mesh {
+        let min_angle = quality.min_angle.clamp(0.0, 30.0);
+        let mut points = self.points.clone();
+        let n = points.len();
+        let mut segments: Vec<(Point, Point)> =
+            (0..n).map(|i| (points[i], points[(i + 1) % n])).collect();
+        loop {
+            while split_encroached(&mut segments, &mut points) {}
+            let mesh = self.inner_triangles(&points);
+            if points.len() - n >= MAX_STEINER_POINTS {
+                return mesh;
+            }
+            let mut bad: Vec<(f32, Point)> = mesh
+                .triangles()
+                .filter(|[a, b, c]| {
+                    smallest_angle(*a, *b, *c) < min_angle || area(*a, *b, *c) > quality.max_area
+                })
+                .filter_map(|[a, b, c]| {
+                    let center = circumcenter(a, b, c)?;
+                    Some((smallest_angle(a, b, c), center))
+                })
+                .collect();
+            if bad.is_empty() {
+                return mesh;
+            }
+            bad.sort_by(|x, y| x.0.total_cmp(&y.0));
+            let mut added = false;
+            for (_, center) in bad {
+                let encroached: Vec<usize> = (0..segments.len())
+                    .filter(|s| encroaches(center, segments[*s]))
+                    .collect();
+                if encroached.is_empty() {
+                    if self.contains(center) && !points.contains(&center) {
+                        points.push(center);
+                        added = true;
+                    }
+                } else {
+                    // Split instead of inserting, and retriangulate before going on
+                    for s in encroached.into_iter().rev() {
+                        split(&mut segments, &mut points, s);
+                    }
+                    added = true;
+                    break;
+                }
+            }
+            if !added {
+                return mesh;
+            }
+        }
+    }
+
+    /// Delaunay triangles of the points whose centroid lies inside the polygon
+    fn inner_triangles(&self, points: &[Point]) -> Mesh {
+        let mesh = delaunay(points);
+        let indices = mesh
+            .indices
+            .chunks(3)
+            .filter(|t| {
+                let [a, b, c] = [
+                    points[t[0] as usize],
+                    points[t[1] as usize],
+                    points[t[2] as usize],
+                ];
+                self.contains(Point {
+                    x: (a.x + b.x + c.x) / 3.0,
+                    y: (a.y + b.y + c.y) / 3.0,
+                })
+            })
+            .flatten()
+            .copied()
+            .collect();
+        Mesh::new(mesh.vertices, indices)
+    }
+}
+
+impl Mesh {
+    /// Return the smallest angle of all triangles in degrees, None for an empty mesh
+    pub fn min_angle(&self) -> Option<f32> {
+        self.triangles()
+            .map(|[a, b, c]| smallest_angle(a, b, c))
+            .fold(None, |min: Option<f32>, angle| {
+                Some(min.unwrap_or(angle).min(angle))
+            })
+    }
+}
+
+/// Split the first segment with a point inside its diametral circle, return false if there
+/// is none
+fn split_encroached(segments: &mut Vec<(Point, Point)>, points: &mut Vec<Point>) -> bool {
+    let found = (0..segments.len()).find(|s| points.iter().any(|p| encroaches(*p, segments[*s])));
+    match found {
+        Some(s) => {
+            split(segments, points, s);
+            true
+        }
+        None => false,
+    }
+}
+
+fn split(segments: &mut Vec<(Point, Point)>, points: &mut Vec<Point>, s: usize) {
+    let (a, b) = segments[s];
+    let middle = Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    };
+    if middle == a || middle == b {
+        // Too short to split in f32, keep it as it is
+        return;
+    }
+    segments[s] = (a, middle);
+    segments.push((middle, b));
+    points.push(middle);
+}
+
+/// Return true if the point lies strictly inside the circle with the segment as diameter
+fn encroaches(point: Point, (a, b): (Point, Point)) -> bool {
+    let dot = (a.x - point.x) * (b.x - point.x) + (a.y - point.y) * (b.y - point.y);
+    dot < -1e-9 * ((b.x - a.x).powi(2) + (b.y - a.y).powi(2))
+}
+
+fn area(a: Point, b: Point, c: Point) -> f32 {
+    ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)).abs() / 2.0
+}
+
+/// Smallest interior angle of the triangle in degrees, opposite its shortest side
+fn smallest_angle(a: Point, b: Point, c: Point) -> f32 {
+    let squared = |p: Point, q: Point| (p.x - q.x).powi(2) + (p.y - q.y).powi(2);
+    let mut sides = [squared(b, c), squared(c, a), squared(a, b)];
+    sides.sort_by(|x, y| x.total_cmp(y));
+    let [shortest, u, v] = sides;
+    let cos = (u + v - shortest) / (2.0 * (u * v).sqrt());
+    transcendental::acos(cos.clamp(-1.0, 1.0)).to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_minimum_angle() {
+        let sliver = Polygon::new(vec![p(0.0, 0.0), p(10.0, 0.0), p(10.0, 1.0), p(0.0, 1.0)]);
+        assert!(sliver.triangulate().min_angle().unwrap() < 10.0);
+        let mesh = sliver.refined_triangulation(&MeshQuality::default());
+        assert!(mesh.min_angle().unwrap() >= 20.0);
+        assert!((mesh.area() - 10.0).abs() < 1e-3);
+        assert_eq!(&sliver.points[..], &mesh.vertices[..4]);
+    }
+
+    #[test]
+    fn test_maximum_area_in_concave_polygon() {
+        let l_shape = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(4.0, 0.0),
+            p(4.0, 2.0),
+            p(2.0, 2.0),
+            p(2.0, 4.0),
+            p(0.0, 4.0),
+        ]);
+        let quality = MeshQuality {
+            min_angle: 25.0,
+            max_area: 0.5,
+        };
+        let mesh = l_shape.refined_triangulation(&quality);
+        assert!(mesh.triangles().all(|[a, b, c]| area(a, b, c) <= 0.5));
+        assert!(mesh.min_angle().unwrap() >= 25.0);
+        assert!((mesh.area() - 12.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,229 @@
+//! Normalization of dirty polygon input. Area, triangulation and boolean routines expect
+//! simple, counter clockwise polygons without repeated or collinear vertices; the methods
+//! here turn user input into that form.
+
+use crate::sweep::segment_crossings;
+use crate::{Point, Polygon, Polyline, Segment, Tolerance};
+
+/// Limit on the number of pieces split_self_intersections produces, only reached for
+/// degenerate input.
+const MAX_SPLITS: usize = 256;
+
+/// A crossing of two non adjacent edges of a polygon or polyline. Edge i runs from point i
+/// to point i + 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SelfIntersection {
+    pub edge_a: usize,
+    pub edge_b: usize,
+    pub point: Point,
+}
+
+impl Polygon {
+    /// Return the signed area, positive for counter clockwise and negative for clockwise
+    /// polygons
+    pub fn signed_area(&self) -> f32 {
+        self.edges()
+            .map(|e| e.point_a.x * e.point_b.y - e.point_b.x * e.point_a.y)
+            .sum::<f32>()
+            / 2.0
+    }
+
+    pub fn is_counter_clockwise(&self) -> bool {
+        self.signed_area() > 0.0
+    }
+
+    /// Reverse the points of a clockwise polygon, so it runs counter clockwise
+    pub fn fix_orientation(&mut self) {
+        if self.signed_area() < 0.0 {
+            self.points.reverse();
+        }
+    }
+
+    /// Remove points coincident with their predecessor, including a last point repeating
+    /// the first one
+    pub fn remove_duplicates(&mut self, tolerance: Tolerance) {
+        let mut points = tolerance.dedup(&self.points);
+        while points.len() > 1 && tolerance.coincident(points[0], points[points.len() - 1]) {
+            points.pop();
+        }
+        self.points = points;
+    }
+
+    /// Remove points lying on the straight line between their neighbours, within the
+    /// tolerance distance. Spikes, where the outline doubles back on itself, are removed too.
+    pub fn remove_collinear(&mut self, tolerance: Tolerance) {
+        let mut i = 0;
+        let mut unchanged = 0;
+        while self.points.len() > 3 && unchanged < self.points.len() {
+            let n = self.points.len();
+            i %= n;
+            let (a, b, c) = (
+                self.points[(i + n - 1) % n],
+                self.points[i],
+                self.points[(i + 1) % n],
+            );
+            // A spike doubling back to its start point has no line, measure to the start
+            let deviation = if tolerance.coincident(a, c) {
+                Segment::new(a, a).distance_to(b)
+            } else {
+                distance_to_line(a, c, b)
+            };
+            if deviation <= tolerance.distance {
+                self.points.remove(i);
+                unchanged = 0;
+            } else {
+                i += 1;
+                unchanged += 1;
+            }
+        }
+    }
+
+    /// Return every crossing of two edges which are not neighbours, ordered by edge
+    pub fn self_intersections(&self) -> Vec<SelfIntersection> {
+        let edges: Vec<Segment> = self.edges().collect();
+        let n = edges.len();
+        // Neighbouring edges share a point, including the closing edge and the first edge
+        let neighbours = |i: usize, j: usize| j == i + 1 || (i == 0 && j + 1 == n);
+        segment_crossings(&edges, Tolerance::default(), neighbours)
+            .into_iter()
+            .map(|(edge_a, edge_b, point)| SelfIntersection {
+                edge_a,
+                edge_b,
+                point,
+            })
+            .collect()
+    }
+
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    /// Split the polygon at its self intersections into simple polygons, each oriented
+    /// counter clockwise. A figure eight becomes its two loops.
+    pub fn split_self_intersections(&self) -> Vec<Polygon> {
+        let mut pending = vec![self.clone()];
+        let mut result = Vec::new();
+        while let Some(polygon) = pending.pop() {
+            if result.len() + pending.len() >= MAX_SPLITS {
+                result.push(polygon);
+                continue;
+            }
+            match polygon.self_intersections().first() {
+                Some(crossing) => {
+                    let (i, j, x) = (crossing.edge_a, crossing.edge_b, crossing.point);
+                    let mut inner = vec![x];
+                    inner.extend_from_slice(&polygon.points[i + 1..=j]);
+                    let mut outer = vec![x];
+                    outer.extend_from_slice(&polygon.points[j + 1..]);
+                    outer.extend_from_slice(&polygon.points[..=i]);
+                    pending.push(Polygon::new(inner));
+                    pending.push(Polygon::new(outer));
+                }
+                None => result.push(polygon),
+            }
+        }
+        result.retain(|p| p.points.len() >= 3);
+        for polygon in &mut result {
+            polygon.fix_orientation();
+        }
+        result
+    }
+
+    /// Run all repairs: remove duplicate and collinear points, split self intersections and
+    /// orient the pieces counter clockwise. Pieces without area are dropped.
+    pub fn repaired(&self, tolerance: Tolerance) -> Vec<Polygon> {
+        let mut polygon = self.clone();
+        polygon.remove_duplicates(tolerance);
+        polygon
+            .split_self_intersections()
+            .into_iter()
+            .filter_map(|mut piece| {
+                piece.remove_duplicates(tolerance);
+                piece.remove_collinear(tolerance);
+                if piece.points.len() >= 3 && piece.signed_area().abs() > 0.0 {
+                    Some(piece)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl Polyline {
+    /// Return every crossing of two segments which are not neighbours, ordered by segment.
+    /// A polyline ending on its own start point is not a crossing.
+    pub fn self_intersections(&self) -> Vec<SelfIntersection> {
+        let segments: Vec<Segment> = self.segments().collect();
+        segment_crossings(&segments, Tolerance::default(), |i, j| j == i + 1)
+            .into_iter()
+            .filter(|(i, j, _)| !(*i == 0 && *j + 1 == segments.len() && self.is_closed()))
+            .map(|(edge_a, edge_b, point)| SelfIntersection {
+                edge_a,
+                edge_b,
+                point,
+            })
+            .collect()
+    }
+
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    fn is_closed(&self) -> bool {
+        self.points.len() > 2 && self.points.first() == self.points.last()
+    }
+}
+
+/// Distance of p from the infinite line through the distinct points a and c
+fn distance_to_line(a: Point, c: Point, p: Point) -> f32 {
+    let (dx, dy) = (c.x - a.x, c.y - a.y);
+    let length = (dx * dx + dy * dy).sqrt();
+    ((p.x - a.x) * dy - (p.y - a.y) * dx).abs() / length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_orientation_and_cleanup() {
+        let mut polygon = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(0.0, 2.0),
+            p(0.0, 2.0),
+            p(2.0, 2.0),
+            p(2.0, 1.0),
+            p(2.0, 0.0),
+            p(1.0, 0.0),
+            p(0.0, 0.0),
+        ]);
+        assert!(!polygon.is_counter_clockwise());
+        polygon.fix_orientation();
+        assert!((polygon.signed_area() - 4.0).abs() < 1e-6);
+        polygon.remove_duplicates(Tolerance::default());
+        assert_eq!(6, polygon.points.len());
+        polygon.remove_collinear(Tolerance::default());
+        assert_eq!(4, polygon.points.len());
+    }
+
+    #[test]
+    fn test_split_figure_eight() {
+        let bowtie = Polygon::new(vec![p(0.0, 0.0), p(2.0, 2.0), p(2.0, 0.0), p(0.0, 2.0)]);
+        let crossings = bowtie.self_intersections();
+        assert_eq!(1, crossings.len());
+        assert_eq!(p(1.0, 1.0), crossings[0].point);
+        assert!(!bowtie.is_simple());
+        let mut zigzag = Polyline::new(bowtie.points.clone());
+        assert_eq!(1, zigzag.self_intersections().len());
+        zigzag.points.push(p(0.0, 0.0));
+        assert_eq!(1, zigzag.self_intersections().len());
+        let pieces = bowtie.repaired(Tolerance::default());
+        assert_eq!(2, pieces.len());
+        for piece in &pieces {
+            assert!(piece.is_simple());
+            assert!((piece.signed_area() - 1.0).abs() < 1e-6);
+        }
+    }
+}
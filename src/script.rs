@@ -0,0 +1,313 @@
+//! Tiny language for geometric constructions, so they can be tried without writing Rust:
+//!
+//! ```text
+//! A = point 0 0; B = point 4 0; C = point 0 3
+//! T = triangle A B C
+//! print T.alpha   # angle at A in degrees
+//! ```
+//!
+//! Statements end at a line break or `;` and `#` starts a comment. `NAME = SHAPE ARGS`
+//! constructs a point (two numbers), vector or segment (two points), circle (center point
+//! and radius) or triangle (three points); `NAME = TERM` copies a value. A term is a
+//! number, a name or a property like `T.alpha`, and `print` prints the terms that follow.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{Area, Centroid, Circle, Perimeter, Point, Segment, Triangle, TrigError, Vector};
+
+/// What went wrong in a statement of a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptErrorKind {
+    /// The statement starts neither with print nor with an assignment
+    UnknownCommand(String),
+    UnknownShape(String),
+    UnknownName(String),
+    UnknownProperty(String),
+    /// The property exists but has no value for this shape, like the angles of a degenerate
+    /// triangle, naming the term
+    Undefined(String),
+    InvalidNumber(String),
+    /// The arguments do not fit the shape, naming the shape
+    WrongArguments(String),
+}
+
+/// Error of a script with the line it occurred on, counting from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub kind: ScriptErrorKind,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ScriptErrorKind::UnknownCommand(word) => write!(f, "unknown command {}", word),
+            ScriptErrorKind::UnknownShape(word) => write!(f, "unknown shape {}", word),
+            ScriptErrorKind::UnknownName(name) => write!(f, "{} is not defined", name),
+            ScriptErrorKind::UnknownProperty(term) => write!(f, "unknown property {}", term),
+            ScriptErrorKind::Undefined(term) => write!(f, "{} is undefined", term),
+            ScriptErrorKind::InvalidNumber(word) => write!(f, "{} is not a number", word),
+            ScriptErrorKind::WrongArguments(shape) => write!(f, "wrong arguments for {}", shape),
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+/// Value bound to a name in a script.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Value {
+    Number(f32),
+    Point(Point),
+    Vector(Vector),
+    Segment(Segment),
+    Circle(Circle),
+    Triangle(Triangle),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let point = |p: Point| format!("({}, {})", p.x, p.y);
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Point(p) => write!(f, "{}", point(*p)),
+            Value::Vector(v) => write!(f, "vector {} {}", point(v.point_a()), point(v.point_b())),
+            Value::Segment(s) => write!(f, "segment {} {}", point(s.point_a), point(s.point_b)),
+            Value::Circle(c) => write!(f, "circle {} {}", point(c.center), c.radius),
+            Value::Triangle(t) => write!(
+                f,
+                "triangle {} {} {}",
+                point(t.point_a()),
+                point(t.point_b()),
+                point(t.point_c())
+            ),
+        }
+    }
+}
+
+/// Interpreter keeping the names defined by the scripts it ran.
+#[derive(Debug, Clone, Default)]
+pub struct Script {
+    values: HashMap<String, Value>,
+}
+
+impl Script {
+    pub fn new() -> Script {
+        Script::default()
+    }
+
+    /// Return the value bound to the name
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Run the source and return the printed lines. Statements before an error keep their
+    /// effect.
+    pub fn run(&mut self, source: &str) -> Result<Vec<String>, ScriptError> {
+        let mut printed = Vec::new();
+        for (index, line) in source.lines().enumerate() {
+            let code = line.split('#').next().unwrap_or("");
+            for statement in code.split(';') {
+                self.statement(statement, &mut printed)
+                    .map_err(|kind| ScriptError {
+                        line: index + 1,
+                        kind,
+                    })?;
+            }
+        }
+        Ok(printed)
+    }
+
+    fn statement(
+        &mut self,
+        statement: &str,
+        printed: &mut Vec<String>,
+    ) -> Result<(), ScriptErrorKind> {
+        let spaced = statement.replace('=', " = ");
+        let words: Vec<&str> = spaced.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["print", terms @ ..] => {
+                let values = terms
+                    .iter()
+                    .map(|t| self.term(t).map(|v| v.to_string()))
+                    .collect::<Result<Vec<String>, _>>()?;
+                printed.push(values.join(" "));
+            }
+            [name, "=", rest @ ..] if is_name(name) => {
+                let value = self.construct(rest)?;
+                self.values.insert(name.to_string(), value);
+            }
+            [word, ..] => return Err(ScriptErrorKind::UnknownCommand(word.to_string())),
+        }
+        Ok(())
+    }
+
+    fn construct(&self, words: &[&str]) -> Result<Value, ScriptErrorKind> {
+        let (shape, args) = match words.split_first() {
+            Some((shape, args)) => (*shape, args),
+            None => return Err(ScriptErrorKind::UnknownShape(String::new())),
+        };
+        let args = args
+            .iter()
+            .map(|a| self.term(a))
+            .collect::<Result<Vec<Value>, _>>()?;
+        let wrong = || ScriptErrorKind::WrongArguments(shape.to_string());
+        let value = match (shape, args.as_slice()) {
+            ("point", [Value::Number(x), Value::Number(y)]) => Value::Point(Point { x: *x, y: *y }),
+            ("vector", [Value::Point(a), Value::Point(b)]) => Value::Vector(Vector::new(*a, *b)),
+            ("segment", [Value::Point(a), Value::Point(b)]) => Value::Segment(Segment::new(*a, *b)),
+            ("circle", [Value::Point(c), Value::Number(r)]) => Value::Circle(Circle::new(*c, *r)),
+            ("triangle", [Value::Point(a), Value::Point(b), Value::Point(c)]) => {
+                Value::Triangle(Triangle::new(*a, *b, *c))
+            }
+            ("point", _) | ("vector", _) | ("segment", _) | ("circle", _) | ("triangle", _) => {
+                return Err(wrong())
+            }
+            (term, []) => self.term(term).map_err(|e| match e {
+                ScriptErrorKind::UnknownName(name) => ScriptErrorKind::UnknownShape(name),
+                e => e,
+            })?,
+            (shape, _) => return Err(ScriptErrorKind::UnknownShape(shape.to_string())),
+        };
+        Ok(value)
+    }
+
+    /// Evaluate a number, a name or a property of a name
+    fn term(&self, term: &str) -> Result<Value, ScriptErrorKind> {
+        if !term.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return term
+                .parse()
+                .map(Value::Number)
+                .map_err(|_| ScriptErrorKind::InvalidNumber(term.to_string()));
+        }
+        let (name, property) = match term.split_once('.') {
+            Some((name, property)) => (name, Some(property)),
+            None => (term, None),
+        };
+        let value = *self
+            .values
+            .get(name)
+            .ok_or_else(|| ScriptErrorKind::UnknownName(name.to_string()))?;
+        match property {
+            Some(property) => match property_of(value, property) {
+                Ok(Some(value)) => Ok(value),
+                Ok(None) => Err(ScriptErrorKind::UnknownProperty(term.to_string())),
+                Err(_) => Err(ScriptErrorKind::Undefined(term.to_string())),
+            },
+            None => Ok(value),
+        }
+    }
+}
+
+/// None for an unknown property, an error for a property without value
+fn property_of(value: Value, property: &str) -> Result<Option<Value>, TrigError> {
+    use Value::Number;
+    let result = match (value, property) {
+        (Value::Point(p), "x") => Number(p.x),
+        (Value::Point(p), "y") => Number(p.y),
+        (Value::Vector(mut v), "length") => Number(v.length()),
+        (Value::Vector(v), "dx") => Number(v.dx()),
+        (Value::Vector(v), "dy") => Number(v.dy()),
+        (Value::Vector(mut v), "alpha") => Number(v.alpha()?),
+        (Value::Vector(mut v), "beta") => Number(v.beta()?),
+        (Value::Segment(s), "length") => Number(s.length()),
+        (Value::Segment(s), "midpoint") => Value::Point(s.centroid()),
+        (Value::Circle(c), "center") => Value::Point(c.center),
+        (Value::Circle(c), "radius") => Number(c.radius),
+        (Value::Circle(c), "area") => Number(c.area()),
+        (Value::Circle(c), "perimeter") => Number(c.perimeter()),
+        (Value::Triangle(mut t), "a") => Number(t.bc()),
+        (Value::Triangle(mut t), "b") => Number(t.ca()),
+        (Value::Triangle(mut t), "c") => Number(t.ab()),
+        (Value::Triangle(mut t), "alpha") => Number(t.alpha()?),
+        (Value::Triangle(mut t), "beta") => Number(t.beta()?),
+        (Value::Triangle(mut t), "gamma") => Number(t.gamma()?),
+        (Value::Triangle(t), "area") => Number(t.area()),
+        (Value::Triangle(t), "perimeter") => Number(t.perimeter()),
+        (Value::Triangle(t), "centroid") => Value::Point(t.centroid()),
+        _ => return Ok(None),
+    };
+    Ok(Some(result))
+}
+
+fn is_name(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && word.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && word != "print"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle_script() {
+        let mut script = Script::new();
+        let printed = script
+            .run(
+                "A = point 0 0; B = point 4 0; C = point 0 3\n\
+                 T = triangle A B C  # right angle at A\n\
+                 print T.alpha T.a T.area\n\
+                 M = T.centroid\n\
+                 print M",
+            )
+            .unwrap();
+        let numbers: Vec<f32> = printed[0]
+            .split(' ')
+            .map(|word| word.parse().unwrap())
+            .collect();
+        for (expected, number) in [90.0, 5.0, 6.0].iter().zip(&numbers) {
+            assert!((expected - number).abs() < 1e-3);
+        }
+        assert_eq!(3, numbers.len());
+        assert_eq!("(1.3333334, 1)", printed[1]);
+        assert_eq!(
+            Some(&Value::Point(Point { x: 4.0, y: 0.0 })),
+            script.get("B")
+        );
+        let printed = script.run("K=circle A 2; print K.radius K").unwrap();
+        assert_eq!(vec!["2 circle (0, 0) 2"], printed);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut script = Script::new();
+        let error = |script: &mut Script, source: &str| script.run(source).unwrap_err();
+        let e = error(&mut script, "A = point 1 2\nprint B");
+        assert_eq!(
+            ScriptError {
+                line: 2,
+                kind: ScriptErrorKind::UnknownName("B".to_string())
+            },
+            e
+        );
+        assert_eq!("line 2: B is not defined", e.to_string());
+        assert_eq!(
+            ScriptErrorKind::WrongArguments("segment".to_string()),
+            error(&mut script, "S = segment A 3").kind
+        );
+        assert_eq!(
+            ScriptErrorKind::UnknownProperty("A.z".to_string()),
+            error(&mut script, "print A.z").kind
+        );
+        assert_eq!(
+            ScriptErrorKind::Undefined("V.alpha".to_string()),
+            error(&mut script, "V = vector A A; print V.alpha").kind
+        );
+        assert_eq!(
+            ScriptErrorKind::InvalidNumber("1,5".to_string()),
+            error(&mut script, "P = point 1,5 2").kind
+        );
+        assert_eq!(
+            ScriptErrorKind::UnknownCommand("draw".to_string()),
+            error(&mut script, "draw A").kind
+        );
+        assert_eq!(
+            ScriptErrorKind::UnknownShape("square".to_string()),
+            error(&mut script, "Q = square A 2").kind
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    contours, transcendental, Capsule, Centroid, Circle, Contains, GridLayout, Point, Polygon,
+    Rect, Segment, Triangle,
+};
+
+/// Exact signed distance to the boundary of a shape, negative inside and positive outside.
+pub trait SignedDistance {
+    fn sdf(&self, point: Point) -> f32;
+
+    /// Return the signed distance at the center of every cell of the layout, row by row
+    fn sdf_grid(&self, layout: &GridLayout) -> Vec<f32> {
+        (0..layout.height)
+            .flat_map(|j| (0..layout.width).map(move |i| (i, j)))
+            .map(|(i, j)| self.sdf(layout.cell_center(i, j)))
+            .collect()
+    }
+
+    /// Return the outlines of the shape traced on the layout with marching squares. Only
+    /// outlines closed within the grid are returned, so the layout should enclose the shape
+    /// with a margin of a cell.
+    fn to_polygons(&self, layout: &GridLayout) -> Vec<Polygon> {
+        let field = self.sdf_grid(layout);
+        let center = layout.cell_center(0, 0);
+        contours(&field, layout.width, layout.height, 0.0)
+            .into_iter()
+            .filter(|line| line.points.len() > 3 && line.points.first() == line.points.last())
+            .map(|line| {
+                let n = line.points.len() - 1;
+                let points = line.points[..n]
+                    .iter()
+                    .map(|p| Point {
+                        x: center.x + p.x * layout.cell_size,
+                        y: center.y + p.y * layout.cell_size,
+                    })
+                    .collect();
+                let mut polygon = Polygon::new(points);
+                polygon.fix_orientation();
+                polygon
+            })
+            .collect()
+    }
+
+    /// Return the shape covering both shapes
+    fn union<B: SignedDistance>(self, other: B) -> Union<Self, B>
+    where
+        Self: Sized,
+    {
+        Union(self, other)
+    }
+
+    /// Return the shape covered by both shapes
+    fn intersection<B: SignedDistance>(self, other: B) -> Intersection<Self, B>
+    where
+        Self: Sized,
+    {
+        Intersection(self, other)
+    }
+
+    /// Return this shape with other cut away
+    fn subtract<B: SignedDistance>(self, other: B) -> Subtraction<Self, B>
+    where
+        Self: Sized,
+    {
+        Subtraction(self, other)
+    }
+
+    /// Return the union with the seam rounded over a width of about radius, using the
+    /// polynomial smooth minimum
+    fn smooth_union<B: SignedDistance>(self, other: B, radius: f32) -> SmoothUnion<Self, B>
+    where
+        Self: Sized,
+    {
+        SmoothUnion(self, other, radius)
+    }
+}
+
+impl<S: SignedDistance + ?Sized> SignedDistance for &S {
+    fn sdf(&self, point: Point) -> f32 {
+        (**self).sdf(point)
+    }
+}
+
+/// Union of two shapes, see SignedDistance::union. Distances outside are exact, inside
+/// they are a bound.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Union<A, B>(pub A, pub B);
+
+/// Intersection of two shapes, see SignedDistance::intersection.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Intersection<A, B>(pub A, pub B);
+
+/// First shape without the second, see SignedDistance::subtract.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Subtraction<A, B>(pub A, pub B);
+
+/// Union with a rounded seam of the given radius, see SignedDistance::smooth_union.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SmoothUnion<A, B>(pub A, pub B, pub f32);
+
+impl<A: SignedDistance, B: SignedDistance> SignedDistance for Union<A, B> {
+    fn sdf(&self, point: Point) -> f32 {
+        self.0.sdf(point).min(self.1.sdf(point))
+    }
+}
+
+impl<A: SignedDistance, B: SignedDistance> SignedDistance for Intersection<A, B> {
+    fn sdf(&self, point: Point) -> f32 {
+        self.0.sdf(point).max(self.1.sdf(point))
+    }
+}
+
+impl<A: SignedDistance, B: SignedDistance> SignedDistance for Subtraction<A, B> {
+    fn sdf(&self, point: Point) -> f32 {
+        self.0.sdf(point).max(-self.1.sdf(point))
+    }
+}
+
+impl<A: SignedDistance, B: SignedDistance> SignedDistance for SmoothUnion<A, B> {
+    fn sdf(&self, point: Point) -> f32 {
+        let (a, b, k) = (self.0.sdf(point), self.1.sdf(point), self.2);
+        if k <= 0.0 {
+            return a.min(b);
+        }
+        let h = (0.5 + 0.5 * (b - a) / k).clamp(0.0, 1.0);
+        b + (a - b) * h - k * h * (1.0 - h)
+    }
+}
+
+impl SignedDistance for Circle {
+    fn sdf(&self, point: Point) -> f32 {
+        transcendental::hypot(point.x - self.center.x, point.y - self.center.y) - self.radius
+    }
+}
+
+impl SignedDistance for Rect {
+    fn sdf(&self, point: Point) -> f32 {
+        let center = self.centroid();
+        let dx = (point.x - center.x).abs() - self.width() / 2.0;
+        let dy = (point.y - center.y).abs() - self.height() / 2.0;
+        transcendental::hypot(dx.max(0.0), dy.max(0.0)) + dx.max(dy).min(0.0)
+    }
+}
+
+impl SignedDistance for Segment {
+    /// Distance to the segment, which has no inside and is never negative
+    fn sdf(&self, point: Point) -> f32 {
+        self.distance_to(point)
+    }
+}
+
+impl SignedDistance for Capsule {
+    fn sdf(&self, point: Point) -> f32 {
+        self.segment.distance_to(point) - self.radius
+    }
+}
+
+impl SignedDistance for Triangle {
+    fn sdf(&self, point: Point) -> f32 {
+        let distance = self
+            .edges()
+            .map(|e| e.distance_to(point))
+            .fold(f32::INFINITY, f32::min);
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+impl SignedDistance for Polygon {
+    /// Inside and outside follow the even-odd rule of Polygon::contains
+    fn sdf(&self, point: Point) -> f32 {
+        let distance = self
+            .edges()
+            .map(|e| e.distance_to(point))
+            .fold(f32::INFINITY, f32::min);
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_shape_distances() {
+        assert_eq!(-1.0, Circle::new(p(0.0, 0.0), 2.0).sdf(p(1.0, 0.0)));
+        let rect = Rect::new(p(0.0, 0.0), p(4.0, 2.0));
+        assert_eq!(-1.0, rect.sdf(p(2.0, 1.0)));
+        assert_eq!(5.0, rect.sdf(p(7.0, 6.0)));
+        let capsule = Capsule {
+            segment: Segment::new(p(0.0, 0.0), p(4.0, 0.0)),
+            radius: 1.0,
+        };
+        assert_eq!(1.0, capsule.sdf(p(2.0, 2.0)));
+        let triangle = Triangle::new(p(0.0, 0.0), p(4.0, 0.0), p(0.0, 4.0));
+        assert_eq!(-1.0, triangle.sdf(p(1.0, 1.0)));
+        let square = Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+        assert_eq!(-1.0, square.sdf(p(1.0, 1.0)));
+        assert_eq!(3.0, square.sdf(p(5.0, 1.0)));
+    }
+
+    #[test]
+    fn test_sdf_grid() {
+        let layout = GridLayout::new(p(-2.0, -2.0), 1.0, 4, 4);
+        let field = Circle::new(p(0.0, 0.0), 1.0).sdf_grid(&layout);
+        assert_eq!(16, field.len());
+        assert!((field[5] - (0.5f32.hypot(0.5) - 1.0)).abs() < 1e-6);
+        assert!(field[0] > 0.0);
+    }
+
+    #[test]
+    fn test_combinators() {
+        let (a, b) = (Circle::new(p(0.0, 0.0), 2.0), Circle::new(p(3.0, 0.0), 2.0));
+        assert_eq!(-2.0, a.union(b).sdf(p(3.0, 0.0)));
+        assert_eq!(-0.5, a.intersection(b).sdf(p(1.5, 0.0)));
+        assert_eq!(0.5, a.subtract(b).sdf(p(1.5, 0.0)));
+        let smooth = a.smooth_union(b, 1.0).sdf(p(1.5, 2.0));
+        assert!(smooth < a.union(b).sdf(p(1.5, 2.0)));
+        // Combinators also take references, a radius of 0 is the plain union
+        assert_eq!(7.0, (&a).smooth_union(&b, 0.0).sdf(p(-9.0, 0.0)));
+    }
+
+    #[test]
+    fn test_contour_back_to_polygon() {
+        let plate = Rect::new(p(-3.0, -2.0), p(3.0, 2.0));
+        let shape = plate.subtract(Circle::new(p(0.0, 0.0), 1.0));
+        let layout = GridLayout::new(p(-4.0, -3.0), 0.1, 80, 60);
+        let mut outlines = shape.to_polygons(&layout);
+        assert_eq!(2, outlines.len());
+        outlines.sort_by(|x, y| x.signed_area().total_cmp(&y.signed_area()));
+        assert!((outlines[1].signed_area() - 24.0).abs() < 0.1);
+        assert!((outlines[0].signed_area() - std::f32::consts::PI).abs() < 0.1);
+    }
+}
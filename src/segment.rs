@@ -0,0 +1,250 @@
+use crate::{ArcLength, Point, Rect, Vector};
+
+/// Straight line segment between point_a and point_b. In contrast to Vector a segment
+/// caches nothing, so it can be freely copied and inspected through shared references.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Segment {
+    pub point_a: Point,
+    pub point_b: Point,
+}
+
+impl Segment {
+    pub fn new(point_a: Point, point_b: Point) -> Segment {
+        Segment { point_a, point_b }
+    }
+
+    pub fn length(&self) -> f32 {
+        Vector::new(self.point_a, self.point_b).length()
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        Rect::new(self.point_a, self.point_b)
+    }
+
+    /// Return the point on the segment closest to the passed point
+    pub fn closest_point(&self, point: Point) -> Point {
+        let (dx, dy) = (
+            self.point_b.x - self.point_a.x,
+            self.point_b.y - self.point_a.y,
+        );
+        let length_squared = dx * dx + dy * dy;
+        if length_squared == 0.0 {
+            return self.point_a;
+        }
+        let t =
+            ((point.x - self.point_a.x) * dx + (point.y - self.point_a.y) * dy) / length_squared;
+        let t = t.clamp(0.0, 1.0);
+        Point {
+            x: self.point_a.x + dx * t,
+            y: self.point_a.y + dy * t,
+        }
+    }
+
+    /// Return the closest pair of points, the first on this and the second on the other
+    /// segment. For crossing segments both points are the intersection.
+    pub fn closest_points(&self, other: &Segment) -> (Point, Point) {
+        let (d1x, d1y) = (
+            self.point_b.x - self.point_a.x,
+            self.point_b.y - self.point_a.y,
+        );
+        let (d2x, d2y) = (
+            other.point_b.x - other.point_a.x,
+            other.point_b.y - other.point_a.y,
+        );
+        let (rx, ry) = (
+            self.point_a.x - other.point_a.x,
+            self.point_a.y - other.point_a.y,
+        );
+        let (a, e, f) = (
+            d1x * d1x + d1y * d1y,
+            d2x * d2x + d2y * d2y,
+            d2x * rx + d2y * ry,
+        );
+        let (s, t) = if a == 0.0 && e == 0.0 {
+            (0.0, 0.0)
+        } else if a == 0.0 {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1x * rx + d1y * ry;
+            if e == 0.0 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1x * d2x + d1y * d2y;
+                let denominator = a * e - b * b;
+                let mut s = if denominator != 0.0 {
+                    ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                let mut t = (b * s + f) / e;
+                if t < 0.0 {
+                    t = 0.0;
+                    s = (-c / a).clamp(0.0, 1.0);
+                } else if t > 1.0 {
+                    t = 1.0;
+                    s = ((b - c) / a).clamp(0.0, 1.0);
+                }
+                (s, t)
+            }
+        };
+        (
+            Point {
+                x: self.point_a.x + d1x * s,
+                y: self.point_a.y + d1y * s,
+            },
+            Point {
+                x: other.point_a.x + d2x * t,
+                y: other.point_a.y + d2y * t,
+            },
+        )
+    }
+
+    /// Return the point where the segments cross, None if they miss each other or are
+    /// parallel. Touching at an end point counts as crossing.
+    pub fn intersection(&self, other: &Segment) -> Option<Point> {
+        let (d1x, d1y) = (
+            self.point_b.x - self.point_a.x,
+            self.point_b.y - self.point_a.y,
+        );
+        let (d2x, d2y) = (
+            other.point_b.x - other.point_a.x,
+            other.point_b.y - other.point_a.y,
+        );
+        let denominator = d1x * d2y - d1y * d2x;
+        if denominator == 0.0 {
+            return None;
+        }
+        let (rx, ry) = (
+            other.point_a.x - self.point_a.x,
+            other.point_a.y - self.point_a.y,
+        );
+        let s = (rx * d2y - ry * d2x) / denominator;
+        let t = (rx * d1y - ry * d1x) / denominator;
+        if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&t) {
+            return None;
+        }
+        Some(Point {
+            x: self.point_a.x + d1x * s,
+            y: self.point_a.y + d1y * s,
+        })
+    }
+
+    /// Return the shortest distance between the segment and the passed point
+    pub fn distance_to(&self, point: Point) -> f32 {
+        Vector::new(self.closest_point(point), point).length()
+    }
+
+    /// Return the point at parameter t, point_a for t = 0 and point_b for t = 1. Values
+    /// outside [0, 1] extrapolate along the line.
+    pub fn point_at(&self, t: f32) -> Point {
+        Point {
+            x: self.point_a.x + (self.point_b.x - self.point_a.x) * t,
+            y: self.point_a.y + (self.point_b.y - self.point_a.y) * t,
+        }
+    }
+
+    /// Return the two pieces before and after parameter t, which is clamped to [0, 1]
+    pub fn split_at(&self, t: f32) -> (Segment, Segment) {
+        let middle = self.point_at(t.clamp(0.0, 1.0));
+        (
+            Segment::new(self.point_a, middle),
+            Segment::new(middle, self.point_b),
+        )
+    }
+
+    /// Return n pieces of equal length from point_a to point_b, at least one
+    pub fn subdivide(&self, n: usize) -> Vec<Segment> {
+        let n = n.max(1);
+        let points: Vec<Point> = (0..=n)
+            .map(|i| match i {
+                0 => self.point_a,
+                i if i == n => self.point_b,
+                i => self.point_at(i as f32 / n as f32),
+            })
+            .collect();
+        points
+            .windows(2)
+            .map(|w| Segment::new(w[0], w[1]))
+            .collect()
+    }
+
+    /// Return the part of the segment inside the rectangle, None if it lies outside. The
+    /// clipped segment keeps the direction of this one. Uses the Liang-Barsky algorithm.
+    pub fn clip_to_rect(&self, rect: &Rect) -> Option<Segment> {
+        let (a, b) = (self.point_a, self.point_b);
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+        let axes = [
+            (a.x, b.x - a.x, rect.min.x, rect.max.x),
+            (a.y, b.y - a.y, rect.min.y, rect.max.y),
+        ];
+        for &(start, delta, min, max) in &axes {
+            if delta == 0.0 {
+                if start < min || start > max {
+                    return None;
+                }
+            } else {
+                let (t0, t1) = ((min - start) / delta, (max - start) / delta);
+                low = low.max(t0.min(t1));
+                high = high.min(t0.max(t1));
+            }
+        }
+        if low > high {
+            return None;
+        }
+        let start = if low == 0.0 { a } else { self.point_at(low) };
+        let end = if high == 1.0 { b } else { self.point_at(high) };
+        Some(Segment::new(start, end))
+    }
+}
+
+impl From<Segment> for Vector {
+    fn from(segment: Segment) -> Vector {
+        Vector::new(segment.point_a, segment.point_b)
+    }
+}
+
+impl ArcLength for Segment {
+    fn arc_length(&self) -> f32 {
+        self.length()
+    }
+
+    fn point_at_distance(&self, s: f32) -> Point {
+        [self.point_a, self.point_b].point_at_distance(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_split_and_subdivide() {
+        let segment = Segment::new(p(0.0, 0.0), p(4.0, 2.0));
+        assert_eq!(p(1.0, 0.5), segment.point_at(0.25));
+        let (first, second) = segment.split_at(0.5);
+        assert_eq!(p(2.0, 1.0), first.point_b);
+        assert_eq!(p(2.0, 1.0), second.point_a);
+        let pieces = segment.subdivide(4);
+        assert_eq!(4, pieces.len());
+        assert_eq!(p(3.0, 1.5), pieces[3].point_a);
+        assert_eq!(p(4.0, 2.0), pieces[3].point_b);
+    }
+
+    #[test]
+    fn test_clip_to_rect() {
+        let rect = Rect::new(p(0.0, 0.0), p(2.0, 2.0));
+        let clipped = Segment::new(p(-1.0, 1.0), p(3.0, 1.0)).clip_to_rect(&rect);
+        assert_eq!(Some(Segment::new(p(0.0, 1.0), p(2.0, 1.0))), clipped);
+        let inside = Segment::new(p(0.5, 0.5), p(1.0, 1.5));
+        assert_eq!(Some(inside), inside.clip_to_rect(&rect));
+        assert_eq!(
+            None,
+            Segment::new(p(5.0, 0.0), p(0.0, 5.0)).clip_to_rect(&rect)
+        );
+        assert_eq!(
+            None,
+            Segment::new(p(3.0, 0.0), p(3.0, 3.0)).clip_to_rect(&rect)
+        );
+    }
+}
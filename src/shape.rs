@@ -0,0 +1,90 @@
+use crate::{Point, Ray, RayHit};
+
+/// Common interface of all closed shapes and segments of the crate, so mixed collections can
+/// be queried together, for example as a Vec<Box<dyn Shape>>.
+pub trait Shape: Area + Perimeter + Centroid + Contains<Point> {
+    /// Return the first point where the ray meets the boundary of the shape, or None if it
+    /// misses. The index of the returned hit is always 0, collection queries like raycast
+    /// replace it with the position of the shape in the collection.
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit>;
+}
+
+/// Enclosed area, never negative. Open shapes like segments have no area.
+pub trait Area {
+    fn area(&self) -> f32;
+}
+
+/// Length of the boundary. For segments this is the segment length.
+pub trait Perimeter {
+    fn perimeter(&self) -> f32;
+}
+
+/// Center of mass of the enclosed area, or of the boundary for open shapes.
+pub trait Centroid {
+    fn centroid(&self) -> Point;
+}
+
+/// Containment test, points on the boundary count as contained.
+pub trait Contains<T> {
+    fn contains(&self, item: T) -> bool;
+}
+
+impl<S: Shape + ?Sized> Shape for &S {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        (**self).ray_intersection(ray)
+    }
+}
+
+impl<S: Shape + ?Sized> Shape for Box<S> {
+    fn ray_intersection(&self, ray: &Ray) -> Option<RayHit> {
+        (**self).ray_intersection(ray)
+    }
+}
+
+impl<S: Area + ?Sized> Area for &S {
+    fn area(&self) -> f32 {
+        (**self).area()
+    }
+}
+
+impl<S: Area + ?Sized> Area for Box<S> {
+    fn area(&self) -> f32 {
+        (**self).area()
+    }
+}
+
+impl<S: Perimeter + ?Sized> Perimeter for &S {
+    fn perimeter(&self) -> f32 {
+        (**self).perimeter()
+    }
+}
+
+impl<S: Perimeter + ?Sized> Perimeter for Box<S> {
+    fn perimeter(&self) -> f32 {
+        (**self).perimeter()
+    }
+}
+
+impl<S: Centroid + ?Sized> Centroid for &S {
+    fn centroid(&self) -> Point {
+        (**self).centroid()
+    }
+}
+
+impl<S: Centroid + ?Sized> Centroid for Box<S> {
+    fn centroid(&self) -> Point {
+        (**self).centroid()
+    }
+}
+
+impl<T, S: Contains<T> + ?Sized> Contains<T> for &S {
+    fn contains(&self, item: T) -> bool {
+        (**self).contains(item)
+    }
+}
+
+impl<T, S: Contains<T> + ?Sized> Contains<T> for Box<S> {
+    fn contains(&self, item: T) -> bool {
+        (**self).contains(item)
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{can_form_triangle, transcendental, Point, Triangle};
+
+/// Side lengths of a triangle for purely metric problems, without coordinates. Following
+/// the textbook convention a is the side opposite point_a, so a = bc, b = ca and c = ab.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SideLengths {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+}
+
+impl SideLengths {
+    pub fn new(a: f32, b: f32, c: f32) -> SideLengths {
+        SideLengths { a, b, c }
+    }
+
+    /// Return true if the sides form a non degenerate triangle
+    pub fn is_valid(&self) -> bool {
+        can_form_triangle(self.a, self.b, self.c)
+    }
+
+    pub fn perimeter(&self) -> f32 {
+        self.a + self.b + self.c
+    }
+
+    /// Return the area by Heron's formula, in the arrangement for sorted sides that stays
+    /// accurate for needle shaped triangles. 0 if the sides form no triangle.
+    pub fn area(&self) -> f32 {
+        if !self.is_valid() {
+            return 0.0;
+        }
+        let mut sides = [self.a, self.b, self.c];
+        sides.sort_by(|x, y| y.total_cmp(x));
+        let [a, b, c] = sides;
+        0.25 * ((a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c))).sqrt()
+    }
+
+    /// Return the angles alpha, beta and gamma in degrees opposite a, b and c by the law of
+    /// cosines, None if the sides form no triangle
+    pub fn angles(&self) -> Option<[f32; 3]> {
+        if !self.is_valid() {
+            return None;
+        }
+        let angle = |adjacent1: f32, adjacent2: f32, opposite: f32| {
+            let cosine = (adjacent1 * adjacent1 + adjacent2 * adjacent2 - opposite * opposite)
+                / (2.0 * adjacent1 * adjacent2);
+            transcendental::acos(cosine.clamp(-1.0, 1.0)).to_degrees()
+        };
+        Some([
+            angle(self.b, self.c, self.a),
+            angle(self.c, self.a, self.b),
+            angle(self.a, self.b, self.c),
+        ])
+    }
+
+    /// Return the triangle with point_a at the origin, point_b on the positive x axis and
+    /// point_c above it, None if the sides form no triangle
+    pub fn to_triangle(&self) -> Option<Triangle> {
+        if !self.is_valid() {
+            return None;
+        }
+        // Foot of the height from C on AB, measured from A
+        let x = (self.b * self.b + self.c * self.c - self.a * self.a) / (2.0 * self.c);
+        let y = (self.b * self.b - x * x).max(0.0).sqrt();
+        Some(Triangle::new(
+            Point { x: 0.0, y: 0.0 },
+            Point { x: self.c, y: 0.0 },
+            Point { x, y },
+        ))
+    }
+}
+
+impl Triangle {
+    /// Return the side lengths without the position of the triangle
+    pub fn side_lengths(&mut self) -> SideLengths {
+        SideLengths::new(self.bc(), self.ca(), self.ab())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_right_triangle() {
+        let sides = SideLengths::new(3.0, 4.0, 5.0);
+        assert!(sides.is_valid());
+        assert_eq!(6.0, sides.area());
+        assert_eq!(12.0, sides.perimeter());
+        let [alpha, beta, gamma] = sides.angles().unwrap();
+        assert!((gamma - 90.0).abs() < 1e-4);
+        assert!((alpha + beta - 90.0).abs() < 1e-4);
+        let mut triangle = sides.to_triangle().unwrap();
+        assert_eq!(Point { x: 5.0, y: 0.0 }, triangle.point_b());
+        let placed = triangle.side_lengths();
+        assert!((placed.a - 3.0).abs() < 1e-5 && (placed.b - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_invalid_sides() {
+        let flat = SideLengths::new(1.0, 2.0, 3.0);
+        assert!(!flat.is_valid());
+        assert_eq!(0.0, flat.area());
+        assert_eq!(None, flat.angles());
+        assert!(flat.to_triangle().is_none());
+    }
+}
@@ -0,0 +1,487 @@
+//! Straight skeleton of simple polygons, computed by simulating the wavefront: every edge
+//! moves inward at unit speed, and the skeleton is traced by the vertices of the shrinking
+//! polygon. Edge events remove an edge that shrank to nothing, split events cut the
+//! wavefront where a reflex vertex runs into an opposite edge.
+
+use crate::{Point, Polygon};
+
+/// Events closer together in time than this are processed as simultaneous.
+const EVENT_TOLERANCE: f32 = 1e-5;
+
+/// Piece of the straight skeleton. The offsets are the distances of the end points from
+/// the polygon boundary, which for a roof of slope s are the heights divided by s.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkeletonEdge {
+    pub start: Point,
+    pub end: Point,
+    pub start_offset: f32,
+    pub end_offset: f32,
+    /// The polygon edges whose faces lie on both sides, edge i running from point i to
+    /// point i + 1 of the input polygon
+    pub faces: (usize, usize),
+}
+
+/// Region swept by one polygon edge, bounded by the edge and skeleton edges. For roofs this
+/// is one roof plane.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkeletonFace {
+    /// The polygon edge sweeping the face, running from point edge to point edge + 1
+    pub edge: usize,
+    /// Outline starting with both points of the polygon edge
+    pub points: Vec<Point>,
+    /// Offset of each outline point, 0 for the points of the polygon edge
+    pub offsets: Vec<f32>,
+}
+
+/// Straight skeleton of a polygon, see Polygon::straight_skeleton.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StraightSkeleton {
+    pub edges: Vec<SkeletonEdge>,
+    polygon: Polygon,
+}
+
+/// Vertex of the moving wavefront
+#[derive(Debug, Copy, Clone)]
+struct WaveVertex {
+    position: Point,
+    velocity: (f32, f32),
+    /// Where and when the vertex started, the start of its skeleton edge
+    origin: Point,
+    origin_offset: f32,
+    /// Line of the wavefront edge leaving this vertex, as index of the polygon edge
+    line: usize,
+}
+
+/// Polygon edge line with inward unit normal n, n . x = c at offset 0
+#[derive(Debug, Copy, Clone)]
+struct EdgeLine {
+    normal: (f32, f32),
+    c: f32,
+}
+
+enum Event {
+    /// The edge leaving vertex index of ring collapses
+    Edge { ring: usize, index: usize },
+    /// Reflex vertex index runs into the edge leaving vertex target
+    Split {
+        ring: usize,
+        index: usize,
+        target: usize,
+    },
+}
+
+impl Polygon {
+    /// Return the straight skeleton of the simple polygon, in either orientation. Holes are
+    /// not supported.
+    pub fn straight_skeleton(&self) -> StraightSkeleton {
+        let n = self.points.len();
+        let mut polygon = self.clone();
+        let reversed = polygon.signed_area() < 0.0;
+        if reversed {
+            polygon.points.reverse();
+        }
+        // Counter clockwise edge i corresponds to input edge input_edge(i)
+        let input_edge = |i: usize| if reversed { (2 * n - 2 - i) % n } else { i };
+        let lines: Vec<EdgeLine> = polygon
+            .edges()
+            .map(|e| {
+                let (dx, dy) = (e.point_b.x - e.point_a.x, e.point_b.y - e.point_a.y);
+                let length = (dx * dx + dy * dy).sqrt().max(f32::MIN_POSITIVE);
+                let normal = (-dy / length, dx / length);
+                EdgeLine {
+                    normal,
+                    c: normal.0 * e.point_a.x + normal.1 * e.point_a.y,
+                }
+            })
+            .collect();
+        let mut rings: Vec<Vec<WaveVertex>> = vec![polygon
+            .points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| WaveVertex {
+                position: *p,
+                velocity: (0.0, 0.0),
+                origin: *p,
+                origin_offset: 0.0,
+                line: i,
+            })
+            .collect()];
+        let mut edges = Vec::new();
+        let mut time = 0.0;
+        for _ in 0..(4 * n * n + 16) {
+            rings.retain(|r| !r.is_empty());
+            for ring in &mut rings {
+                update_velocities(ring, &lines);
+            }
+            let (dt, event) = match next_event(&rings, &lines, time) {
+                Some(found) => found,
+                None => break,
+            };
+            time += dt;
+            for vertex in rings.iter_mut().flatten() {
+                vertex.position = advance(vertex, dt);
+            }
+            match event {
+                Event::Edge { ring, index } => {
+                    let r = &mut rings[ring];
+                    let len = r.len();
+                    let next = (index + 1) % len;
+                    let (a, b) = (r[index], r[next]);
+                    let meeting = midpoint(a.position, b.position);
+                    let previous_line = r[(index + len - 1) % len].line;
+                    emit(&mut edges, &a, meeting, time, previous_line);
+                    emit(&mut edges, &b, meeting, time, a.line);
+                    r[index] = WaveVertex {
+                        position: meeting,
+                        velocity: (0.0, 0.0),
+                        origin: meeting,
+                        origin_offset: time,
+                        line: b.line,
+                    };
+                    r.remove(next);
+                }
+                Event::Split {
+                    ring,
+                    index,
+                    target,
+                } => {
+                    let r = rings.swap_remove(ring);
+                    let len = r.len();
+                    let reflex = r[index];
+                    let hit = reflex.position;
+                    let previous = r[(index + len - 1) % len];
+                    emit(&mut edges, &reflex, hit, time, previous.line);
+                    let start = |line: usize| WaveVertex {
+                        position: hit,
+                        velocity: (0.0, 0.0),
+                        origin: hit,
+                        origin_offset: time,
+                        line,
+                    };
+                    let mut first = vec![start(reflex.line)];
+                    let mut i = (index + 1) % len;
+                    while i != (target + 1) % len {
+                        first.push(r[i]);
+                        i = (i + 1) % len;
+                    }
+                    let mut second = vec![start(r[target].line)];
+                    let mut i = (target + 1) % len;
+                    while i != index {
+                        second.push(r[i]);
+                        i = (i + 1) % len;
+                    }
+                    rings.push(first);
+                    rings.push(second);
+                }
+            }
+            // Wavefronts of one or two vertices have collapsed into a point or a ridge
+            for r in rings.iter_mut().filter(|r| r.len() < 3) {
+                if r.len() == 2 {
+                    let (a, b) = (r[0], r[1]);
+                    emit(&mut edges, &a, a.position, time, b.line);
+                    emit(&mut edges, &b, b.position, time, a.line);
+                    push_edge(
+                        &mut edges,
+                        a.position,
+                        b.position,
+                        time,
+                        time,
+                        (a.line, b.line),
+                    );
+                } else if let Some(a) = r.first().copied() {
+                    emit(&mut edges, &a, a.position, time, a.line);
+                }
+                r.clear();
+            }
+        }
+        for edge in &mut edges {
+            edge.faces = (input_edge(edge.faces.0), input_edge(edge.faces.1));
+        }
+        StraightSkeleton {
+            edges,
+            polygon: self.clone(),
+        }
+    }
+}
+
+impl StraightSkeleton {
+    /// Return the faces of all polygon edges in edge order, each bounded by its polygon
+    /// edge and the skeleton edges between the edge's end points
+    pub fn faces(&self) -> Vec<SkeletonFace> {
+        let points = &self.polygon.points;
+        let n = points.len();
+        let reversed = self.polygon.signed_area() < 0.0;
+        let scale = self
+            .polygon
+            .edges()
+            .map(|e| e.length())
+            .fold(0.0f32, f32::max)
+            .max(1.0);
+        let same = |a: Point, b: Point| (a.x - b.x).abs() + (a.y - b.y).abs() < 1e-4 * scale;
+        (0..n)
+            .map(|edge| {
+                // Walk the face counter clockwise, so it starts along the edge when the
+                // polygon is counter clockwise and against it otherwise
+                let (a, b) = if reversed {
+                    (points[(edge + 1) % n], points[edge])
+                } else {
+                    (points[edge], points[(edge + 1) % n])
+                };
+                let mut outline = vec![a, b];
+                let mut offsets = vec![0.0, 0.0];
+                let mut used = vec![false; self.edges.len()];
+                let mut current = b;
+                for _ in 0..self.edges.len() {
+                    let next = self.edges.iter().enumerate().find(|(i, e)| {
+                        !used[*i]
+                            && (e.faces.0 == edge || e.faces.1 == edge)
+                            && (same(e.start, current) || same(e.end, current))
+                    });
+                    let (i, e) = match next {
+                        Some(found) => found,
+                        None => break,
+                    };
+                    used[i] = true;
+                    let (point, offset) = if same(e.start, current) {
+                        (e.end, e.end_offset)
+                    } else {
+                        (e.start, e.start_offset)
+                    };
+                    if same(point, a) {
+                        break;
+                    }
+                    outline.push(point);
+                    offsets.push(offset);
+                    current = point;
+                }
+                SkeletonFace {
+                    edge,
+                    points: outline,
+                    offsets,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Set the vertex velocities, so every vertex stays on both of its moving edge lines
+fn update_velocities(ring: &mut [WaveVertex], lines: &[EdgeLine]) {
+    let len = ring.len();
+    for i in 0..len {
+        let incoming = lines[ring[(i + len - 1) % len].line].normal;
+        let outgoing = lines[ring[i].line].normal;
+        ring[i].velocity = bisector(incoming, outgoing);
+    }
+}
+
+/// Velocity v with n1 . v = 1 and n2 . v = 1
+fn bisector(n1: (f32, f32), n2: (f32, f32)) -> (f32, f32) {
+    let det = n1.0 * n2.1 - n1.1 * n2.0;
+    if det.abs() < 1e-6 {
+        // Parallel edges move along, opposite edges pin the vertex between them
+        if n1.0 * n2.0 + n1.1 * n2.1 > 0.0 {
+            n1
+        } else {
+            (0.0, 0.0)
+        }
+    } else {
+        ((n2.1 - n1.1) / det, (n1.0 - n2.0) / det)
+    }
+}
+
+fn advance(vertex: &WaveVertex, dt: f32) -> Point {
+    Point {
+        x: vertex.position.x + vertex.velocity.0 * dt,
+        y: vertex.position.y + vertex.velocity.1 * dt,
+    }
+}
+
+/// Find the earliest event after time and return it with its delay
+fn next_event(rings: &[Vec<WaveVertex>], lines: &[EdgeLine], time: f32) -> Option<(f32, Event)> {
+    let mut best: Option<(f32, Event)> = None;
+    let mut consider = |dt: f32, event: Event| {
+        let earlier = match &best {
+            Some((best_dt, _)) => dt < *best_dt - EVENT_TOLERANCE,
+            None => true,
+        };
+        if dt >= -EVENT_TOLERANCE && earlier {
+            best = Some((dt.max(0.0), event));
+        }
+    };
+    for (ring_index, ring) in rings.iter().enumerate() {
+        let len = ring.len();
+        for i in 0..len {
+            let (a, b) = (ring[i], ring[(i + 1) % len]);
+            let normal = lines[a.line].normal;
+            let direction = (normal.1, -normal.0);
+            let gap = (b.position.x - a.position.x) * direction.0
+                + (b.position.y - a.position.y) * direction.1;
+            let closing = (b.velocity.0 - a.velocity.0) * direction.0
+                + (b.velocity.1 - a.velocity.1) * direction.1;
+            if closing < 0.0 {
+                consider(
+                    -gap / closing,
+                    Event::Edge {
+                        ring: ring_index,
+                        index: i,
+                    },
+                );
+            }
+        }
+        for i in 0..len {
+            let previous = ring[(i + len - 1) % len];
+            let vertex = ring[i];
+            let (n_in, n_out) = (lines[previous.line].normal, lines[vertex.line].normal);
+            // Reflex vertices turn right on a counter clockwise wavefront
+            if n_in.1 * n_out.0 - n_in.0 * n_out.1 <= 1e-6 {
+                continue;
+            }
+            for target in 0..len {
+                if target == i || (target + 1) % len == i {
+                    continue;
+                }
+                let line = lines[ring[target].line];
+                let ahead = dot(line.normal, vertex.position) - (line.c + time);
+                let approach =
+                    line.normal.0 * vertex.velocity.0 + line.normal.1 * vertex.velocity.1 - 1.0;
+                if ahead < 0.0 || approach >= 0.0 {
+                    continue;
+                }
+                let dt = -ahead / approach;
+                let hit = advance(&vertex, dt);
+                let (a, b) = (
+                    advance(&ring[target], dt),
+                    advance(&ring[(target + 1) % len], dt),
+                );
+                let (ex, ey) = (b.x - a.x, b.y - a.y);
+                let length_squared = ex * ex + ey * ey;
+                if length_squared == 0.0 {
+                    continue;
+                }
+                let t = ((hit.x - a.x) * ex + (hit.y - a.y) * ey) / length_squared;
+                if (0.0..=1.0).contains(&t) {
+                    consider(
+                        dt,
+                        Event::Split {
+                            ring: ring_index,
+                            index: i,
+                            target,
+                        },
+                    );
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Record the skeleton edge traced by vertex until it ended at end
+fn emit(edges: &mut Vec<SkeletonEdge>, vertex: &WaveVertex, end: Point, time: f32, other: usize) {
+    push_edge(
+        edges,
+        vertex.origin,
+        end,
+        vertex.origin_offset,
+        time,
+        (other, vertex.line),
+    );
+}
+
+fn push_edge(
+    edges: &mut Vec<SkeletonEdge>,
+    start: Point,
+    end: Point,
+    start_offset: f32,
+    end_offset: f32,
+    faces: (usize, usize),
+) {
+    if (start.x - end.x).abs() + (start.y - end.y).abs() > 1e-6 {
+        edges.push(SkeletonEdge {
+            start,
+            end,
+            start_offset,
+            end_offset,
+            faces,
+        });
+    }
+}
+
+fn dot(a: (f32, f32), p: Point) -> f32 {
+    a.0 * p.x + a.1 * p.y
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Area;
+
+    fn face_area_sum(skeleton: &StraightSkeleton) -> f32 {
+        skeleton
+            .faces()
+            .into_iter()
+            .map(|f| Polygon::new(f.points).area())
+            .sum()
+    }
+
+    #[test]
+    fn test_rectangle_roof() {
+        let rectangle = Polygon::new(vec![p(0.0, 0.0), p(4.0, 0.0), p(4.0, 2.0), p(0.0, 2.0)]);
+        let skeleton = rectangle.straight_skeleton();
+        assert_eq!(5, skeleton.edges.len());
+        let ridge = skeleton
+            .edges
+            .iter()
+            .find(|e| e.start_offset > 0.0)
+            .unwrap();
+        assert!((ridge.start.y - 1.0).abs() < 1e-5 && (ridge.end.y - 1.0).abs() < 1e-5);
+        assert!(((ridge.start.x - ridge.end.x).abs() - 2.0).abs() < 1e-5);
+        let faces = skeleton.faces();
+        assert_eq!(4, faces[0].points.len());
+        assert_eq!(3, faces[1].points.len());
+        assert!((faces[0].offsets[2] - 1.0).abs() < 1e-5);
+        assert!((face_area_sum(&skeleton) - 8.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_concave_footprint() {
+        // L shaped building, ordered clockwise
+        let footprint = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(0.0, 4.0),
+            p(2.0, 4.0),
+            p(2.0, 2.0),
+            p(6.0, 2.0),
+            p(6.0, 0.0),
+        ]);
+        let skeleton = footprint.straight_skeleton();
+        for edge in &skeleton.edges {
+            assert!(edge.end_offset <= 1.0 + 1e-5);
+            assert!(edge.start_offset <= edge.end_offset);
+        }
+        assert!((face_area_sum(&skeleton) - footprint.area()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_notch_splits_wavefront() {
+        let notched = Polygon::new(vec![
+            p(0.0, 0.0),
+            p(10.0, 0.0),
+            p(10.0, 4.0),
+            p(5.5, 4.0),
+            p(5.0, 1.0),
+            p(4.5, 4.0),
+            p(0.0, 4.0),
+        ]);
+        let skeleton = notched.straight_skeleton();
+        assert!(skeleton.edges.iter().all(|e| notched.contains(e.end)));
+        assert!((face_area_sum(&skeleton) - notched.area()).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,331 @@
+//! A small geometric constraint solver in the spirit of a CAD sketch. Points and segments
+//! are declared with rough initial positions, constraints describe the intended geometry
+//! and solve moves the free points until all constraints hold.
+
+use crate::{transcendental, Point, Residuals};
+use std::fmt;
+
+/// Iteration limit of the Levenberg-Marquardt solver.
+const MAX_SOLVER_ITERATIONS: usize = 200;
+/// Largest constraint violation accepted as solved.
+const SOLVER_TOLERANCE: f64 = 1e-5;
+
+/// Handle of a point in a Sketch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PointId(usize);
+
+/// Handle of a segment in a Sketch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SegmentId(usize);
+
+/// Relation the solver has to establish. Angles are in degrees and measured counter
+/// clockwise from the first to the second segment; as segments are lines without direction,
+/// an angle and the same angle plus 180 degrees are equivalent.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Constraint {
+    Coincident(PointId, PointId),
+    Length(SegmentId, f32),
+    Angle(SegmentId, SegmentId, f32),
+    Perpendicular(SegmentId, SegmentId),
+    Parallel(SegmentId, SegmentId),
+}
+
+/// Returned by Sketch::solve when the constraints could not all be satisfied, because they
+/// contradict each other or the start positions are too far off.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SketchError {
+    /// The remaining constraint violations
+    pub residuals: Residuals,
+}
+
+impl fmt::Display for SketchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sketch constraints not satisfied, largest violation {}",
+            self.residuals.max
+        )
+    }
+}
+
+impl std::error::Error for SketchError {}
+
+/// Collection of points, segments between them and constraints.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sketch {
+    points: Vec<Point>,
+    fixed: Vec<bool>,
+    segments: Vec<(PointId, PointId)>,
+    constraints: Vec<Constraint>,
+}
+
+impl Sketch {
+    pub fn new() -> Sketch {
+        Sketch::default()
+    }
+
+    /// Add a free point at its initial position
+    pub fn add_point(&mut self, position: Point) -> PointId {
+        self.points.push(position);
+        self.fixed.push(false);
+        PointId(self.points.len() - 1)
+    }
+
+    /// Add a segment between two points of this sketch
+    pub fn add_segment(&mut self, a: PointId, b: PointId) -> SegmentId {
+        self.segments.push((a, b));
+        SegmentId(self.segments.len() - 1)
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Keep the point at its current position while solving
+    pub fn fix(&mut self, point: PointId) {
+        self.fixed[point.0] = true;
+    }
+
+    pub fn point(&self, point: PointId) -> Point {
+        self.points[point.0]
+    }
+
+    pub fn segment(&self, segment: SegmentId) -> (Point, Point) {
+        let (a, b) = self.segments[segment.0];
+        (self.points[a.0], self.points[b.0])
+    }
+
+    /// Move the free points until all constraints hold, by Levenberg-Marquardt iteration on
+    /// the constraint violations. Under constrained sketches end up close to their initial
+    /// positions. The points keep the best found positions even if solving fails.
+    pub fn solve(&mut self) -> Result<Residuals, SketchError> {
+        let free: Vec<usize> = (0..self.points.len()).filter(|i| !self.fixed[*i]).collect();
+        let mut x: Vec<f64> = free
+            .iter()
+            .flat_map(|i| vec![self.points[*i].x as f64, self.points[*i].y as f64])
+            .collect();
+        let mut r = self.residuals(&free, &x);
+        let mut cost = squared_norm(&r);
+        let mut damping = 1e-3;
+        for _ in 0..MAX_SOLVER_ITERATIONS {
+            if max_abs(&r) < SOLVER_TOLERANCE || x.is_empty() {
+                break;
+            }
+            let jacobian = self.jacobian(&free, &x, &r);
+            let n = x.len();
+            let mut normal = vec![vec![0.0; n]; n];
+            let mut gradient = vec![0.0; n];
+            for (row, residual) in jacobian.iter().zip(&r) {
+                for i in 0..n {
+                    gradient[i] -= row[i] * residual;
+                    for j in 0..n {
+                        normal[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+            for (i, row) in normal.iter_mut().enumerate() {
+                row[i] += damping;
+            }
+            let step = match solve_linear(normal, gradient) {
+                Some(step) => step,
+                None => break,
+            };
+            let candidate: Vec<f64> = x.iter().zip(&step).map(|(x, s)| x + s).collect();
+            let candidate_r = self.residuals(&free, &candidate);
+            let candidate_cost = squared_norm(&candidate_r);
+            if candidate_cost < cost {
+                x = candidate;
+                r = candidate_r;
+                cost = candidate_cost;
+                damping = (damping / 10.0).max(1e-12);
+            } else {
+                damping *= 10.0;
+                if damping > 1e12 {
+                    break;
+                }
+            }
+        }
+        for (k, i) in free.iter().enumerate() {
+            self.points[*i] = Point {
+                x: x[2 * k] as f32,
+                y: x[2 * k + 1] as f32,
+            };
+        }
+        let residuals = Residuals::from_distances(r.iter().map(|v| *v as f32));
+        if r.is_empty() || max_abs(&r) < SOLVER_TOLERANCE {
+            Ok(residuals)
+        } else {
+            Err(SketchError { residuals })
+        }
+    }
+
+    /// Constraint violations with the free points at the coordinates x
+    fn residuals(&self, free: &[usize], x: &[f64]) -> Vec<f64> {
+        let mut positions: Vec<(f64, f64)> = self
+            .points
+            .iter()
+            .map(|p| (p.x as f64, p.y as f64))
+            .collect();
+        for (k, i) in free.iter().enumerate() {
+            positions[*i] = (x[2 * k], x[2 * k + 1]);
+        }
+        let direction = |segment: SegmentId| {
+            let (a, b) = self.segments[segment.0];
+            let (pa, pb) = (positions[a.0], positions[b.0]);
+            (pb.0 - pa.0, pb.1 - pa.1)
+        };
+        let mut residuals = Vec::with_capacity(self.constraints.len() * 2);
+        for constraint in &self.constraints {
+            match *constraint {
+                Constraint::Coincident(a, b) => {
+                    residuals.push(positions[b.0].0 - positions[a.0].0);
+                    residuals.push(positions[b.0].1 - positions[a.0].1);
+                }
+                Constraint::Length(segment, length) => {
+                    let (dx, dy) = direction(segment);
+                    residuals.push((dx * dx + dy * dy).sqrt() - length as f64);
+                }
+                Constraint::Angle(first, second, degrees) => {
+                    // sin(actual - wanted), zero when the angle matches
+                    let (u, v) = (direction(first), direction(second));
+                    let (sin, cos) = transcendental::sin_cos((degrees as f64).to_radians());
+                    residuals.push(normalized(cross(u, v) * cos - dot(u, v) * sin, u, v));
+                }
+                Constraint::Perpendicular(first, second) => {
+                    let (u, v) = (direction(first), direction(second));
+                    residuals.push(normalized(dot(u, v), u, v));
+                }
+                Constraint::Parallel(first, second) => {
+                    let (u, v) = (direction(first), direction(second));
+                    residuals.push(normalized(cross(u, v), u, v));
+                }
+            }
+        }
+        residuals
+    }
+
+    /// Jacobian of the residuals by forward differences, one row per residual
+    fn jacobian(&self, free: &[usize], x: &[f64], r: &[f64]) -> Vec<Vec<f64>> {
+        let mut jacobian = vec![vec![0.0; x.len()]; r.len()];
+        let mut shifted = x.to_vec();
+        for column in 0..x.len() {
+            let h = 1e-7 * x[column].abs().max(1.0);
+            shifted[column] = x[column] + h;
+            let r_shifted = self.residuals(free, &shifted);
+            shifted[column] = x[column];
+            for (row, (a, b)) in jacobian.iter_mut().zip(r_shifted.iter().zip(r)) {
+                row[column] = (a - b) / h;
+            }
+        }
+        jacobian
+    }
+}
+
+/// Divide by the lengths of both directions, zero for degenerate segments
+fn normalized(value: f64, u: (f64, f64), v: (f64, f64)) -> f64 {
+    let lengths = (dot(u, u) * dot(v, v)).sqrt();
+    if lengths == 0.0 {
+        0.0
+    } else {
+        value / lengths
+    }
+}
+
+fn dot(u: (f64, f64), v: (f64, f64)) -> f64 {
+    u.0 * v.0 + u.1 * v.1
+}
+
+fn cross(u: (f64, f64), v: (f64, f64)) -> f64 {
+    u.0 * v.1 - u.1 * v.0
+}
+
+fn squared_norm(values: &[f64]) -> f64 {
+    values.iter().map(|v| v * v).sum()
+}
+
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |m, v| m.max(v.abs()))
+}
+
+/// Solve the square system m * x = v by Gaussian elimination with partial pivoting, None
+/// if m is singular
+fn solve_linear(mut m: Vec<Vec<f64>>, mut v: Vec<f64>) -> Option<Vec<f64>> {
+    let n = v.len();
+    for column in 0..n {
+        let pivot = (column..n).max_by(|a, b| {
+            m[*a][column]
+                .abs()
+                .partial_cmp(&m[*b][column].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if m[pivot][column] == 0.0 {
+            return None;
+        }
+        m.swap(column, pivot);
+        v.swap(column, pivot);
+        let (upper, lower) = m.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot_value;
+            }
+            v[column + 1 + offset] -= factor * v[column];
+        }
+    }
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| m[row][k] * x[k]).sum();
+        x[row] = (v[row] - sum) / m[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_solve_rectangle() {
+        let mut sketch = Sketch::new();
+        let a = sketch.add_point(p(0.0, 0.0));
+        let b = sketch.add_point(p(3.8, 0.3));
+        let c = sketch.add_point(p(4.2, 2.1));
+        let d = sketch.add_point(p(-0.2, 1.7));
+        sketch.fix(a);
+        let ab = sketch.add_segment(a, b);
+        let bc = sketch.add_segment(b, c);
+        let cd = sketch.add_segment(c, d);
+        let da = sketch.add_segment(d, a);
+        sketch.add_constraint(Constraint::Angle(ab, da, 90.0));
+        sketch.add_constraint(Constraint::Angle(da, cd, 90.0));
+        sketch.add_constraint(Constraint::Parallel(ab, cd));
+        sketch.add_constraint(Constraint::Perpendicular(ab, bc));
+        sketch.add_constraint(Constraint::Length(ab, 4.0));
+        sketch.add_constraint(Constraint::Length(bc, 2.0));
+        let residuals = sketch.solve().unwrap();
+        assert!(residuals.max < 1e-4);
+        assert_eq!(p(0.0, 0.0), sketch.point(a));
+        let (start, end) = sketch.segment(ab);
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        assert!(((dx * dx + dy * dy).sqrt() - 4.0).abs() < 1e-4);
+        let (c, d) = (sketch.point(c), sketch.point(d));
+        let opposite = ((c.x - d.x).powi(2) + (c.y - d.y).powi(2)).sqrt();
+        assert!((opposite - 4.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_contradicting_constraints() {
+        let mut sketch = Sketch::new();
+        let a = sketch.add_point(p(0.0, 0.0));
+        let b = sketch.add_point(p(1.0, 0.0));
+        let c = sketch.add_point(p(1.0, 1.0));
+        sketch.add_constraint(Constraint::Coincident(b, c));
+        let ab = sketch.add_segment(a, b);
+        let ac = sketch.add_segment(a, c);
+        sketch.add_constraint(Constraint::Length(ab, 1.0));
+        sketch.add_constraint(Constraint::Length(ac, 2.0));
+        assert!(sketch.solve().is_err());
+    }
+}
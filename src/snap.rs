@@ -0,0 +1,260 @@
+use std::collections::{BTreeSet, HashSet};
+
+use crate::sweep::segment_crossings;
+use crate::{Circle, OrderedPoint, Point, Polygon, Polyline, Rect, Segment, Triangle};
+
+/// Distance below which two points count as the same point. Routines that intersect or
+/// combine geometry take a Tolerance, so nearly coincident vertices are welded the same way
+/// everywhere.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Tolerance {
+    pub distance: f32,
+}
+
+impl Default for Tolerance {
+    fn default() -> Tolerance {
+        Tolerance { distance: 1e-6 }
+    }
+}
+
+impl Tolerance {
+    pub fn new(distance: f32) -> Tolerance {
+        Tolerance { distance }
+    }
+
+    /// Return true if the points are at most the tolerance distance apart
+    pub fn coincident(&self, a: Point, b: Point) -> bool {
+        (a.x - b.x).powi(2) + (a.y - b.y).powi(2) <= self.distance * self.distance
+    }
+
+    /// Return the points with every point replaced by the first earlier point coincident
+    /// with it. The result only depends on the order of the input, so welding is
+    /// deterministic.
+    pub fn weld(&self, points: &[Point]) -> Vec<Point> {
+        let mut welded: Vec<Point> = Vec::with_capacity(points.len());
+        for point in points {
+            let representative = welded
+                .iter()
+                .find(|w| self.coincident(**w, *point))
+                .copied()
+                .unwrap_or(*point);
+            welded.push(representative);
+        }
+        welded
+    }
+
+    /// Return the points without consecutive coincident points, keeping the first of each run
+    pub fn dedup(&self, points: &[Point]) -> Vec<Point> {
+        let mut result: Vec<Point> = Vec::with_capacity(points.len());
+        for point in points {
+            let duplicate = match result.last() {
+                Some(last) => self.coincident(*last, *point),
+                None => false,
+            };
+            if !duplicate {
+                result.push(*point);
+            }
+        }
+        result
+    }
+}
+
+/// Geometry whose defining points can be rounded to a regular grid.
+pub trait Snap {
+    /// Return a copy with every defining point moved to the nearest multiple of spacing.
+    /// Lengths such as a radius are rounded the same way.
+    fn snap_to_grid(&self, spacing: f32) -> Self;
+}
+
+fn snap_value(value: f32, spacing: f32) -> f32 {
+    (value / spacing).round() * spacing
+}
+
+impl Snap for Point {
+    fn snap_to_grid(&self, spacing: f32) -> Point {
+        Point {
+            x: snap_value(self.x, spacing),
+            y: snap_value(self.y, spacing),
+        }
+    }
+}
+
+impl Snap for Segment {
+    fn snap_to_grid(&self, spacing: f32) -> Segment {
+        Segment::new(
+            self.point_a.snap_to_grid(spacing),
+            self.point_b.snap_to_grid(spacing),
+        )
+    }
+}
+
+impl Snap for Rect {
+    fn snap_to_grid(&self, spacing: f32) -> Rect {
+        Rect::new(
+            self.min.snap_to_grid(spacing),
+            self.max.snap_to_grid(spacing),
+        )
+    }
+}
+
+impl Snap for Circle {
+    fn snap_to_grid(&self, spacing: f32) -> Circle {
+        Circle::new(
+            self.center.snap_to_grid(spacing),
+            snap_value(self.radius, spacing),
+        )
+    }
+}
+
+impl Snap for Triangle {
+    fn snap_to_grid(&self, spacing: f32) -> Triangle {
+        Triangle::new(
+            self.point_a().snap_to_grid(spacing),
+            self.point_b().snap_to_grid(spacing),
+            self.point_c().snap_to_grid(spacing),
+        )
+    }
+}
+
+impl Snap for Polygon {
+    fn snap_to_grid(&self, spacing: f32) -> Polygon {
+        Polygon::new(
+            self.points
+                .iter()
+                .map(|p| p.snap_to_grid(spacing))
+                .collect(),
+        )
+    }
+}
+
+impl Snap for Polyline {
+    fn snap_to_grid(&self, spacing: f32) -> Polyline {
+        Polyline::new(
+            self.points
+                .iter()
+                .map(|p| p.snap_to_grid(spacing))
+                .collect(),
+        )
+    }
+}
+
+/// Return the segments snap rounded to a grid of the given spacing. Every endpoint and
+/// crossing marks its grid cell as hot, and each segment is rerouted through the centers of
+/// all hot cells it passes, in order. The results only meet at grid points, so the topology
+/// no longer depends on how f32 rounded the crossings. Duplicate and zero length pieces are
+/// left out.
+pub fn snap_round(segments: &[Segment], spacing: f32) -> Vec<Segment> {
+    if spacing <= 0.0 {
+        return segments.to_vec();
+    }
+    let cell = |p: Point| {
+        (
+            (p.x / spacing).round() as i64,
+            (p.y / spacing).round() as i64,
+        )
+    };
+    let mut hot: BTreeSet<(i64, i64)> = BTreeSet::new();
+    for segment in segments {
+        hot.insert(cell(segment.point_a));
+        hot.insert(cell(segment.point_b));
+    }
+    for (_, _, point) in segment_crossings(segments, Tolerance::default(), |_, _| false) {
+        hot.insert(cell(point));
+    }
+    let centers: Vec<Point> = hot
+        .iter()
+        .map(|(i, j)| Point {
+            x: *i as f32 * spacing,
+            y: *j as f32 * spacing,
+        })
+        .collect();
+
+    let mut seen: HashSet<(OrderedPoint, OrderedPoint)> = HashSet::new();
+    let mut result = Vec::new();
+    for segment in segments {
+        let (a, b) = (segment.point_a, segment.point_b);
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let length_squared = (dx * dx + dy * dy).max(f32::MIN_POSITIVE);
+        let mut route: Vec<(f32, Point)> = centers
+            .iter()
+            .filter(|c| passes_cell(segment, **c, spacing / 2.0))
+            .map(|c| (((c.x - a.x) * dx + (c.y - a.y) * dy) / length_squared, *c))
+            .collect();
+        route.sort_by(|p, q| p.0.total_cmp(&q.0));
+        for pair in route.windows(2) {
+            let (start, end) = (pair[0].1, pair[1].1);
+            let key = if OrderedPoint(start) < OrderedPoint(end) {
+                (OrderedPoint(start), OrderedPoint(end))
+            } else {
+                (OrderedPoint(end), OrderedPoint(start))
+            };
+            if start != end && seen.insert(key) {
+                result.push(Segment::new(start, end));
+            }
+        }
+    }
+    result
+}
+
+/// Return true if the segment touches the square of half width half around center
+fn passes_cell(segment: &Segment, center: Point, half: f32) -> bool {
+    let cell = Rect {
+        min: Point {
+            x: center.x - half,
+            y: center.y - half,
+        },
+        max: Point {
+            x: center.x + half,
+            y: center.y + half,
+        },
+    };
+    segment.clip_to_rect(&cell).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_snap_to_grid() {
+        assert_eq!(p(0.5, -1.0), p(0.6, -1.2).snap_to_grid(0.5));
+        let circle = Circle::new(p(1.1, 2.9), 0.8).snap_to_grid(1.0);
+        assert_eq!(Circle::new(p(1.0, 3.0), 1.0), circle);
+        let square = Polygon::new(vec![p(0.1, 0.0), p(2.05, -0.1), p(1.9, 2.1)]);
+        assert_eq!(
+            vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0)],
+            square.snap_to_grid(1.0).points
+        );
+    }
+
+    #[test]
+    fn test_weld_and_dedup() {
+        let tolerance = Tolerance::new(0.01);
+        let points = [p(0.0, 0.0), p(1.0, 0.0), p(0.005, 0.0), p(1.0, 0.001)];
+        assert_eq!(
+            vec![p(0.0, 0.0), p(1.0, 0.0), p(0.0, 0.0), p(1.0, 0.0)],
+            tolerance.weld(&points)
+        );
+        let points = [p(0.0, 0.0), p(0.001, 0.0), p(1.0, 0.0), p(1.0, 0.0)];
+        assert_eq!(vec![p(0.0, 0.0), p(1.0, 0.0)], tolerance.dedup(&points));
+    }
+
+    #[test]
+    fn test_snap_round() {
+        let segments = [
+            Segment::new(p(0.0, 0.0), p(4.0, 4.1)),
+            Segment::new(p(0.0, 4.0), p(4.0, 0.1)),
+        ];
+        let rounded = snap_round(&segments, 1.0);
+        assert_eq!(4, rounded.len());
+        let meeting = rounded
+            .iter()
+            .filter(|s| s.point_a == p(2.0, 2.0) || s.point_b == p(2.0, 2.0))
+            .count();
+        assert_eq!(4, meeting);
+        assert!(rounded
+            .iter()
+            .all(|s| s.point_a.snap_to_grid(1.0) == s.point_a));
+    }
+}
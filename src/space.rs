@@ -0,0 +1,137 @@
+//! Points, triangles and planes in three dimensions, for height fields and scans. Like the plane
+//! types they are plain values in f32.
+
+/// Point in space, also used as a direction.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Point3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Point3 {
+    pub fn new(x: f32, y: f32, z: f32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// Return the difference self - other, the direction from other to self
+    pub fn sub(&self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    pub fn dot(&self, other: Point3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: Point3) -> Point3 {
+        Point3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Return the distance from the origin, the length as a direction
+    pub fn norm(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+}
+
+/// Triangle in space with corners a, b and c.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct Triangle3 {
+    pub a: Point3,
+    pub b: Point3,
+    pub c: Point3,
+}
+
+impl Triangle3 {
+    pub fn new(a: Point3, b: Point3, c: Point3) -> Triangle3 {
+        Triangle3 { a, b, c }
+    }
+
+    /// Return the unit normal, pointing to the side from which a, b and c run counter
+    /// clockwise. None for degenerate triangles.
+    pub fn normal(&self) -> Option<Point3> {
+        let normal = self.b.sub(self.a).cross(self.c.sub(self.a));
+        let length = normal.norm();
+        if length == 0.0 {
+            return None;
+        }
+        Some(Point3::new(
+            normal.x / length,
+            normal.y / length,
+            normal.z / length,
+        ))
+    }
+
+    pub fn area(&self) -> f32 {
+        self.b.sub(self.a).cross(self.c.sub(self.a)).norm() / 2.0
+    }
+}
+
+/// Plane of the points p with normal . p = offset. Planes built by through or fitted are
+/// normalized so that signed_distance is a true distance.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Plane {
+    pub normal: Point3,
+    pub offset: f32,
+}
+
+impl Plane {
+    pub fn new(normal: Point3, offset: f32) -> Plane {
+        Plane { normal, offset }
+    }
+
+    /// Return the plane through the point with the given normal, None for a zero normal
+    pub fn through(point: Point3, normal: Point3) -> Option<Plane> {
+        let length = normal.norm();
+        if length == 0.0 {
+            return None;
+        }
+        let unit = Point3::new(normal.x / length, normal.y / length, normal.z / length);
+        Some(Plane::new(unit, unit.dot(point)))
+    }
+
+    /// Return the distance of the point from the plane, positive on the side the normal
+    /// points to
+    pub fn signed_distance(&self, point: Point3) -> f32 {
+        (self.normal.dot(point) - self.offset) / self.normal.norm()
+    }
+
+    pub fn distance_to(&self, point: Point3) -> f32 {
+        self.signed_distance(point).abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_triangle3() {
+        let triangle = Triangle3::new(
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(2.0, 0.0, 1.0),
+            Point3::new(0.0, 2.0, 1.0),
+        );
+        assert_eq!(2.0, triangle.area());
+        assert_eq!(Some(Point3::new(0.0, 0.0, 1.0)), triangle.normal());
+        let flat = Triangle3::new(triangle.a, triangle.b, triangle.b);
+        assert_eq!(None, flat.normal());
+    }
+
+    #[test]
+    fn test_plane_distance() {
+        let plane = Plane::through(Point3::new(0.0, 0.0, 2.0), Point3::new(0.0, 0.0, -3.0));
+        let plane = plane.unwrap();
+        assert_eq!(-1.0, plane.signed_distance(Point3::new(5.0, 1.0, 3.0)));
+        assert_eq!(2.0, plane.distance_to(Point3::new(0.0, 0.0, 0.0)));
+        assert_eq!(
+            None,
+            Plane::through(Point3::new(1.0, 1.0, 1.0), Point3::new(0.0, 0.0, 0.0))
+        );
+    }
+}
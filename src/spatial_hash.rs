@@ -0,0 +1,158 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::Rect;
+
+/// Handle of an item stored in a broad phase structure.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemId(pub usize);
+
+/// Uniform grid of square cells hashing items by their bounding boxes. Works best when the
+/// items are spread evenly and about as large as a cell; each item is stored in every cell
+/// its box overlaps.
+#[derive(Debug, Clone)]
+pub struct SpatialHash<T> {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<ItemId>>,
+    items: Vec<Option<(Rect, T)>>,
+}
+
+impl<T> SpatialHash<T> {
+    pub fn new(cell_size: f32) -> SpatialHash<T> {
+        SpatialHash {
+            cell_size,
+            cells: HashMap::new(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Store the item with its bounding box and return its handle
+    pub fn insert(&mut self, bounds: Rect, item: T) -> ItemId {
+        let id = ItemId(self.items.len());
+        self.items.push(Some((bounds, item)));
+        self.link(id, &bounds);
+        id
+    }
+
+    /// Remove the item and return it, None if the handle was already removed
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let (bounds, item) = self.items.get_mut(id.0)?.take()?;
+        self.unlink(id, &bounds);
+        Some(item)
+    }
+
+    /// Move the item to new bounds, return false if the handle was removed
+    pub fn update(&mut self, id: ItemId, bounds: Rect) -> bool {
+        let old = match self.items.get_mut(id.0) {
+            Some(Some((old, _))) => std::mem::replace(old, bounds),
+            _ => return false,
+        };
+        self.unlink(id, &old);
+        self.link(id, &bounds);
+        true
+    }
+
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.items.get(id.0)?.as_ref().map(|(_, item)| item)
+    }
+
+    pub fn bounds(&self, id: ItemId) -> Option<Rect> {
+        self.items.get(id.0)?.as_ref().map(|(bounds, _)| *bounds)
+    }
+
+    /// Return the items whose boxes overlap the region, in ascending order
+    pub fn query(&self, region: &Rect) -> Vec<ItemId> {
+        let mut found = BTreeSet::new();
+        for cell in self.cells_of(region) {
+            for id in self.cells.get(&cell).into_iter().flatten() {
+                if self.bounds(*id).map(|b| b.intersects(region)) == Some(true) {
+                    found.insert(*id);
+                }
+            }
+        }
+        found.into_iter().collect()
+    }
+
+    /// Return every pair of items with overlapping boxes once, as (lower, higher) handle in
+    /// ascending order
+    pub fn pairs(&self) -> Vec<(ItemId, ItemId)> {
+        let mut pairs = BTreeSet::new();
+        for ids in self.cells.values() {
+            for (k, a) in ids.iter().enumerate() {
+                for b in &ids[k + 1..] {
+                    let (a, b) = ((*a).min(*b), (*a).max(*b));
+                    if pairs.contains(&(a, b)) {
+                        continue;
+                    }
+                    if let (Some(ba), Some(bb)) = (self.bounds(a), self.bounds(b)) {
+                        if ba.intersects(&bb) {
+                            pairs.insert((a, b));
+                        }
+                    }
+                }
+            }
+        }
+        pairs.into_iter().collect()
+    }
+
+    fn cells_of(&self, bounds: &Rect) -> impl Iterator<Item = (i32, i32)> {
+        let cell = |v: f32| (v / self.cell_size).floor() as i32;
+        let (i0, i1) = (cell(bounds.min.x), cell(bounds.max.x));
+        let (j0, j1) = (cell(bounds.min.y), cell(bounds.max.y));
+        (i0..=i1).flat_map(move |i| (j0..=j1).map(move |j| (i, j)))
+    }
+
+    fn link(&mut self, id: ItemId, bounds: &Rect) {
+        let cells: Vec<(i32, i32)> = self.cells_of(bounds).collect();
+        for cell in cells {
+            self.cells.entry(cell).or_default().push(id);
+        }
+    }
+
+    fn unlink(&mut self, id: ItemId, bounds: &Rect) {
+        let cells: Vec<(i32, i32)> = self.cells_of(bounds).collect();
+        for cell in cells {
+            if let Some(ids) = self.cells.get_mut(&cell) {
+                ids.retain(|i| *i != id);
+                if ids.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    fn r(x0: f32, y0: f32, x1: f32, y1: f32) -> Rect {
+        Rect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 })
+    }
+
+    #[test]
+    fn test_pairs_and_queries() {
+        let mut hash = SpatialHash::new(1.0);
+        let a = hash.insert(r(0.0, 0.0, 1.5, 1.5), "a");
+        let b = hash.insert(r(1.0, 1.0, 2.0, 2.0), "b");
+        let c = hash.insert(r(5.0, 5.0, 6.0, 6.0), "c");
+        assert_eq!(vec![(a, b)], hash.pairs());
+        assert_eq!(vec![b, c], hash.query(&r(1.8, 1.8, 5.5, 5.5)));
+        assert_eq!(Some(&"c"), hash.get(c));
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut hash = SpatialHash::new(2.0);
+        let a = hash.insert(r(0.0, 0.0, 1.0, 1.0), 1);
+        let b = hash.insert(r(10.0, 10.0, 11.0, 11.0), 2);
+        assert!(hash.pairs().is_empty());
+        assert!(hash.update(b, r(0.5, 0.5, 1.5, 1.5)));
+        assert_eq!(vec![(a, b)], hash.pairs());
+        assert_eq!(Some(1), hash.remove(a));
+        assert_eq!(None, hash.remove(a));
+        assert!(!hash.update(a, r(0.0, 0.0, 1.0, 1.0)));
+        assert!(hash.pairs().is_empty());
+        assert_eq!(vec![b], hash.query(&r(-5.0, -5.0, 5.0, 5.0)));
+    }
+}
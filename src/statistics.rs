@@ -0,0 +1,123 @@
+use crate::{transcendental, Angle, Line, Point};
+
+/// Covariance matrix of a point set, normalized by the number of points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Covariance {
+    pub xx: f32,
+    pub xy: f32,
+    pub yy: f32,
+}
+
+/// Descriptive statistics of a point set, to summarize it before fitting or clustering.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Summary {
+    pub centroid: Point,
+    pub covariance: Covariance,
+    /// Root mean square distance of the points from the centroid
+    pub standard_distance: f32,
+    /// Line through the centroid along the direction of largest variance
+    pub principal_axis: Line,
+}
+
+impl Summary {
+    /// Return the statistics of the points, None if there are no points
+    pub fn of(points: &[Point]) -> Option<Summary> {
+        let centroid = centroid(points)?;
+        let covariance = Covariance::of(points)?;
+        Some(Summary {
+            centroid,
+            covariance,
+            standard_distance: covariance.standard_distance(),
+            principal_axis: Line::from_point_direction(
+                centroid,
+                Angle::from_degrees(covariance.principal_angle()),
+            ),
+        })
+    }
+}
+
+/// Return the mean of the points, None if there are no points
+pub fn centroid(points: &[Point]) -> Option<Point> {
+    if points.is_empty() {
+        return None;
+    }
+    let n = points.len() as f32;
+    let (x, y) = points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x, y + p.y));
+    Some(Point { x: x / n, y: y / n })
+}
+
+impl Covariance {
+    /// Return the covariance of the points around their centroid, None if there are no
+    /// points
+    pub fn of(points: &[Point]) -> Option<Covariance> {
+        let center = centroid(points)?;
+        let n = points.len() as f32;
+        let (xx, xy, yy) = points.iter().fold((0.0, 0.0, 0.0), |(xx, xy, yy), p| {
+            let (dx, dy) = (p.x - center.x, p.y - center.y);
+            (xx + dx * dx, xy + dx * dy, yy + dy * dy)
+        });
+        Some(Covariance {
+            xx: xx / n,
+            xy: xy / n,
+            yy: yy / n,
+        })
+    }
+
+    /// Return the eigenvalues, the variances along the principal axes, largest first
+    pub fn eigenvalues(&self) -> (f32, f32) {
+        let mean = (self.xx + self.yy) / 2.0;
+        let spread = (((self.xx - self.yy) / 2.0).powi(2) + self.xy * self.xy).sqrt();
+        (mean + spread, mean - spread)
+    }
+
+    /// Return the root mean square distance from the centroid, the square root of the trace
+    pub fn standard_distance(&self) -> f32 {
+        (self.xx + self.yy).sqrt()
+    }
+
+    /// Return the angle in degrees of the axis with the largest variance, in (-90, 90]
+    pub fn principal_angle(&self) -> f32 {
+        let angle = 0.5 * transcendental::atan2(2.0 * self.xy, self.xx - self.yy).to_degrees();
+        if angle <= -90.0 {
+            angle + 180.0
+        } else {
+            angle
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_centroid() {
+        assert_eq!(None, centroid(&[]));
+        let center = centroid(&[p(0.0, 0.0), p(4.0, 0.0), p(2.0, 3.0)]).unwrap();
+        assert_eq!(p(2.0, 1.0), center);
+    }
+
+    #[test]
+    fn test_covariance_of_diagonal_points() {
+        let points = [p(-1.0, -1.0), p(0.0, 0.0), p(1.0, 1.0)];
+        let covariance = Covariance::of(&points).unwrap();
+        assert!((covariance.xy - 2.0 / 3.0).abs() < 1e-6);
+        assert!((covariance.principal_angle() - 45.0).abs() < 1e-4);
+        let (major, minor) = covariance.eigenvalues();
+        assert!((major - 4.0 / 3.0).abs() < 1e-6);
+        assert!(minor.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(None, Summary::of(&[]));
+        let points = [p(1.0, 0.0), p(5.0, 0.0), p(3.0, 1.0), p(3.0, -1.0)];
+        let summary = Summary::of(&points).unwrap();
+        assert_eq!(p(3.0, 0.0), summary.centroid);
+        assert!((summary.standard_distance - 2.5f32.sqrt()).abs() < 1e-6);
+        assert!(summary.principal_axis.distance_to(p(-10.0, 0.0)) < 1e-5);
+    }
+}
@@ -0,0 +1,104 @@
+//! Proptest strategies for the basic geometry types, enabled by the proptest feature. All
+//! generated values are finite, vectors have a length and triangles have no angle below one
+//! degree, so property tests exercise real geometry instead of degenerate corner cases.
+
+use crate::{transcendental, Point, Triangle, Vector};
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+/// Smallest angle in degrees of generated triangles.
+const MIN_TRIANGLE_ANGLE: f32 = 1.0;
+/// Shortest length of generated vectors.
+const MIN_VECTOR_LENGTH: f32 = 1e-3;
+
+/// Half open range [min, max) for both coordinates of generated points.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CoordinateRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for CoordinateRange {
+    fn default() -> CoordinateRange {
+        CoordinateRange {
+            min: -1000.0,
+            max: 1000.0,
+        }
+    }
+}
+
+impl CoordinateRange {
+    pub fn new(min: f32, max: f32) -> CoordinateRange {
+        CoordinateRange { min, max }
+    }
+}
+
+/// Return a strategy for points with both coordinates in range
+pub fn points(range: CoordinateRange) -> impl Strategy<Value = Point> {
+    (range.min..range.max, range.min..range.max).prop_map(|(x, y)| Point { x, y })
+}
+
+/// Return a strategy for vectors between points in range, never shorter than 1e-3
+pub fn vectors(range: CoordinateRange) -> impl Strategy<Value = Vector> {
+    (points(range), points(range))
+        .prop_filter("vector without length", |(a, b)| {
+            transcendental::hypot(b.x - a.x, b.y - a.y) >= MIN_VECTOR_LENGTH
+        })
+        .prop_map(|(a, b)| Vector::new(a, b))
+}
+
+/// Return a strategy for triangles with corners in range and no angle below one degree
+pub fn triangles(range: CoordinateRange) -> impl Strategy<Value = Triangle> {
+    (points(range), points(range), points(range))
+        .prop_map(|(a, b, c)| Triangle::new(a, b, c))
+        .prop_filter("degenerate triangle", |t| {
+            let mut t = *t;
+            let smallest = t.try_angles().map(|[a, b, c]| a.min(b).min(c));
+            smallest.is_ok_and(|angle| angle >= MIN_TRIANGLE_ANGLE)
+        })
+}
+
+impl Arbitrary for Point {
+    type Parameters = CoordinateRange;
+    type Strategy = BoxedStrategy<Point>;
+
+    fn arbitrary_with(range: CoordinateRange) -> BoxedStrategy<Point> {
+        points(range).boxed()
+    }
+}
+
+impl Arbitrary for Vector {
+    type Parameters = CoordinateRange;
+    type Strategy = BoxedStrategy<Vector>;
+
+    fn arbitrary_with(range: CoordinateRange) -> BoxedStrategy<Vector> {
+        vectors(range).boxed()
+    }
+}
+
+impl Arbitrary for Triangle {
+    type Parameters = CoordinateRange;
+    type Strategy = BoxedStrategy<Triangle>;
+
+    fn arbitrary_with(range: CoordinateRange) -> BoxedStrategy<Triangle> {
+        triangles(range).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn test_triangle_angles_sum_to_180(mut t in any::<Triangle>()) {
+            prop_assert!((t.angle_sum().unwrap() - 180.0).abs() < 0.1);
+        }
+
+        #[test]
+        fn test_points_respect_range(p in points(CoordinateRange::new(-1.0, 1.0))) {
+            prop_assert!(p.x >= -1.0 && p.x < 1.0 && p.y >= -1.0 && p.y < 1.0);
+        }
+    }
+}
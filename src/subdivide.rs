@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{Mesh, Point};
+
+/// Scheme used by Mesh::subdivide.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Subdivision {
+    /// Split at the edge midpoints, the outline and area stay exactly the same
+    Midpoint,
+    /// Loop subdivision, which also smooths the vertices. Boundaries are smoothed as curves,
+    /// so corners are rounded off and the area shrinks a little.
+    Loop,
+}
+
+impl Mesh {
+    /// Return the mesh with every triangle split into four at its edge midpoints, keeping
+    /// the orientation. The vertices of this mesh come first, followed by one new vertex per
+    /// edge.
+    pub fn subdivide(&self, scheme: Subdivision) -> Mesh {
+        // Triangles on each undirected edge, as the corner opposite to the edge
+        let mut edges: BTreeMap<(u32, u32), Vec<u32>> = BTreeMap::new();
+        for t in self.indices.chunks(3) {
+            for k in 0..3 {
+                let (a, b, opposite) = (t[k], t[(k + 1) % 3], t[(k + 2) % 3]);
+                edges
+                    .entry((a.min(b), a.max(b)))
+                    .or_default()
+                    .push(opposite);
+            }
+        }
+
+        let mut vertices = match scheme {
+            Subdivision::Midpoint => self.vertices.clone(),
+            Subdivision::Loop => self.smoothed_vertices(&edges),
+        };
+        let mut middle: BTreeMap<(u32, u32), u32> = BTreeMap::new();
+        for (&(a, b), opposite) in &edges {
+            let (pa, pb) = (self.vertices[a as usize], self.vertices[b as usize]);
+            let point = match (scheme, opposite.as_slice()) {
+                (Subdivision::Loop, &[c, d]) => {
+                    let (pc, pd) = (self.vertices[c as usize], self.vertices[d as usize]);
+                    Point {
+                        x: 0.375 * (pa.x + pb.x) + 0.125 * (pc.x + pd.x),
+                        y: 0.375 * (pa.y + pb.y) + 0.125 * (pc.y + pd.y),
+                    }
+                }
+                _ => Point {
+                    x: (pa.x + pb.x) / 2.0,
+                    y: (pa.y + pb.y) / 2.0,
+                },
+            };
+            middle.insert((a, b), vertices.len() as u32);
+            vertices.push(point);
+        }
+
+        let mut indices = Vec::with_capacity(self.indices.len() * 4);
+        let mid = |a: u32, b: u32| middle[&(a.min(b), a.max(b))];
+        for t in self.indices.chunks(3) {
+            let (a, b, c) = (t[0], t[1], t[2]);
+            let (ab, bc, ca) = (mid(a, b), mid(b, c), mid(c, a));
+            indices.extend_from_slice(&[a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca]);
+        }
+        Mesh::new(vertices, indices)
+    }
+
+    /// Loop vertex rule: interior vertices are averaged with all neighbors, boundary vertices
+    /// only with their two boundary neighbors
+    fn smoothed_vertices(&self, edges: &BTreeMap<(u32, u32), Vec<u32>>) -> Vec<Point> {
+        let mut neighbors = vec![BTreeSet::new(); self.vertices.len()];
+        let mut boundary = vec![BTreeSet::new(); self.vertices.len()];
+        for (&(a, b), opposite) in edges {
+            neighbors[a as usize].insert(b);
+            neighbors[b as usize].insert(a);
+            if opposite.len() == 1 {
+                boundary[a as usize].insert(b);
+                boundary[b as usize].insert(a);
+            }
+        }
+        let sum = |set: &BTreeSet<u32>| {
+            set.iter().fold((0.0, 0.0), |(x, y), i| {
+                let p = self.vertices[*i as usize];
+                (x + p.x, y + p.y)
+            })
+        };
+        self.vertices
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                if boundary[i].len() == 2 {
+                    let (x, y) = sum(&boundary[i]);
+                    Point {
+                        x: 0.75 * v.x + 0.125 * x,
+                        y: 0.75 * v.y + 0.125 * y,
+                    }
+                } else if boundary[i].is_empty() && !neighbors[i].is_empty() {
+                    let n = neighbors[i].len() as f32;
+                    let beta = if n > 3.0 { 3.0 / (8.0 * n) } else { 3.0 / 16.0 };
+                    let (x, y) = sum(&neighbors[i]);
+                    Point {
+                        x: (1.0 - n * beta) * v.x + beta * x,
+                        y: (1.0 - n * beta) * v.y + beta * y,
+                    }
+                } else {
+                    // Unused vertices and non manifold boundary corners stay in place
+                    *v
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn square() -> Mesh {
+        Mesh::new(
+            vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)],
+            vec![0, 1, 2, 0, 2, 3],
+        )
+    }
+
+    #[test]
+    fn test_midpoint_subdivision() {
+        let mesh = square().subdivide(Subdivision::Midpoint);
+        assert_eq!(9, mesh.vertices.len());
+        assert_eq!(8 * 3, mesh.indices.len());
+        assert!((mesh.area() - 4.0).abs() < 1e-6);
+        assert!(mesh.vertices.contains(&p(1.0, 1.0)));
+        let twice = mesh.subdivide(Subdivision::Midpoint);
+        assert_eq!(32 * 3, twice.indices.len());
+        assert!((twice.area() - 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_loop_subdivision_smooths() {
+        let mesh = square().subdivide(Subdivision::Loop);
+        assert_eq!(p(0.25, 0.25), mesh.vertices[0]);
+        // The diagonal is shared by both triangles and uses the interior edge rule
+        assert!(mesh.vertices.contains(&p(1.0, 1.0)));
+        assert!((mesh.area() - 3.0).abs() < 1e-5);
+        assert!(mesh
+            .triangles()
+            .all(|[a, b, c]| { (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y) > 0.0 }));
+    }
+}
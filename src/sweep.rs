@@ -0,0 +1,75 @@
+//! Sweep over segments sorted by their left end, the shared machinery of the intersection
+//! queries. Only segments whose x ranges overlap are tested against each other, which makes
+//! typical outlines close to linear instead of quadratic.
+
+use crate::{Point, Segment, SegmentContact, Tolerance};
+
+/// Return all crossings and touches (i, j, point) with i < j between the segments, skipping
+/// the pairs for which ignore(i, j) is true, ordered by i and then j. Collinear overlaps
+/// have no single point and are left out.
+pub(crate) fn segment_crossings(
+    segments: &[Segment],
+    tolerance: Tolerance,
+    ignore: impl Fn(usize, usize) -> bool,
+) -> Vec<(usize, usize, Point)> {
+    segment_contacts(segments, tolerance, ignore)
+        .into_iter()
+        .filter_map(|(i, j, contact)| contact.point().map(|point| (i, j, point)))
+        .collect()
+}
+
+/// Return the contacts (i, j, contact) with i < j of all segment pairs that meet, skipping
+/// the pairs for which ignore(i, j) is true, ordered by i and then j
+pub(crate) fn segment_contacts(
+    segments: &[Segment],
+    tolerance: Tolerance,
+    ignore: impl Fn(usize, usize) -> bool,
+) -> Vec<(usize, usize, SegmentContact)> {
+    let min_x = |s: &Segment| s.point_a.x.min(s.point_b.x);
+    let max_x = |s: &Segment| s.point_a.x.max(s.point_b.x);
+    let mut order: Vec<usize> = (0..segments.len()).collect();
+    order.sort_by(|a, b| min_x(&segments[*a]).total_cmp(&min_x(&segments[*b])));
+    let mut active: Vec<usize> = Vec::new();
+    let mut contacts = Vec::new();
+    for current in order {
+        let left = min_x(&segments[current]);
+        active.retain(|a| max_x(&segments[*a]) + tolerance.distance >= left);
+        for &other in &active {
+            let (i, j) = (other.min(current), other.max(current));
+            if ignore(i, j) {
+                continue;
+            }
+            match segments[i].contact(&segments[j], tolerance) {
+                SegmentContact::Disjoint => {}
+                contact => contacts.push((i, j, contact)),
+            }
+        }
+        active.push(current);
+    }
+    contacts.sort_by_key(|c| (c.0, c.1));
+    contacts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_segment_crossings() {
+        let segments = [
+            Segment::new(p(0.0, 0.0), p(4.0, 4.0)),
+            Segment::new(p(10.0, 0.0), p(12.0, 2.0)),
+            Segment::new(p(0.0, 4.0), p(4.0, 0.0)),
+            Segment::new(p(3.0, 0.0), p(3.0, 5.0)),
+        ];
+        let crossings = segment_crossings(&segments, Tolerance::default(), |_, _| false);
+        let pairs: Vec<(usize, usize)> = crossings.iter().map(|c| (c.0, c.1)).collect();
+        assert_eq!(vec![(0, 2), (0, 3), (2, 3)], pairs);
+        assert_eq!(p(2.0, 2.0), crossings[0].2);
+        assert_eq!(
+            2,
+            segment_crossings(&segments, Tolerance::default(), |i, j| i == 0 && j == 2).len()
+        );
+    }
+}
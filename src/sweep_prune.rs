@@ -0,0 +1,165 @@
+use std::collections::BTreeSet;
+
+use crate::{ItemId, Rect};
+
+/// Pairs that started or stopped overlapping since the previous call to
+/// SweepAndPrune::update_pairs.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PairChanges {
+    pub started: Vec<(ItemId, ItemId)>,
+    pub ended: Vec<(ItemId, ItemId)>,
+}
+
+/// Broad phase keeping the bounding boxes sorted by their left edge. Moving items only
+/// shuffle the order slightly between frames, so the insertion sort done on every update is
+/// close to linear.
+#[derive(Debug, Clone)]
+pub struct SweepAndPrune<T> {
+    items: Vec<Option<(Rect, T)>>,
+    order: Vec<ItemId>,
+    pairs: BTreeSet<(ItemId, ItemId)>,
+}
+
+impl<T> Default for SweepAndPrune<T> {
+    fn default() -> Self {
+        SweepAndPrune::new()
+    }
+}
+
+impl<T> SweepAndPrune<T> {
+    pub fn new() -> SweepAndPrune<T> {
+        SweepAndPrune {
+            items: Vec::new(),
+            order: Vec::new(),
+            pairs: BTreeSet::new(),
+        }
+    }
+
+    /// Store the item with its bounding box and return its handle. The pairs it makes are
+    /// reported by the next update_pairs.
+    pub fn insert(&mut self, bounds: Rect, item: T) -> ItemId {
+        let id = ItemId(self.items.len());
+        self.items.push(Some((bounds, item)));
+        self.order.push(id);
+        id
+    }
+
+    /// Remove the item and return it, None if the handle was already removed. Its pairs are
+    /// reported as ended by the next update_pairs.
+    pub fn remove(&mut self, id: ItemId) -> Option<T> {
+        let (_, item) = self.items.get_mut(id.0)?.take()?;
+        self.order.retain(|i| *i != id);
+        Some(item)
+    }
+
+    /// Move the item to new bounds, return false if the handle was removed
+    pub fn update(&mut self, id: ItemId, bounds: Rect) -> bool {
+        match self.items.get_mut(id.0) {
+            Some(Some((old, _))) => {
+                *old = bounds;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, id: ItemId) -> Option<&T> {
+        self.items.get(id.0)?.as_ref().map(|(_, item)| item)
+    }
+
+    pub fn bounds(&self, id: ItemId) -> Option<Rect> {
+        self.items.get(id.0)?.as_ref().map(|(bounds, _)| *bounds)
+    }
+
+    /// Return the overlapping pairs found by the last update_pairs, as (lower, higher) handle
+    /// in ascending order
+    pub fn pairs(&self) -> Vec<(ItemId, ItemId)> {
+        self.pairs.iter().copied().collect()
+    }
+
+    /// Re-sort the boxes, sweep them for overlaps and return how the pairs changed
+    pub fn update_pairs(&mut self) -> PairChanges {
+        self.sort();
+        let mut current = BTreeSet::new();
+        for (k, a) in self.order.iter().enumerate() {
+            let ba = self.rect(*a);
+            for b in &self.order[k + 1..] {
+                let bb = self.rect(*b);
+                if bb.min.x > ba.max.x {
+                    break;
+                }
+                if ba.intersects(&bb) {
+                    current.insert(((*a).min(*b), (*a).max(*b)));
+                }
+            }
+        }
+        let changes = PairChanges {
+            started: current.difference(&self.pairs).copied().collect(),
+            ended: self.pairs.difference(&current).copied().collect(),
+        };
+        self.pairs = current;
+        changes
+    }
+
+    /// Insertion sort of the handles by the left edge of their boxes
+    fn sort(&mut self) {
+        for k in 1..self.order.len() {
+            let mut j = k;
+            while j > 0 && self.rect(self.order[j - 1]).min.x > self.rect(self.order[j]).min.x {
+                self.order.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+    }
+
+    /// Box of a handle in the sorted order, which are never removed
+    fn rect(&self, id: ItemId) -> Rect {
+        self.items[id.0]
+            .as_ref()
+            .map(|(bounds, _)| *bounds)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    fn r(x0: f32, y0: f32, x1: f32, y1: f32) -> Rect {
+        Rect::new(Point { x: x0, y: y0 }, Point { x: x1, y: y1 })
+    }
+
+    #[test]
+    fn test_sweep_pairs() {
+        let mut broad = SweepAndPrune::new();
+        let a = broad.insert(r(0.0, 0.0, 2.0, 2.0), 'a');
+        let b = broad.insert(r(1.0, 1.0, 3.0, 3.0), 'b');
+        let c = broad.insert(r(1.0, 5.0, 2.0, 6.0), 'c');
+        let changes = broad.update_pairs();
+        assert_eq!(vec![(a, b)], changes.started);
+        assert!(changes.ended.is_empty());
+        assert_eq!(vec![(a, b)], broad.pairs());
+        assert_eq!(Some(&'c'), broad.get(c));
+    }
+
+    #[test]
+    fn test_moving_items() {
+        let mut broad = SweepAndPrune::new();
+        let a = broad.insert(r(0.0, 0.0, 1.0, 1.0), ());
+        let b = broad.insert(r(3.0, 0.0, 4.0, 1.0), ());
+        broad.update_pairs();
+        assert!(broad.pairs().is_empty());
+        broad.update(b, r(0.5, 0.5, 1.5, 1.5));
+        assert_eq!(vec![(a, b)], broad.update_pairs().started);
+        broad.update(a, r(-4.0, 0.0, -3.0, 1.0));
+        let changes = broad.update_pairs();
+        assert!(changes.started.is_empty());
+        assert_eq!(vec![(a, b)], changes.ended);
+        broad.update(a, r(1.0, 1.0, 2.0, 2.0));
+        broad.update_pairs();
+        assert_eq!(Some(()), broad.remove(b));
+        assert_eq!(vec![(a, b)], broad.update_pairs().ended);
+        assert!(!broad.update(b, r(0.0, 0.0, 1.0, 1.0)));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Angle, Centroid, Line, Point, Polygon, Tolerance};
+
+impl Polygon {
+    /// Return the mirror axes of the polygon, every line through the centroid reflecting
+    /// the vertices onto vertices within the tolerance. Candidates run through a vertex or
+    /// the midpoint of an edge, since every axis of a polygon does.
+    pub fn symmetry_axes(&self, tolerance: Tolerance) -> Vec<Line> {
+        if self.points.len() < 3 {
+            return Vec::new();
+        }
+        let center = self.centroid();
+        let n = self.points.len();
+        let candidates = self.points.iter().copied().chain((0..n).map(|i| {
+            let (a, b) = (self.points[i], self.points[(i + 1) % n]);
+            Point {
+                x: (a.x + b.x) / 2.0,
+                y: (a.y + b.y) / 2.0,
+            }
+        }));
+        let mut axes: Vec<Line> = Vec::new();
+        for point in candidates {
+            let axis = match Line::through(center, point) {
+                Some(axis) => axis,
+                None => continue,
+            };
+            // All axes pass the centroid, so parallel ones are the same line
+            if axes
+                .iter()
+                .any(|a| (a.a * axis.b - a.b * axis.a).abs() < 1e-4)
+            {
+                continue;
+            }
+            if self.maps_onto_itself(|p| reflect(&axis, p), tolerance) {
+                axes.push(axis);
+            }
+        }
+        axes
+    }
+
+    /// Return the largest n so that turning the polygon by 360 / n degrees about its
+    /// centroid maps the vertices onto vertices within the tolerance, 1 if no turn does.
+    pub fn rotational_symmetry_order(&self, tolerance: Tolerance) -> usize {
+        let n = self.points.len();
+        if n < 3 {
+            return 1;
+        }
+        let center = self.centroid();
+        (2..=n)
+            .rev()
+            .find(|order| {
+                let turn = Angle::from_degrees(360.0 / *order as f32);
+                let (sin, cos) = (turn.sin(), turn.cos());
+                self.maps_onto_itself(
+                    |p| {
+                        let (dx, dy) = (p.x - center.x, p.y - center.y);
+                        Point {
+                            x: center.x + dx * cos - dy * sin,
+                            y: center.y + dx * sin + dy * cos,
+                        }
+                    },
+                    tolerance,
+                )
+            })
+            .unwrap_or(1)
+    }
+
+    fn maps_onto_itself<F: Fn(Point) -> Point>(&self, transform: F, tolerance: Tolerance) -> bool {
+        self.points.iter().all(|p| {
+            let moved = transform(*p);
+            self.points.iter().any(|q| tolerance.coincident(moved, *q))
+        })
+    }
+}
+
+/// Return the mirror image of the point across the normalized line
+fn reflect(line: &Line, point: Point) -> Point {
+    let distance = line.signed_distance(point);
+    Point {
+        x: point.x - 2.0 * distance * line.a,
+        y: point.y - 2.0 * distance * line.b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_square_and_rectangle() {
+        let tolerance = Tolerance::new(1e-4);
+        let square = Polygon::new(vec![p(0.0, 0.0), p(2.0, 0.0), p(2.0, 2.0), p(0.0, 2.0)]);
+        assert_eq!(4, square.symmetry_axes(tolerance).len());
+        assert_eq!(4, square.rotational_symmetry_order(tolerance));
+        let rect = Polygon::new(vec![p(0.0, 0.0), p(4.0, 0.0), p(4.0, 2.0), p(0.0, 2.0)]);
+        let axes = rect.symmetry_axes(tolerance);
+        assert_eq!(2, axes.len());
+        assert!(axes.iter().all(|axis| axis.distance_to(p(2.0, 1.0)) < 1e-4));
+        assert_eq!(2, rect.rotational_symmetry_order(tolerance));
+    }
+
+    #[test]
+    fn test_asymmetric_shapes() {
+        let tolerance = Tolerance::new(1e-4);
+        let kite = Polygon::new(vec![p(0.0, 0.0), p(1.0, -1.0), p(4.0, 0.0), p(1.0, 1.0)]);
+        let axes = kite.symmetry_axes(tolerance);
+        assert_eq!(1, axes.len());
+        assert!(axes[0].distance_to(p(3.0, 0.0)) < 1e-4);
+        assert_eq!(1, kite.rotational_symmetry_order(tolerance));
+        let scalene = Polygon::new(vec![p(0.0, 0.0), p(5.0, 0.0), p(1.0, 2.0)]);
+        assert!(scalene.symmetry_axes(tolerance).is_empty());
+    }
+}
@@ -0,0 +1,235 @@
+//! Transcendental functions behind every computation of the crate, in f32 and f64. With the
+//! deterministic feature they come from libm, whose software implementations give bit
+//! identical results on every platform, as lockstep simulations need. Without it they are the
+//! functions of the standard library, which may differ in the last bit between platforms.
+//! The crate never uses fused multiply add, so the remaining arithmetic is deterministic
+//! either way. Only StdTrig calls the standard library directly, as its name promises.
+
+/// Floating point type with the transcendental functions the crate needs.
+pub(crate) trait Real: Copy {
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn asinh(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn hypot(self, y: Self) -> Self;
+    fn cbrt(self) -> Self;
+}
+
+#[cfg(feature = "deterministic")]
+macro_rules! real {
+    ($t:ident, $sin:ident, $cos:ident, $sincos:ident, $tan:ident, $asin:ident, $acos:ident,
+     $atan:ident, $atan2:ident, $sinh:ident, $cosh:ident, $asinh:ident, $exp:ident,
+     $log:ident, $hypot:ident, $cbrt:ident) => {
+        impl Real for $t {
+            fn sin(self) -> $t {
+                libm::$sin(self)
+            }
+            fn cos(self) -> $t {
+                libm::$cos(self)
+            }
+            fn sin_cos(self) -> ($t, $t) {
+                libm::$sincos(self)
+            }
+            fn tan(self) -> $t {
+                libm::$tan(self)
+            }
+            fn asin(self) -> $t {
+                libm::$asin(self)
+            }
+            fn acos(self) -> $t {
+                libm::$acos(self)
+            }
+            fn atan(self) -> $t {
+                libm::$atan(self)
+            }
+            fn atan2(self, x: $t) -> $t {
+                libm::$atan2(self, x)
+            }
+            fn sinh(self) -> $t {
+                libm::$sinh(self)
+            }
+            fn cosh(self) -> $t {
+                libm::$cosh(self)
+            }
+            fn asinh(self) -> $t {
+                libm::$asinh(self)
+            }
+            fn exp(self) -> $t {
+                libm::$exp(self)
+            }
+            fn ln(self) -> $t {
+                libm::$log(self)
+            }
+            fn hypot(self, y: $t) -> $t {
+                libm::$hypot(self, y)
+            }
+            fn cbrt(self) -> $t {
+                libm::$cbrt(self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "deterministic")]
+real!(
+    f32, sinf, cosf, sincosf, tanf, asinf, acosf, atanf, atan2f, sinhf, coshf, asinhf, expf, logf,
+    hypotf, cbrtf
+);
+#[cfg(feature = "deterministic")]
+real!(
+    f64, sin, cos, sincos, tan, asin, acos, atan, atan2, sinh, cosh, asinh, exp, log, hypot, cbrt
+);
+
+#[cfg(not(feature = "deterministic"))]
+macro_rules! real {
+    ($t:ident) => {
+        impl Real for $t {
+            fn sin(self) -> $t {
+                $t::sin(self)
+            }
+            fn cos(self) -> $t {
+                $t::cos(self)
+            }
+            fn sin_cos(self) -> ($t, $t) {
+                $t::sin_cos(self)
+            }
+            fn tan(self) -> $t {
+                $t::tan(self)
+            }
+            fn asin(self) -> $t {
+                $t::asin(self)
+            }
+            fn acos(self) -> $t {
+                $t::acos(self)
+            }
+            fn atan(self) -> $t {
+                $t::atan(self)
+            }
+            fn atan2(self, x: $t) -> $t {
+                $t::atan2(self, x)
+            }
+            fn sinh(self) -> $t {
+                $t::sinh(self)
+            }
+            fn cosh(self) -> $t {
+                $t::cosh(self)
+            }
+            fn asinh(self) -> $t {
+                $t::asinh(self)
+            }
+            fn exp(self) -> $t {
+                $t::exp(self)
+            }
+            fn ln(self) -> $t {
+                $t::ln(self)
+            }
+            fn hypot(self, y: $t) -> $t {
+                $t::hypot(self, y)
+            }
+            fn cbrt(self) -> $t {
+                $t::cbrt(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "deterministic"))]
+real!(f32);
+#[cfg(not(feature = "deterministic"))]
+real!(f64);
+
+pub(crate) fn sin<T: Real>(x: T) -> T {
+    x.sin()
+}
+
+pub(crate) fn cos<T: Real>(x: T) -> T {
+    x.cos()
+}
+
+pub(crate) fn sin_cos<T: Real>(x: T) -> (T, T) {
+    x.sin_cos()
+}
+
+pub(crate) fn tan<T: Real>(x: T) -> T {
+    x.tan()
+}
+
+pub(crate) fn asin<T: Real>(x: T) -> T {
+    x.asin()
+}
+
+pub(crate) fn acos<T: Real>(x: T) -> T {
+    x.acos()
+}
+
+pub(crate) fn atan<T: Real>(x: T) -> T {
+    x.atan()
+}
+
+pub(crate) fn atan2<T: Real>(y: T, x: T) -> T {
+    y.atan2(x)
+}
+
+pub(crate) fn sinh<T: Real>(x: T) -> T {
+    x.sinh()
+}
+
+pub(crate) fn cosh<T: Real>(x: T) -> T {
+    x.cosh()
+}
+
+pub(crate) fn asinh<T: Real>(x: T) -> T {
+    x.asinh()
+}
+
+pub(crate) fn exp<T: Real>(x: T) -> T {
+    x.exp()
+}
+
+/// Natural logarithm
+pub(crate) fn ln<T: Real>(x: T) -> T {
+    x.ln()
+}
+
+/// Return the length of (x, y) without overflow or underflow in the squares
+pub(crate) fn hypot<T: Real>(x: T, y: T) -> T {
+    x.hypot(y)
+}
+
+pub(crate) fn cbrt<T: Real>(x: T) -> T {
+    x.cbrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_functions_match_std() {
+        for i in -20..=20 {
+            let x = i as f32 / 10.0;
+            assert!((sin(x) - x.sin()).abs() < 1e-6);
+            assert_eq!((sin(x), cos(x)), sin_cos(x));
+            assert!((atan2(x, 0.5) - x.atan2(0.5)).abs() < 1e-6);
+            if x.abs() <= 1.0 {
+                assert!((acos(x) - x.acos()).abs() < 1e-6);
+            }
+            assert!((cosh(x) - x.cosh()).abs() < 1e-5);
+            assert!((exp(x) - x.exp()).abs() < 1e-5);
+            assert!((hypot(x, 0.5) - x.hypot(0.5)).abs() < 1e-6);
+            let wide = x as f64;
+            assert!((atan2(wide, 0.5) - wide.atan2(0.5)).abs() < 1e-12);
+            assert!((cbrt(wide) - wide.cbrt()).abs() < 1e-12);
+        }
+        assert!((ln(std::f32::consts::E) - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,233 @@
+//! Traversal of square grid cells along lines. Cell (i, j) covers [i * size, (i + 1) * size)
+//! horizontally and [j * size, (j + 1) * size) vertically.
+
+use std::collections::BTreeSet;
+
+use crate::{Circle, Segment};
+
+/// Iterator over all cells a segment passes through, from the cell of point_a to the cell
+/// of point_b, created by Segment::grid_cells. Consecutive cells share an edge; when the
+/// segment passes exactly through a grid corner the horizontal neighbor is visited first.
+#[derive(Debug, Clone)]
+pub struct GridCells {
+    cell: (i32, i32),
+    step: (i32, i32),
+    next_boundary: (f32, f32),
+    delta: (f32, f32),
+    remaining: usize,
+    done: bool,
+}
+
+impl Iterator for GridCells {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<(i32, i32)> {
+        if self.done {
+            return None;
+        }
+        let current = self.cell;
+        if self.remaining == 0 {
+            self.done = true;
+        } else {
+            self.remaining -= 1;
+            if self.next_boundary.0 <= self.next_boundary.1 {
+                self.cell.0 += self.step.0;
+                self.next_boundary.0 += self.delta.0;
+            } else {
+                self.cell.1 += self.step.1;
+                self.next_boundary.1 += self.delta.1;
+            }
+        }
+        Some(current)
+    }
+}
+
+impl Segment {
+    /// Return the cells of a grid with the given cell size that the segment passes through,
+    /// walked with the Amanatides-Woo voxel traversal, for line of sight checks on tile maps
+    pub fn grid_cells(&self, cell_size: f32) -> GridCells {
+        let (a, b) = (self.point_a, self.point_b);
+        let cell = |v: f32| (v / cell_size).floor() as i32;
+        let (start, end) = ((cell(a.x), cell(a.y)), (cell(b.x), cell(b.y)));
+        // Parameter along the segment of the first boundary crossing and between crossings
+        let axis = |from: f32, to: f32, index: i32| {
+            let delta = to - from;
+            if delta == 0.0 {
+                return (0, f32::INFINITY, f32::INFINITY);
+            }
+            let step = if delta > 0.0 { 1 } else { -1 };
+            let boundary = (index + (step + 1) / 2) as f32 * cell_size;
+            (step, (boundary - from) / delta, cell_size / delta.abs())
+        };
+        let (step_x, next_x, delta_x) = axis(a.x, b.x, start.0);
+        let (step_y, next_y, delta_y) = axis(a.y, b.y, start.1);
+        GridCells {
+            cell: start,
+            step: (step_x, step_y),
+            next_boundary: (next_x, next_y),
+            delta: (delta_x, delta_y),
+            remaining: ((end.0 - start.0).abs() + (end.1 - start.1).abs()) as usize,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the integer points of a Bresenham line, both ends included.
+#[derive(Debug, Clone)]
+pub struct Bresenham {
+    current: (i32, i32),
+    end: (i32, i32),
+    delta: (i32, i32),
+    step: (i32, i32),
+    error: i32,
+    done: bool,
+}
+
+impl Bresenham {
+    pub fn new(start: (i32, i32), end: (i32, i32)) -> Bresenham {
+        let delta = ((end.0 - start.0).abs(), -(end.1 - start.1).abs());
+        Bresenham {
+            current: start,
+            end,
+            delta,
+            step: ((end.0 - start.0).signum(), (end.1 - start.1).signum()),
+            error: delta.0 + delta.1,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for Bresenham {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<(i32, i32)> {
+        if self.done {
+            return None;
+        }
+        let point = self.current;
+        if point == self.end {
+            self.done = true;
+            return Some(point);
+        }
+        let doubled = 2 * self.error;
+        if doubled >= self.delta.1 {
+            self.error += self.delta.1;
+            self.current.0 += self.step.0;
+        }
+        if doubled <= self.delta.0 {
+            self.error += self.delta.0;
+            self.current.1 += self.step.1;
+        }
+        Some(point)
+    }
+}
+
+impl Circle {
+    /// Iterate over the cells whose center lies inside the circle, row by row from the
+    /// bottom, for filling occupancy maps
+    pub fn grid_cells(&self, cell_size: f32) -> impl Iterator<Item = (i32, i32)> {
+        let circle = *self;
+        let r = self.radius.abs();
+        let cell = |v: f32| (v / cell_size).floor() as i32;
+        let (i0, i1) = (cell(self.center.x - r), cell(self.center.x + r));
+        let (j0, j1) = (cell(self.center.y - r), cell(self.center.y + r));
+        (j0..=j1).flat_map(move |j| {
+            (i0..=i1).filter_map(move |i| {
+                let dx = (i as f32 + 0.5) * cell_size - circle.center.x;
+                let dy = (j as f32 + 0.5) * cell_size - circle.center.y;
+                if dx * dx + dy * dy <= r * r {
+                    Some((i, j))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+
+    /// Iterate over the cells of the outline drawn with the midpoint circle algorithm,
+    /// around the cell of the center with the radius rounded to whole cells. Every cell is
+    /// returned once, ordered by column and then row.
+    pub fn outline_cells(&self, cell_size: f32) -> impl Iterator<Item = (i32, i32)> {
+        let center = (
+            (self.center.x / cell_size).floor() as i32,
+            (self.center.y / cell_size).floor() as i32,
+        );
+        let radius = (self.radius.abs() / cell_size).round() as i32;
+        let mut cells = BTreeSet::new();
+        let (mut x, mut y, mut error) = (radius, 0, 1 - radius);
+        while x >= y {
+            for &(u, v) in &[
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                cells.insert((center.0 + u, center.1 + v));
+            }
+            y += 1;
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+        cells.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_grid_cells() {
+        let segment = Segment::new(p(0.5, 0.5), p(3.5, 1.9));
+        let cells: Vec<(i32, i32)> = segment.grid_cells(1.0).collect();
+        assert_eq!(vec![(0, 0), (1, 0), (1, 1), (2, 1), (3, 1)], cells);
+        let backwards: Vec<(i32, i32)> = Segment::new(p(-0.5, 2.5), p(-0.5, -0.5))
+            .grid_cells(1.0)
+            .collect();
+        assert_eq!(vec![(-1, 2), (-1, 1), (-1, 0), (-1, -1)], backwards);
+        assert_eq!(
+            1,
+            Segment::new(p(0.1, 0.1), p(0.2, 0.9))
+                .grid_cells(1.0)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_bresenham() {
+        let points: Vec<(i32, i32)> = Bresenham::new((0, 0), (4, 2)).collect();
+        assert_eq!(vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)], points);
+        let steep: Vec<(i32, i32)> = Bresenham::new((0, 0), (-1, -3)).collect();
+        assert_eq!(4, steep.len());
+        assert_eq!(Some(&(-1, -3)), steep.last());
+    }
+
+    #[test]
+    fn test_circle_cells() {
+        let circle = Circle::new(p(0.0, 0.0), 2.0);
+        let filled: Vec<(i32, i32)> = circle.grid_cells(1.0).collect();
+        assert_eq!(12, filled.len());
+        assert!(filled.contains(&(-2, 0)) && !filled.contains(&(-2, -2)));
+        let outline: Vec<(i32, i32)> = Circle::new(p(0.5, 0.5), 3.0).outline_cells(1.0).collect();
+        assert!(outline.contains(&(3, 0)) && outline.contains(&(0, -3)));
+        assert!(outline.iter().all(|(i, j)| {
+            let r = ((i * i + j * j) as f32).sqrt();
+            (r - 3.0).abs() < 0.75
+        }));
+        assert_eq!(
+            vec![(0, 0)],
+            Circle::new(p(0.5, 0.5), 0.2)
+                .outline_cells(1.0)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,407 @@
+use crate::{
+    can_form_triangle, transcendental, Angle, Point, Pose2, Triangle, TriangleError, TrigError,
+    Vector,
+};
+
+/// Relative deviation up to which redundant constraints count as consistent.
+const CONSISTENCY_TOLERANCE: f32 = 1e-3;
+
+/// Side lengths and angles of a triangle without a position in the plane. The naming
+/// follows Triangle: ab is the side between point_a and point_b, alpha the angle in degrees
+/// at point_a.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct SolvedTriangle {
+    pub ab: f32,
+    pub bc: f32,
+    pub ca: f32,
+    pub alpha: f32,
+    pub beta: f32,
+    pub gamma: f32,
+}
+
+impl SolvedTriangle {
+    /// Place the triangle with point_a at position and point_b in direction heading from
+    /// it. point_c lies to the left of AB, so the points run counter clockwise.
+    pub fn place(&self, position: Point, heading: Angle) -> Triangle {
+        let pose = Pose2::new(position, heading);
+        let points = self.canonical();
+        Triangle::new(
+            pose.transform_point(points[0]),
+            pose.transform_point(points[1]),
+            pose.transform_point(points[2]),
+        )
+    }
+
+    /// Points with A at the origin and B on the positive x axis, C counter clockwise
+    fn canonical(&self) -> [Point; 3] {
+        let (sin, cos) = transcendental::sin_cos(self.alpha.to_radians());
+        [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: self.ab, y: 0.0 },
+            Point {
+                x: self.ca * cos,
+                y: self.ca * sin,
+            },
+        ]
+    }
+}
+
+/// Collects known sides, angles and placed points of a triangle and solves for the rest.
+/// Any combination fixing the shape works: three sides, two sides and an angle, or one side
+/// and two angles. Placed points count as the sides between them. Redundant values are
+/// allowed as long as they agree.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct TriangleBuilder {
+    sides: [Option<f32>; 3],
+    angles: [Option<f32>; 3],
+    points: [Option<Point>; 3],
+}
+
+impl TriangleBuilder {
+    pub fn new() -> TriangleBuilder {
+        TriangleBuilder::default()
+    }
+
+    /// Set the length of the side between point_a and point_b
+    pub fn ab(&mut self, length: f32) -> &mut TriangleBuilder {
+        self.sides[2] = Some(length);
+        self
+    }
+
+    /// Set the length of the side between point_b and point_c
+    pub fn bc(&mut self, length: f32) -> &mut TriangleBuilder {
+        self.sides[0] = Some(length);
+        self
+    }
+
+    /// Set the length of the side between point_c and point_a
+    pub fn ca(&mut self, length: f32) -> &mut TriangleBuilder {
+        self.sides[1] = Some(length);
+        self
+    }
+
+    /// Set the angle in degrees at point_a
+    pub fn alpha(&mut self, degrees: f32) -> &mut TriangleBuilder {
+        self.angles[0] = Some(degrees);
+        self
+    }
+
+    /// Set the angle in degrees at point_b
+    pub fn beta(&mut self, degrees: f32) -> &mut TriangleBuilder {
+        self.angles[1] = Some(degrees);
+        self
+    }
+
+    /// Set the angle in degrees at point_c
+    pub fn gamma(&mut self, degrees: f32) -> &mut TriangleBuilder {
+        self.angles[2] = Some(degrees);
+        self
+    }
+
+    pub fn point_a(&mut self, point: Point) -> &mut TriangleBuilder {
+        self.points[0] = Some(point);
+        self
+    }
+
+    pub fn point_b(&mut self, point: Point) -> &mut TriangleBuilder {
+        self.points[1] = Some(point);
+        self
+    }
+
+    pub fn point_c(&mut self, point: Point) -> &mut TriangleBuilder {
+        self.points[2] = Some(point);
+        self
+    }
+
+    /// Solve for all sides and angles. Errors are TrigError::UnsolvableTriangle with the
+    /// reason.
+    pub fn solve(&self) -> Result<SolvedTriangle, TrigError> {
+        let mut sides = self.sides;
+        for (i, side) in sides.iter_mut().enumerate() {
+            // The side opposite vertex i connects the other two vertices
+            if let (Some(p), Some(q)) = (self.points[(i + 1) % 3], self.points[(i + 2) % 3]) {
+                let length = Vector::new(p, q).length();
+                if let Some(given) = *side {
+                    if !agrees(given, length) {
+                        return Err(TriangleError::OverConstrained.into());
+                    }
+                }
+                *side = Some(length);
+            }
+        }
+        if sides.iter().flatten().any(|s| *s <= 0.0)
+            || self
+                .angles
+                .iter()
+                .flatten()
+                .any(|a| *a <= 0.0 || *a >= 180.0)
+        {
+            return Err(TriangleError::Impossible.into());
+        }
+        let (sides, angles) = solve_sides_and_angles(sides, self.angles)?;
+        for i in 0..3 {
+            let side_matches = match self.sides[i] {
+                Some(given) => agrees(given, sides[i]),
+                None => true,
+            };
+            let angle_matches = match self.angles[i] {
+                Some(given) => agrees(given, angles[i]),
+                None => true,
+            };
+            if !side_matches || !angle_matches {
+                return Err(TriangleError::OverConstrained.into());
+            }
+        }
+        Ok(SolvedTriangle {
+            ab: sides[2],
+            bc: sides[0],
+            ca: sides[1],
+            alpha: angles[0],
+            beta: angles[1],
+            gamma: angles[2],
+        })
+    }
+
+    /// Solve and place the triangle. Without placed points point_a lands on the origin and
+    /// point_b on the positive x axis. One placed point moves the triangle there, two placed
+    /// points also fix its direction. The unplaced points follow counter clockwise order.
+    pub fn build(&self) -> Result<Triangle, TrigError> {
+        let solved = self.solve()?;
+        let placed: Vec<(usize, Point)> = (0..3)
+            .filter_map(|i| self.points[i].map(|point| (i, point)))
+            .collect();
+        let canonical = solved.canonical();
+        let pose = match placed.as_slice() {
+            [] => Pose2::identity(),
+            [(i, target)] => Pose2::new(subtract(*target, canonical[*i]), Angle::from_degrees(0.0)),
+            [(i, target_i), (j, target_j), ..] => {
+                let heading =
+                    direction(*target_i, *target_j) - direction(canonical[*i], canonical[*j]);
+                let rotated = Pose2::new(Point { x: 0.0, y: 0.0 }, Angle::from_degrees(heading))
+                    .transform_point(canonical[*i]);
+                Pose2::new(subtract(*target_i, rotated), Angle::from_degrees(heading))
+            }
+        };
+        let [a, b, c] = canonical.map(|p| pose.transform_point(p));
+        // Three placed points are kept exactly as given
+        Ok(Triangle::new(
+            self.points[0].unwrap_or(a),
+            self.points[1].unwrap_or(b),
+            self.points[2].unwrap_or(c),
+        ))
+    }
+}
+
+/// Complete sides (opposite each vertex) and angles (at each vertex) from a minimal subset
+fn solve_sides_and_angles(
+    mut sides: [Option<f32>; 3],
+    mut angles: [Option<f32>; 3],
+) -> Result<([f32; 3], [f32; 3]), TriangleError> {
+    let known_angles: Vec<usize> = (0..3).filter(|i| angles[*i].is_some()).collect();
+    if known_angles.len() == 2 {
+        let sum: f32 = angles.iter().flatten().sum();
+        if sum >= 180.0 {
+            return Err(TriangleError::Impossible);
+        }
+        let missing = 3 - known_angles[0] - known_angles[1];
+        angles[missing] = Some(180.0 - sum);
+    }
+    let known_sides: Vec<usize> = (0..3).filter(|i| sides[*i].is_some()).collect();
+    if known_sides.is_empty() {
+        return Err(TriangleError::UnderConstrained);
+    }
+    let sine_of = |degrees: f32| transcendental::sin(degrees.to_radians());
+    if angles.iter().all(|a| a.is_some()) && known_sides.len() < 3 {
+        // Law of sines from any known side
+        let k = known_sides[0];
+        let ratio = known(sides[k])? / sine_of(known(angles[k])?);
+        for i in 0..3 {
+            if sides[i].is_none() {
+                sides[i] = Some(ratio * sine_of(known(angles[i])?));
+            }
+        }
+    } else if known_sides.len() == 2 {
+        let (i, j) = (known_sides[0], known_sides[1]);
+        let k = 3 - i - j;
+        let (si, sj) = (known(sides[i])?, known(sides[j])?);
+        if let Some(included) = angles[k] {
+            // Two sides and the angle between them: law of cosines
+            let cosine = transcendental::cos(included.to_radians());
+            sides[k] = Some((si * si + sj * sj - 2.0 * si * sj * cosine).sqrt());
+        } else if let Some(&known_angle) = known_angles.first() {
+            // Two sides and an angle opposite one of them
+            let (side_other, side_known) = if known_angle == i { (sj, si) } else { (si, sj) };
+            let angle_known = known(angles[known_angle])?;
+            let sine = side_other * sine_of(angle_known) / side_known;
+            if sine > 1.0 {
+                return Err(TriangleError::Impossible);
+            }
+            let acute = transcendental::asin(sine).to_degrees();
+            if side_other > side_known {
+                // The angle opposite the longer side may be acute or obtuse
+                if angle_known >= 90.0 {
+                    return Err(TriangleError::Impossible);
+                } else if acute < 90.0 {
+                    return Err(TriangleError::Ambiguous);
+                }
+            }
+            let remaining = 180.0 - angle_known - acute;
+            if remaining <= 0.0 {
+                return Err(TriangleError::Impossible);
+            }
+            sides[k] = Some(side_known * sine_of(remaining) / sine_of(angle_known));
+        } else {
+            return Err(TriangleError::UnderConstrained);
+        }
+    } else if known_sides.len() < 3 {
+        return Err(TriangleError::UnderConstrained);
+    }
+    let sides = [known(sides[0])?, known(sides[1])?, known(sides[2])?];
+    if !can_form_triangle(sides[0], sides[1], sides[2]) {
+        return Err(TriangleError::Impossible);
+    }
+    let mut solved_angles = [0.0; 3];
+    for i in 0..3 {
+        let (p, q) = (sides[(i + 1) % 3], sides[(i + 2) % 3]);
+        solved_angles[i] = transcendental::acos(
+            ((p * p + q * q - sides[i] * sides[i]) / (2.0 * p * q)).clamp(-1.0, 1.0),
+        )
+        .to_degrees();
+    }
+    Ok((sides, solved_angles))
+}
+
+/// Value the solver has derived by now, which only fails for too few constraints
+fn known(value: Option<f32>) -> Result<f32, TriangleError> {
+    value.ok_or(TriangleError::UnderConstrained)
+}
+
+fn agrees(a: f32, b: f32) -> bool {
+    (a - b).abs() <= CONSISTENCY_TOLERANCE * a.abs().max(b.abs()).max(1.0)
+}
+
+fn subtract(a: Point, b: Point) -> Point {
+    Point {
+        x: a.x - b.x,
+        y: a.y - b.y,
+    }
+}
+
+/// Direction in degrees from p to q
+fn direction(p: Point, q: Point) -> f32 {
+    transcendental::atan2(q.y - p.y, q.x - p.x).to_degrees()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn test_solve_combinations() {
+        let sss = TriangleBuilder::new()
+            .ab(5.0)
+            .bc(3.0)
+            .ca(4.0)
+            .solve()
+            .unwrap();
+        assert!(close(90.0, sss.gamma));
+        let sas = TriangleBuilder::new()
+            .ca(4.0)
+            .ab(5.0)
+            .alpha(sss.alpha)
+            .solve()
+            .unwrap();
+        assert!(close(3.0, sas.bc));
+        let asa = TriangleBuilder::new()
+            .ab(5.0)
+            .alpha(sss.alpha)
+            .beta(sss.beta)
+            .solve()
+            .unwrap();
+        assert!(close(4.0, asa.ca) && close(3.0, asa.bc));
+        let ssa = TriangleBuilder::new()
+            .ab(5.0)
+            .bc(3.0)
+            .gamma(90.0)
+            .solve()
+            .unwrap();
+        assert!(close(4.0, ssa.ca));
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        assert_eq!(
+            Err(TrigError::from(TriangleError::UnderConstrained)),
+            TriangleBuilder::new().alpha(60.0).beta(60.0).solve()
+        );
+        assert_eq!(
+            Err(TrigError::from(TriangleError::OverConstrained)),
+            TriangleBuilder::new()
+                .ab(5.0)
+                .bc(3.0)
+                .ca(4.0)
+                .gamma(80.0)
+                .solve()
+        );
+        assert_eq!(
+            Err(TrigError::from(TriangleError::Impossible)),
+            TriangleBuilder::new().ab(1.0).bc(1.0).ca(3.0).solve()
+        );
+        assert_eq!(
+            Err(TrigError::from(TriangleError::Ambiguous)),
+            TriangleBuilder::new().bc(3.0).ca(4.0).alpha(30.0).solve()
+        );
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn test_solve_uses_libm() {
+        // Bit for bit the values of libm, whatever the platform's own functions return
+        let sas = TriangleBuilder::new()
+            .ca(4.0)
+            .ab(5.0)
+            .alpha(50.0)
+            .solve()
+            .unwrap();
+        let bc = (16.0f32 + 25.0 - 40.0 * libm::cosf(50f32.to_radians())).sqrt();
+        assert_eq!(bc, sas.bc);
+        let cosine = (bc * bc + 4.0 * 4.0 - 5.0 * 5.0) / (2.0 * bc * 4.0);
+        assert_eq!(libm::acosf(cosine.clamp(-1.0, 1.0)).to_degrees(), sas.gamma);
+        let asa = TriangleBuilder::new()
+            .ab(5.0)
+            .alpha(50.0)
+            .beta(60.0)
+            .solve()
+            .unwrap();
+        let ratio = 5.0 / libm::sinf(70f32.to_radians());
+        assert_eq!(ratio * libm::sinf(50f32.to_radians()), asa.bc);
+    }
+
+    #[test]
+    fn test_build_with_placed_points() {
+        let triangle = TriangleBuilder::new()
+            .point_a(p(1.0, 1.0))
+            .point_b(p(1.0, 6.0))
+            .ca(4.0)
+            .bc(3.0)
+            .build()
+            .unwrap();
+        let c = triangle.point_c();
+        assert!(close(-1.4, c.x) && close(4.2, c.y));
+        let placed = TriangleBuilder::new()
+            .ab(2.0)
+            .alpha(90.0)
+            .beta(45.0)
+            .solve()
+            .unwrap();
+        let triangle = placed.place(p(0.0, 0.0), Angle::from_degrees(90.0));
+        assert!(close(0.0, triangle.point_b().x) && close(2.0, triangle.point_b().y));
+        assert!(close(-2.0, triangle.point_c().x) && close(0.0, triangle.point_c().y));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{transcendental, Angle, Point, Vector};
+
+impl Angle {
+    /// Return the direction a fraction t of the way from a to b, turning the shorter way
+    /// round. The result is normalized; t outside [0, 1] keeps turning past the ends.
+    pub fn slerp(a: Angle, b: Angle, t: f32) -> Angle {
+        (a + (b - a).normalized() * t).normalized()
+    }
+}
+
+impl Vector {
+    /// Return the vector turned about point A towards the direction of target by at most
+    /// max_step, the shorter way round, keeping its length. Once within max_step it takes the
+    /// target direction exactly.
+    pub fn rotate_towards(&self, target: &Vector, max_step: Angle) -> Vector {
+        let current = transcendental::atan2(self.dy(), self.dx()).to_degrees();
+        let wanted = transcendental::atan2(target.dy(), target.dx()).to_degrees();
+        let remaining = Angle::from_degrees(wanted - current).normalized().degrees();
+        let step = max_step.degrees().abs();
+        let turn = Angle::from_degrees(remaining.clamp(-step, step));
+        let (dx, dy) = (self.dx(), self.dy());
+        let (sin, cos) = (turn.sin(), turn.cos());
+        let a = self.point_a();
+        Vector::new(
+            a,
+            Point {
+                x: a.x + cos * dx - sin * dy,
+                y: a.y + sin * dx + cos * dy,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_slerp_shortest_way() {
+        let (a, b) = (Angle::from_degrees(170.0), Angle::from_degrees(-170.0));
+        assert_eq!(Angle::from_degrees(180.0), Angle::slerp(a, b, 0.5));
+        assert_eq!(Angle::from_degrees(-175.0), Angle::slerp(a, b, 0.75));
+        let (a, b) = (Angle::from_degrees(10.0), Angle::from_degrees(50.0));
+        assert_eq!(Angle::from_degrees(20.0), Angle::slerp(a, b, 0.25));
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        let turret = Vector::new(p(1.0, 1.0), p(3.0, 1.0));
+        let target = Vector::new(p(0.0, 0.0), p(0.0, -5.0));
+        let turned = turret.rotate_towards(&target, Angle::from_degrees(30.0));
+        let b = turned.point_b();
+        assert!((b.x - (1.0 + 2.0 * 30f32.to_radians().cos())).abs() < 1e-5);
+        assert!((b.y - (1.0 - 2.0 * 30f32.to_radians().sin())).abs() < 1e-5);
+        let reached = turret.rotate_towards(&target, Angle::from_degrees(120.0));
+        assert!((reached.point_b().x - 1.0).abs() < 1e-5);
+        assert!((reached.point_b().y + 1.0).abs() < 1e-5);
+    }
+}
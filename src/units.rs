@@ -0,0 +1,97 @@
+//! Typed lengths and angles from the uom crate, enabled by the uom feature, so meters can not
+//! be mixed up with feet or degrees with radians. Plain crate coordinates are interpreted as
+//! meters and plain angles, as everywhere in the crate, as degrees.
+
+use crate::{transcendental, Circle, Point, Ray, Triangle, TrigError, Vector};
+use uom::si::angle::degree;
+use uom::si::f32::{Angle, Length};
+use uom::si::length::meter;
+
+impl From<Angle> for crate::Angle {
+    fn from(angle: Angle) -> crate::Angle {
+        crate::Angle::from_degrees(angle.get::<degree>())
+    }
+}
+
+impl From<crate::Angle> for Angle {
+    fn from(angle: crate::Angle) -> Angle {
+        Angle::new::<degree>(angle.degrees())
+    }
+}
+
+/// Return the point at the typed coordinates
+pub fn point(x: Length, y: Length) -> Point {
+    Point {
+        x: x.get::<meter>(),
+        y: y.get::<meter>(),
+    }
+}
+
+/// Return the coordinates of the point as typed lengths
+pub fn coordinates(point: Point) -> (Length, Length) {
+    (Length::new::<meter>(point.x), Length::new::<meter>(point.y))
+}
+
+/// Return the distance between the points
+pub fn distance(a: Point, b: Point) -> Length {
+    Length::new::<meter>(Vector::new(a, b).length())
+}
+
+/// Return the direction from a to b, counter clockwise from the positive x axis
+pub fn direction(a: Point, b: Point) -> Angle {
+    Angle::new::<degree>(transcendental::atan2(b.y - a.y, b.x - a.x).to_degrees())
+}
+
+/// Return the ray from origin in the typed direction
+pub fn ray(origin: Point, direction: Angle) -> Ray {
+    Ray::from_angle(origin, direction.get::<degree>())
+}
+
+/// Return the circle with a typed radius
+pub fn circle(center: Point, radius: Length) -> Circle {
+    Circle::new(center, radius.get::<meter>())
+}
+
+/// Return the side lengths ab, bc and ca of the triangle
+pub fn triangle_sides(triangle: &Triangle) -> (Length, Length, Length) {
+    let mut triangle = *triangle;
+    (
+        Length::new::<meter>(triangle.ab()),
+        Length::new::<meter>(triangle.bc()),
+        Length::new::<meter>(triangle.ca()),
+    )
+}
+
+/// Return the angles alpha, beta and gamma of the triangle, an error for collinear corners
+pub fn triangle_angles(triangle: &Triangle) -> Result<(Angle, Angle, Angle), TrigError> {
+    let mut triangle = *triangle;
+    let [alpha, beta, gamma] = triangle.try_angles()?;
+    Ok((
+        Angle::new::<degree>(alpha),
+        Angle::new::<degree>(beta),
+        Angle::new::<degree>(gamma),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::angle::radian;
+    use uom::si::length::{centimeter, foot};
+
+    #[test]
+    fn test_units_are_converted() {
+        let a = point(Length::new::<foot>(0.0), Length::new::<foot>(0.0));
+        let b = point(Length::new::<centimeter>(300.0), Length::new::<meter>(4.0));
+        assert!((distance(a, b).get::<meter>() - 5.0).abs() < 1e-5);
+        let right = Angle::new::<radian>(std::f32::consts::FRAC_PI_2);
+        assert!((crate::Angle::from(right).degrees() - 90.0).abs() < 1e-4);
+        let triangle = Triangle::new(
+            a,
+            b,
+            point(Length::new::<meter>(3.0), Length::new::<meter>(0.0)),
+        );
+        let (_, _, gamma) = triangle_angles(&triangle).unwrap();
+        assert!((gamma.get::<degree>() - 90.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{Circle, Ellipse, Point, Polygon, Polyline, Rect, Segment, SelfIntersection, Triangle};
+
+/// Problem found by Validate. Edge i runs from point i to point i + 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Issue {
+    /// A coordinate of the vertex, or a length for vertex None, is NaN or infinite
+    NotFinite { vertex: Option<usize> },
+    /// Fewer points than the shape needs
+    TooFewPoints,
+    /// An edge of length zero
+    DegenerateEdge { edge: usize },
+    /// A negative radius, semi axis or a rectangle with min above max
+    NegativeSize,
+    /// The shape encloses no area
+    ZeroArea,
+    /// The polygon runs clockwise, routines like triangulate expect counter clockwise
+    WrongWinding,
+    /// Two non adjacent edges cross
+    SelfIntersection(SelfIntersection),
+}
+
+/// All issues found in a shape, in the order they were checked.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn check(&mut self, failed: bool, issue: Issue) {
+        if failed {
+            self.issues.push(issue);
+        }
+    }
+
+    /// Report every point with a NaN or infinite coordinate, return true if all are finite
+    fn check_points(&mut self, points: &[Point]) -> bool {
+        let before = self.issues.len();
+        for (i, p) in points.iter().enumerate() {
+            self.check(!is_finite(*p), Issue::NotFinite { vertex: Some(i) });
+        }
+        self.issues.len() == before
+    }
+
+    fn check_edges(&mut self, edges: impl Iterator<Item = Segment>) {
+        for (i, edge) in edges.enumerate() {
+            self.check(
+                edge.point_a == edge.point_b,
+                Issue::DegenerateEdge { edge: i },
+            );
+        }
+    }
+
+    fn check_lengths(&mut self, lengths: &[f32]) {
+        self.check(
+            lengths.iter().any(|l| !l.is_finite()),
+            Issue::NotFinite { vertex: None },
+        );
+        self.check(lengths.iter().any(|l| *l < 0.0), Issue::NegativeSize);
+        self.check(lengths.contains(&0.0), Issue::ZeroArea);
+    }
+}
+
+/// Checks for malformed geometry, so bad input is found up front instead of surfacing as
+/// NaN deep inside a computation.
+pub trait Validate {
+    fn validate(&self) -> ValidationReport;
+}
+
+fn is_finite(point: Point) -> bool {
+    point.x.is_finite() && point.y.is_finite()
+}
+
+impl Validate for Point {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        report.check_points(&[*self]);
+        report
+    }
+}
+
+impl Validate for Segment {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        if report.check_points(&[self.point_a, self.point_b]) {
+            report.check_edges(std::iter::once(*self));
+        }
+        report
+    }
+}
+
+impl Validate for Circle {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        report.check_points(&[self.center]);
+        report.check_lengths(&[self.radius]);
+        report
+    }
+}
+
+impl Validate for Ellipse {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        report.check_points(&[self.center]);
+        report.check_lengths(&[self.semi_major, self.semi_minor]);
+        report.check(
+            !self.rotation.is_finite(),
+            Issue::NotFinite { vertex: None },
+        );
+        report
+    }
+}
+
+impl Validate for Rect {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        if report.check_points(&[self.min, self.max]) {
+            report.check_lengths(&[self.width(), self.height()]);
+        }
+        report
+    }
+}
+
+impl Validate for Triangle {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        if report.check_points(&[self.point_a(), self.point_b(), self.point_c()]) {
+            report.check_edges(self.edges());
+            let (a, b, c) = (self.point_a(), self.point_b(), self.point_c());
+            let cross = (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y);
+            report.check(cross == 0.0, Issue::ZeroArea);
+        }
+        report
+    }
+}
+
+impl Validate for Polygon {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        if self.points.len() < 3 {
+            report.issues.push(Issue::TooFewPoints);
+        }
+        if report.check_points(&self.points) && self.points.len() >= 3 {
+            report.check_edges(self.edges());
+            let area = self.signed_area();
+            report.check(area == 0.0, Issue::ZeroArea);
+            report.check(area < 0.0, Issue::WrongWinding);
+            for crossing in self.self_intersections() {
+                report.issues.push(Issue::SelfIntersection(crossing));
+            }
+        }
+        report
+    }
+}
+
+impl Validate for Polyline {
+    fn validate(&self) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        if self.points.len() < 2 {
+            report.issues.push(Issue::TooFewPoints);
+        }
+        if report.check_points(&self.points) {
+            report.check_edges(self.segments());
+            for crossing in self.self_intersections() {
+                report.issues.push(Issue::SelfIntersection(crossing));
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_polygon_report() {
+        let square = Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)]);
+        assert!(square.validate().is_valid());
+        let mut clockwise = square.clone();
+        clockwise.points.reverse();
+        assert_eq!(vec![Issue::WrongWinding], clockwise.validate().issues);
+        let bowtie = Polygon::new(vec![p(0.0, 0.0), p(1.0, 1.0), p(1.0, 0.0), p(0.0, 1.0)]);
+        let issues = bowtie.validate().issues;
+        assert_eq!(Issue::ZeroArea, issues[0]);
+        assert!(matches!(issues[1], Issue::SelfIntersection(_)));
+        let broken = Polygon::new(vec![p(0.0, 0.0), p(f32::NAN, 0.0), p(0.0, 0.0)]);
+        assert_eq!(
+            vec![Issue::NotFinite { vertex: Some(1) }],
+            broken.validate().issues
+        );
+    }
+
+    #[test]
+    fn test_simple_shapes() {
+        assert_eq!(
+            vec![Issue::NegativeSize],
+            Circle::new(p(0.0, 0.0), -1.0).validate().issues
+        );
+        assert_eq!(
+            vec![Issue::DegenerateEdge { edge: 0 }],
+            Segment::new(p(1.0, 1.0), p(1.0, 1.0)).validate().issues
+        );
+        assert_eq!(
+            vec![Issue::ZeroArea],
+            Rect::new(p(0.0, 0.0), p(0.0, 2.0)).validate().issues
+        );
+        let line = Polyline::new(vec![p(0.0, 0.0), p(2.0, 2.0), p(2.0, 0.0), p(0.0, 2.0)]);
+        assert_eq!(1, line.validate().issues.len());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{Point, Polygon, Segment, Triangle, TrigError};
+use std::ops::Index;
+
+/// Position of a vertex in a shape, for indexing Triangle and Polygon. The vertices of a
+/// triangle are 0 for point_a, 1 for point_b and 2 for point_c.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VertexId(pub usize);
+
+impl Triangle {
+    /// Iterate over point_a, point_b and point_c
+    pub fn vertices(&self) -> impl Iterator<Item = Point> {
+        vec![self.point_a, self.point_b, self.point_c].into_iter()
+    }
+
+    /// Iterate over the edges ab, bc and ca
+    pub fn edges(&self) -> impl Iterator<Item = Segment> {
+        vec![
+            Segment::new(self.point_a, self.point_b),
+            Segment::new(self.point_b, self.point_c),
+            Segment::new(self.point_c, self.point_a),
+        ]
+        .into_iter()
+    }
+
+    /// Return the interior angle in degrees at the vertex, alpha for 0, beta for 1 and gamma
+    /// for 2, an error for collinear corners. Panics for ids above 2.
+    pub fn angle_at(&mut self, id: VertexId) -> Result<f32, TrigError> {
+        match id.0 {
+            0 => self.alpha(),
+            1 => self.beta(),
+            2 => self.gamma(),
+            _ => panic!("triangle has no vertex {}", id.0),
+        }
+    }
+
+    /// Return the exterior angles at point_a, point_b and point_c, each 180 degrees minus
+    /// the interior angle
+    pub fn exterior_angles(&mut self) -> Result<[f32; 3], TrigError> {
+        let [alpha, beta, gamma] = self.try_angles()?;
+        Ok([180.0 - alpha, 180.0 - beta, 180.0 - gamma])
+    }
+
+    /// Return the sum of the interior angles, 180 up to rounding for a valid triangle and
+    /// an error for a degenerate one
+    pub fn angle_sum(&mut self) -> Result<f32, TrigError> {
+        let [alpha, beta, gamma] = self.try_angles()?;
+        Ok(alpha + beta + gamma)
+    }
+}
+
+impl Index<VertexId> for Triangle {
+    type Output = Point;
+
+    /// Panics for ids above 2
+    fn index(&self, id: VertexId) -> &Point {
+        match id.0 {
+            0 => &self.point_a,
+            1 => &self.point_b,
+            2 => &self.point_c,
+            _ => panic!("triangle has no vertex {}", id.0),
+        }
+    }
+}
+
+impl Polygon {
+    /// Iterate over the points with their ids
+    pub fn vertices(&self) -> impl Iterator<Item = (VertexId, Point)> + '_ {
+        self.points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (VertexId(i), *p))
+    }
+}
+
+impl Index<VertexId> for Polygon {
+    type Output = Point;
+
+    fn index(&self, id: VertexId) -> &Point {
+        &self.points[id.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+    use crate::Vector;
+
+    #[test]
+    fn test_triangle_vertices_and_edges() {
+        let triangle = Triangle::new(p(0.0, 0.0), p(3.0, 0.0), p(0.0, 4.0));
+        assert_eq!(p(3.0, 0.0), triangle[VertexId(1)]);
+        let vertices: Vec<Point> = triangle.vertices().collect();
+        assert_eq!(vec![p(0.0, 0.0), p(3.0, 0.0), p(0.0, 4.0)], vertices);
+        let lengths: Vec<f32> = triangle.edges().map(|e| Vector::from(e).length()).collect();
+        assert_eq!(vec![3.0, 5.0, 4.0], lengths);
+    }
+
+    #[test]
+    fn test_triangle_angles_by_vertex() {
+        let mut triangle = Triangle::new(p(0.0, 0.0), p(3.0, 0.0), p(0.0, 4.0));
+        let angles: Vec<f32> = (0..3)
+            .map(|i| triangle.angle_at(VertexId(i)).unwrap())
+            .collect();
+        assert_eq!(triangle.alpha(), Ok(angles[0]));
+        assert!((angles[0] - 90.0).abs() < 1e-4);
+        let exterior = triangle.exterior_angles().unwrap();
+        assert!((exterior.iter().sum::<f32>() - 360.0).abs() < 1e-3);
+        assert!((exterior[1] - (180.0 - angles[1])).abs() < 1e-6);
+        assert!((triangle.angle_sum().unwrap() - 180.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_polygon_vertices() {
+        let polygon = Polygon::new(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0)]);
+        let (id, point) = polygon.vertices().last().unwrap();
+        assert_eq!(VertexId(2), id);
+        assert_eq!(polygon[id], point);
+    }
+}
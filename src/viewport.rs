@@ -0,0 +1,207 @@
+use crate::{Circle, Point, Polygon, Polyline, Rect, Segment, Triangle};
+
+/// 2D camera mapping world coordinates to screen pixels. The screen origin is the top left
+/// corner with y growing downwards, the world has y growing upwards. zoom is the number of
+/// pixels per world unit and center the world point shown in the middle of the screen.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    pub center: Point,
+    pub zoom: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Viewport {
+    pub fn new(center: Point, zoom: f32, width: f32, height: f32) -> Viewport {
+        Viewport {
+            center,
+            zoom,
+            width,
+            height,
+        }
+    }
+
+    /// Return the viewport showing all of world on a screen of width by height pixels, at
+    /// the largest zoom that keeps the aspect ratio
+    pub fn fit(world: Rect, width: f32, height: f32) -> Viewport {
+        let zoom = (width / world.width()).min(height / world.height());
+        let center = Point {
+            x: (world.min.x + world.max.x) / 2.0,
+            y: (world.min.y + world.max.y) / 2.0,
+        };
+        Viewport::new(center, zoom, width, height)
+    }
+
+    pub fn world_to_screen(&self, point: Point) -> Point {
+        Point {
+            x: self.width / 2.0 + (point.x - self.center.x) * self.zoom,
+            y: self.height / 2.0 - (point.y - self.center.y) * self.zoom,
+        }
+    }
+
+    pub fn screen_to_world(&self, point: Point) -> Point {
+        Point {
+            x: self.center.x + (point.x - self.width / 2.0) / self.zoom,
+            y: self.center.y - (point.y - self.height / 2.0) / self.zoom,
+        }
+    }
+
+    /// Return the part of the world visible on screen
+    pub fn visible_world(&self) -> Rect {
+        Rect::new(
+            self.screen_to_world(Point { x: 0.0, y: 0.0 }),
+            self.screen_to_world(Point {
+                x: self.width,
+                y: self.height,
+            }),
+        )
+    }
+
+    /// Move the view as when dragging the content by dx, dy pixels
+    pub fn pan(&mut self, dx: f32, dy: f32) -> &mut Self {
+        self.center.x -= dx / self.zoom;
+        self.center.y += dy / self.zoom;
+        self
+    }
+
+    /// Multiply the zoom by factor, keeping the world point under the screen point in place
+    /// as scroll wheel zooming does
+    pub fn zoom_at(&mut self, screen: Point, factor: f32) -> &mut Self {
+        let anchor = self.screen_to_world(screen);
+        self.zoom *= factor;
+        let moved = self.screen_to_world(screen);
+        self.center.x += anchor.x - moved.x;
+        self.center.y += anchor.y - moved.y;
+        self
+    }
+}
+
+/// Geometry that can be moved between world and screen coordinates of a Viewport.
+pub trait ViewTransform {
+    fn to_screen(&self, viewport: &Viewport) -> Self;
+    fn to_world(&self, viewport: &Viewport) -> Self;
+}
+
+impl ViewTransform for Point {
+    fn to_screen(&self, viewport: &Viewport) -> Point {
+        viewport.world_to_screen(*self)
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Point {
+        viewport.screen_to_world(*self)
+    }
+}
+
+impl ViewTransform for Segment {
+    fn to_screen(&self, viewport: &Viewport) -> Segment {
+        Segment::new(
+            self.point_a.to_screen(viewport),
+            self.point_b.to_screen(viewport),
+        )
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Segment {
+        Segment::new(
+            self.point_a.to_world(viewport),
+            self.point_b.to_world(viewport),
+        )
+    }
+}
+
+impl ViewTransform for Rect {
+    /// The flipped y axis swaps top and bottom, min and max are sorted again
+    fn to_screen(&self, viewport: &Viewport) -> Rect {
+        Rect::new(self.min.to_screen(viewport), self.max.to_screen(viewport))
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Rect {
+        Rect::new(self.min.to_world(viewport), self.max.to_world(viewport))
+    }
+}
+
+impl ViewTransform for Circle {
+    fn to_screen(&self, viewport: &Viewport) -> Circle {
+        Circle::new(self.center.to_screen(viewport), self.radius * viewport.zoom)
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Circle {
+        Circle::new(self.center.to_world(viewport), self.radius / viewport.zoom)
+    }
+}
+
+impl ViewTransform for Triangle {
+    fn to_screen(&self, viewport: &Viewport) -> Triangle {
+        Triangle::new(
+            self.point_a().to_screen(viewport),
+            self.point_b().to_screen(viewport),
+            self.point_c().to_screen(viewport),
+        )
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Triangle {
+        Triangle::new(
+            self.point_a().to_world(viewport),
+            self.point_b().to_world(viewport),
+            self.point_c().to_world(viewport),
+        )
+    }
+}
+
+impl ViewTransform for Polygon {
+    /// The flipped y axis turns counter clockwise polygons clockwise on screen
+    fn to_screen(&self, viewport: &Viewport) -> Polygon {
+        Polygon::new(self.points.iter().map(|p| p.to_screen(viewport)).collect())
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Polygon {
+        Polygon::new(self.points.iter().map(|p| p.to_world(viewport)).collect())
+    }
+}
+
+impl ViewTransform for Polyline {
+    fn to_screen(&self, viewport: &Viewport) -> Polyline {
+        Polyline::new(self.points.iter().map(|p| p.to_screen(viewport)).collect())
+    }
+
+    fn to_world(&self, viewport: &Viewport) -> Polyline {
+        Polyline::new(self.points.iter().map(|p| p.to_world(viewport)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    #[test]
+    fn test_fit_and_flip() {
+        let world = Rect::new(p(0.0, 0.0), p(10.0, 5.0));
+        let viewport = Viewport::fit(world, 200.0, 200.0);
+        assert_eq!(20.0, viewport.zoom);
+        assert_eq!(p(0.0, 150.0), viewport.world_to_screen(p(0.0, 0.0)));
+        assert_eq!(p(200.0, 50.0), viewport.world_to_screen(p(10.0, 5.0)));
+        assert_eq!(p(10.0, 5.0), viewport.screen_to_world(p(200.0, 50.0)));
+        let screen = world.to_screen(&viewport);
+        assert_eq!(Rect::new(p(0.0, 50.0), p(200.0, 150.0)), screen);
+        assert_eq!(
+            Circle::new(p(100.0, 100.0), 20.0),
+            Circle::new(p(5.0, 2.5), 1.0).to_screen(&viewport)
+        );
+    }
+
+    #[test]
+    fn test_pan_and_zoom() {
+        let mut viewport = Viewport::new(p(0.0, 0.0), 10.0, 100.0, 100.0);
+        let cursor = p(80.0, 30.0);
+        let under_cursor = viewport.screen_to_world(cursor);
+        viewport.zoom_at(cursor, 2.0);
+        let moved = viewport.screen_to_world(cursor);
+        assert!((moved.x - under_cursor.x).abs() < 1e-5 && (moved.y - under_cursor.y).abs() < 1e-5);
+        let before = viewport.world_to_screen(p(1.0, 1.0));
+        viewport.pan(15.0, -5.0);
+        let after = viewport.world_to_screen(p(1.0, 1.0));
+        assert!(
+            (after.x - before.x - 15.0).abs() < 1e-4 && (after.y - before.y + 5.0).abs() < 1e-4
+        );
+    }
+}
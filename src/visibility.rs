@@ -0,0 +1,59 @@
+use crate::{raycast, transcendental, Point, Polygon, Ray, Segment, Tolerance};
+
+/// Angle in degrees by which rays are turned past each obstacle end point to look behind it.
+const SWEEP_OFFSET: f32 = 1e-3;
+
+/// Return the region visible from origin among the obstacle segments, computed by an
+/// angular sweep: rays are cast towards every obstacle end point and slightly past it on
+/// both sides, and the closest hits, sorted by angle, form the polygon. The obstacles are
+/// expected to enclose origin, for example by including the walls of a room. Rays escaping
+/// through gaps hit nothing and leave no vertex.
+pub fn visibility_polygon(origin: Point, obstacles: &[Segment]) -> Polygon {
+    let mut angles: Vec<f32> = obstacles
+        .iter()
+        .flat_map(|s| vec![s.point_a, s.point_b])
+        .map(|p| transcendental::atan2(p.y - origin.y, p.x - origin.x).to_degrees())
+        .flat_map(|angle| vec![angle - SWEEP_OFFSET, angle, angle + SWEEP_OFFSET])
+        .collect();
+    angles.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let points: Vec<Point> = angles
+        .into_iter()
+        .filter_map(|angle| raycast(&Ray::from_angle(origin, angle), obstacles))
+        .map(|hit| hit.point)
+        .collect();
+    let points = Tolerance::default().dedup(&points);
+    Polygon::new(points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::p;
+
+    fn room() -> Vec<Segment> {
+        vec![
+            Segment::new(p(0.0, 0.0), p(10.0, 0.0)),
+            Segment::new(p(10.0, 0.0), p(10.0, 10.0)),
+            Segment::new(p(10.0, 10.0), p(0.0, 10.0)),
+            Segment::new(p(0.0, 10.0), p(0.0, 0.0)),
+        ]
+    }
+
+    #[test]
+    fn test_empty_room_is_fully_visible() {
+        let visible = visibility_polygon(p(3.0, 4.0), &room());
+        assert!(visible.contains(p(9.9, 9.9)));
+        assert!(visible.contains(p(0.1, 0.1)));
+        assert!(visible.contains(p(9.9, 0.1)));
+    }
+
+    #[test]
+    fn test_pillar_casts_shadow() {
+        let mut obstacles = room();
+        obstacles.push(Segment::new(p(6.0, 4.0), p(6.0, 6.0)));
+        let visible = visibility_polygon(p(2.0, 5.0), &obstacles);
+        assert!(!visible.contains(p(8.0, 5.0)));
+        assert!(visible.contains(p(5.0, 5.0)));
+        assert!(visible.contains(p(8.0, 1.0)));
+    }
+}
@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{Point, Polygon, Polyline, Segment, SegmentContact, SignedDistance, Tolerance};
+
+/// Graph connecting the corners of polygon obstacles that can see each other, for shortest
+/// paths that wrap tightly around the obstacles. Paths may touch obstacle corners and run
+/// along their edges but never through their inside.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisibilityGraph {
+    obstacles: Vec<Polygon>,
+    tolerance: Tolerance,
+    vertices: Vec<Point>,
+    /// Visible vertices of each vertex with their distance
+    neighbors: Vec<Vec<(usize, f32)>>,
+}
+
+impl VisibilityGraph {
+    /// Build the graph among all obstacle corners. Building tests every pair of corners
+    /// against every edge, so it takes cubic time in the number of corners.
+    pub fn new(obstacles: &[Polygon]) -> VisibilityGraph {
+        VisibilityGraph::with_tolerance(obstacles, Tolerance::default())
+    }
+
+    /// Build the graph with lines of sight that may enter an obstacle by up to the
+    /// tolerance, so paths along edges survive rounding
+    pub fn with_tolerance(obstacles: &[Polygon], tolerance: Tolerance) -> VisibilityGraph {
+        let vertices: Vec<Point> = obstacles
+            .iter()
+            .flat_map(|o| o.points.iter().copied())
+            .collect();
+        let mut graph = VisibilityGraph {
+            obstacles: obstacles.to_vec(),
+            tolerance,
+            vertices,
+            neighbors: Vec::new(),
+        };
+        graph.neighbors = (0..graph.vertices.len())
+            .map(|i| graph.visible_from(graph.vertices[i], i + 1..graph.vertices.len()))
+            .collect();
+        // Edges were only searched towards higher indices, mirror them
+        for i in 0..graph.neighbors.len() {
+            for k in 0..graph.neighbors[i].len() {
+                let (j, length) = graph.neighbors[i][k];
+                if j > i {
+                    graph.neighbors[j].push((i, length));
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Return every edge of the graph once
+    pub fn edges(&self) -> Vec<Segment> {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .flat_map(|(i, n)| {
+                n.iter()
+                    .filter(move |(j, _)| *j > i)
+                    .map(move |(j, _)| Segment::new(self.vertices[i], self.vertices[*j]))
+            })
+            .collect()
+    }
+
+    /// Return true if the straight line between the points stays out of every obstacle
+    pub fn is_visible(&self, a: Point, b: Point) -> bool {
+        let segment = Segment::new(a, b);
+        let d = self.tolerance.distance;
+        let length_squared = (b.x - a.x).powi(2) + (b.y - a.y).powi(2);
+        if length_squared == 0.0 {
+            return !self.is_blocked(a);
+        }
+        let along =
+            |p: Point| ((p.x - a.x) * (b.x - a.x) + (p.y - a.y) * (b.y - a.y)) / length_squared;
+        self.obstacles.iter().all(|obstacle| {
+            // Cut the segment where it meets the outline and check the middle of every piece
+            let mut cuts: Vec<f32> = obstacle
+                .edges()
+                .flat_map(|e| match segment.contact(&e, self.tolerance) {
+                    SegmentContact::Disjoint => vec![],
+                    SegmentContact::Crossing(p) | SegmentContact::Touching(p) => vec![p],
+                    SegmentContact::Overlapping(piece) => vec![piece.point_a, piece.point_b],
+                })
+                .map(along)
+                .chain(vec![0.0, 1.0])
+                .collect();
+            cuts.sort_by(|x, y| x.total_cmp(y));
+            cuts.windows(2).all(|w| {
+                (w[1] - w[0]) * length_squared.sqrt() <= d
+                    || obstacle.sdf(segment.point_at((w[0] + w[1]) / 2.0)) >= -d
+            })
+        })
+    }
+
+    /// Return the shortest path from start to goal around the obstacles, None if either
+    /// point lies inside an obstacle or no path exists
+    pub fn shortest_path(&self, start: Point, goal: Point) -> Option<Polyline> {
+        if self.is_blocked(start) || self.is_blocked(goal) {
+            return None;
+        }
+        if self.is_visible(start, goal) {
+            return Some(Polyline::new(vec![start, goal]));
+        }
+        // Start and goal join the graph as the two vertices after the corners
+        let (s, g) = (self.vertices.len(), self.vertices.len() + 1);
+        let from_start = self.visible_from(start, 0..s);
+        let to_goal = self.visible_from(goal, 0..s);
+        let point = |i: usize| match i {
+            i if i == s => start,
+            i if i == g => goal,
+            i => self.vertices[i],
+        };
+        let mut cost = vec![f32::INFINITY; g + 1];
+        let mut came_from = vec![None; g + 1];
+        let mut open = BinaryHeap::new();
+        cost[s] = 0.0;
+        open.push(Open {
+            estimate: Segment::new(start, goal).length(),
+            vertex: s,
+        });
+        while let Some(Open { vertex, .. }) = open.pop() {
+            if vertex == g {
+                let mut path = vec![goal];
+                let mut current = g;
+                while let Some(previous) = came_from[current] {
+                    path.push(point(previous));
+                    current = previous;
+                }
+                path.reverse();
+                return Some(Polyline::new(path));
+            }
+            let mut next: Vec<(usize, f32)> = if vertex == s {
+                from_start.clone()
+            } else {
+                self.neighbors[vertex].clone()
+            };
+            if let Some((_, length)) = to_goal.iter().find(|(i, _)| *i == vertex) {
+                next.push((g, *length));
+            }
+            for (n, length) in next {
+                let step = cost[vertex] + length;
+                if step < cost[n] {
+                    cost[n] = step;
+                    came_from[n] = Some(vertex);
+                    open.push(Open {
+                        estimate: step + Segment::new(point(n), goal).length(),
+                        vertex: n,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Return the corners in range visible from the point with their distance
+    fn visible_from(&self, point: Point, range: std::ops::Range<usize>) -> Vec<(usize, f32)> {
+        range
+            .filter(|j| self.is_visible(point, self.vertices[*j]))
+            .map(|j| (j, Segment::new(point, self.vertices[j]).length()))
+            .collect()
+    }
+
+    fn is_blocked(&self, point: Point) -> bool {
+        self.obstacles
+            .iter()
+            .any(|o| o.sdf(point) < -self.tolerance.distance)
+    }
+}
+
+/// Vertex waiting in the A* queue, ordered so the lowest estimate comes first
+#[derive(Copy, Clone)]
+struct Open {
+    estimate: f32,
+    vertex: usize,
+}
+
+impl PartialEq for Open {
+    fn eq(&self, other: &Open) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Open {}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Open) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Open) -> Ordering {
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{p, square};
+
+    #[test]
+    fn test_visibility() {
+        let graph = VisibilityGraph::new(&[square(0.0, 0.0, 2.0)]);
+        // The sides of the square are edges, the diagonals pass through it
+        assert_eq!(4, graph.edges().len());
+        assert!(graph.is_visible(p(-1.0, -1.0), p(0.0, 0.0)));
+        assert!(graph.is_visible(p(-1.0, 0.0), p(3.0, 0.0)));
+        assert!(!graph.is_visible(p(-1.0, 1.0), p(3.0, 1.0)));
+        assert!(!graph.is_visible(p(-1.0, -1.0), p(3.0, 3.0)));
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let graph = VisibilityGraph::new(&[square(2.0, -1.0, 2.0), square(6.0, 0.0, 2.0)]);
+        let path = graph.shortest_path(p(0.0, 0.0), p(10.0, 0.5)).unwrap();
+        assert_eq!(p(0.0, 0.0), path.points[0]);
+        assert_eq!(p(10.0, 0.5), *path.points.last().unwrap());
+        // Below the first square, then grazing the corner of the second
+        let expected = Segment::new(p(0.0, 0.0), p(2.0, -1.0)).length()
+            + 2.0
+            + Segment::new(p(4.0, -1.0), p(10.0, 0.5)).length();
+        assert!((path.length() - expected).abs() < 1e-4, "{:?}", path);
+        assert_eq!(None, graph.shortest_path(p(3.0, 0.0), p(10.0, 0.0)));
+        let direct = graph.shortest_path(p(0.0, 3.0), p(10.0, 3.0)).unwrap();
+        assert_eq!(2, direct.points.len());
+    }
+}
@@ -0,0 +1,234 @@
+//! JavaScript bindings through wasm-bindgen, enabled by the wasm feature. The classes wrap
+//! the crate types and hand out plain numbers and arrays, which cross into JavaScript
+//! cheaply. Angles are in degrees, like everywhere else in the crate. Errors, like the angles
+//! of a degenerate triangle, are thrown as their message.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Area, Perimeter, Point, Triangle, TriangleBuilder, TrigError, Vector};
+
+/// Point exported to JavaScript as Point.
+#[wasm_bindgen(js_name = Point)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WasmPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[wasm_bindgen(js_class = Point)]
+impl WasmPoint {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: f32, y: f32) -> WasmPoint {
+        WasmPoint { x, y }
+    }
+
+    /// Return the distance to the other point
+    #[wasm_bindgen(js_name = distanceTo)]
+    pub fn distance_to(&self, other: &WasmPoint) -> f32 {
+        Vector::new(Point::from(*self), Point::from(*other)).length()
+    }
+}
+
+impl From<WasmPoint> for Point {
+    fn from(point: WasmPoint) -> Point {
+        Point {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<Point> for WasmPoint {
+    fn from(point: Point) -> WasmPoint {
+        WasmPoint::new(point.x, point.y)
+    }
+}
+
+/// Vector exported to JavaScript as Vector.
+#[wasm_bindgen(js_name = Vector)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WasmVector {
+    inner: Vector,
+}
+
+#[wasm_bindgen(js_class = Vector)]
+impl WasmVector {
+    #[wasm_bindgen(constructor)]
+    pub fn new(point_a: &WasmPoint, point_b: &WasmPoint) -> WasmVector {
+        WasmVector {
+            inner: Vector::new_initialized(Point::from(*point_a), Point::from(*point_b)),
+        }
+    }
+
+    #[wasm_bindgen(getter, js_name = pointA)]
+    pub fn point_a(&self) -> WasmPoint {
+        self.inner.point_a().into()
+    }
+
+    #[wasm_bindgen(getter, js_name = pointB)]
+    pub fn point_b(&self) -> WasmPoint {
+        self.inner.point_b().into()
+    }
+
+    pub fn dx(&self) -> f32 {
+        self.inner.dx()
+    }
+
+    pub fn dy(&self) -> f32 {
+        self.inner.dy()
+    }
+
+    pub fn length(&self) -> f32 {
+        let mut inner = self.inner;
+        inner.length()
+    }
+
+    pub fn alpha(&self) -> Result<f32, JsValue> {
+        let mut inner = self.inner;
+        inner.alpha().map_err(thrown)
+    }
+
+    pub fn beta(&self) -> Result<f32, JsValue> {
+        let mut inner = self.inner;
+        inner.beta().map_err(thrown)
+    }
+}
+
+/// Triangle exported to JavaScript as Triangle.
+#[wasm_bindgen(js_name = Triangle)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WasmTriangle {
+    inner: Triangle,
+}
+
+#[wasm_bindgen(js_class = Triangle)]
+impl WasmTriangle {
+    #[wasm_bindgen(constructor)]
+    pub fn new(point_a: &WasmPoint, point_b: &WasmPoint, point_c: &WasmPoint) -> WasmTriangle {
+        WasmTriangle {
+            inner: Triangle::new_initialized(
+                Point::from(*point_a),
+                Point::from(*point_b),
+                Point::from(*point_c),
+            ),
+        }
+    }
+
+    #[wasm_bindgen(getter, js_name = pointA)]
+    pub fn point_a(&self) -> WasmPoint {
+        self.inner.point_a().into()
+    }
+
+    #[wasm_bindgen(getter, js_name = pointB)]
+    pub fn point_b(&self) -> WasmPoint {
+        self.inner.point_b().into()
+    }
+
+    #[wasm_bindgen(getter, js_name = pointC)]
+    pub fn point_c(&self) -> WasmPoint {
+        self.inner.point_c().into()
+    }
+
+    pub fn ab(&self) -> f32 {
+        let mut inner = self.inner;
+        inner.ab()
+    }
+
+    pub fn bc(&self) -> f32 {
+        let mut inner = self.inner;
+        inner.bc()
+    }
+
+    pub fn ca(&self) -> f32 {
+        let mut inner = self.inner;
+        inner.ca()
+    }
+
+    pub fn alpha(&self) -> Result<f32, JsValue> {
+        let mut inner = self.inner;
+        inner.alpha().map_err(thrown)
+    }
+
+    pub fn beta(&self) -> Result<f32, JsValue> {
+        let mut inner = self.inner;
+        inner.beta().map_err(thrown)
+    }
+
+    pub fn gamma(&self) -> Result<f32, JsValue> {
+        let mut inner = self.inner;
+        inner.gamma().map_err(thrown)
+    }
+
+    pub fn area(&self) -> f32 {
+        self.inner.area()
+    }
+
+    pub fn perimeter(&self) -> f32 {
+        self.inner.perimeter()
+    }
+
+    /// Return the corners as [ax, ay, bx, by, cx, cy], ready for drawing on a canvas
+    #[wasm_bindgen(js_name = toArray)]
+    pub fn to_array(&self) -> Vec<f32> {
+        self.inner.vertices().flat_map(|p| vec![p.x, p.y]).collect()
+    }
+}
+
+/// TriangleBuilder exported to JavaScript as TriangleSolver. The setters return nothing, as
+/// JavaScript can not hold the builder's mutable borrow.
+#[wasm_bindgen(js_name = TriangleSolver)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct WasmTriangleSolver {
+    builder: TriangleBuilder,
+}
+
+#[wasm_bindgen(js_class = TriangleSolver)]
+impl WasmTriangleSolver {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmTriangleSolver {
+        WasmTriangleSolver::default()
+    }
+
+    #[wasm_bindgen(js_name = setAb)]
+    pub fn set_ab(&mut self, length: f32) {
+        self.builder.ab(length);
+    }
+
+    #[wasm_bindgen(js_name = setBc)]
+    pub fn set_bc(&mut self, length: f32) {
+        self.builder.bc(length);
+    }
+
+    #[wasm_bindgen(js_name = setCa)]
+    pub fn set_ca(&mut self, length: f32) {
+        self.builder.ca(length);
+    }
+
+    #[wasm_bindgen(js_name = setAlpha)]
+    pub fn set_alpha(&mut self, degrees: f32) {
+        self.builder.alpha(degrees);
+    }
+
+    #[wasm_bindgen(js_name = setBeta)]
+    pub fn set_beta(&mut self, degrees: f32) {
+        self.builder.beta(degrees);
+    }
+
+    #[wasm_bindgen(js_name = setGamma)]
+    pub fn set_gamma(&mut self, degrees: f32) {
+        self.builder.gamma(degrees);
+    }
+
+    /// Return the solved triangle placed as by TriangleBuilder::build, or throw the error
+    /// message
+    pub fn solve(&self) -> Result<WasmTriangle, JsValue> {
+        self.builder
+            .build()
+            .map(|inner| WasmTriangle { inner })
+            .map_err(thrown)
+    }
+}
+
+fn thrown(error: TrigError) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}